@@ -3,6 +3,7 @@ use std::env::*;
 use std::path::Path;
 
 pub fn cog_set_var(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Env);
   let (v1, v2) = get_2_words!(state, w);
   let vword1 = v1.value_stack_ref().first().unwrap().vword_ref();
   let vword2 = v2.value_stack_ref().first().unwrap().vword_ref();
@@ -13,6 +14,7 @@ pub fn cog_set_var(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_remove_var(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Env);
   let vw = get_word!(state, w);
   let vword = vw.value_stack_ref().first().unwrap().vword_ref();
   remove_var(&vword.str_word);
@@ -21,11 +23,12 @@ pub fn cog_remove_var(mut state: CognitionState, w: Option<&Value>) -> Cognition
 }
 
 pub fn cog_current_dir(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Env);
   let Ok(current) = current_dir() else {
-    return state.eval_error("INVALID CURRENT DIRECTORY", w)
+    return state.eval_error_other("INVALID CURRENT DIRECTORY", w)
   };
   let Ok(mut string) = current.into_os_string().into_string() else {
-    return state.eval_error("INVALID STRING", w)
+    return state.eval_error(ErrorKind::InvalidString, w)
   };
   let mut vw = state.pool.get_vword(0);
   std::mem::swap(&mut string, &mut vw.str_word);
@@ -35,11 +38,12 @@ pub fn cog_current_dir(mut state: CognitionState, w: Option<&Value>) -> Cognitio
 }
 
 pub fn cog_set_current_dir(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Env);
   let vw = get_word!(state, w);
   let vword = vw.value_stack_ref().first().unwrap().vword_ref();
   if set_current_dir(Path::new(&vword.str_word)).is_err() {
     state.current().stack.push(vw);
-    return state.eval_error("CHANGE DIRECTORY FAILED", w)
+    return state.eval_error_other("CHANGE DIRECTORY FAILED", w)
   }
   state.pool.add_val(vw);
   state
@@ -3,12 +3,12 @@ use unicode_segmentation::UnicodeSegmentation;
 
 pub fn cog_gunconcat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let mut unconcatlen: usize = 0;
   for val in v.value_stack_ref().iter() {
     let Value::Word(word) = val else {
       stack.push(v);
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     };
     unconcatlen += word.str_word.len()
   }
@@ -28,25 +28,25 @@ pub fn cog_gunconcat(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 
 pub fn cog_gcut(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(vint) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(vint) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let Some(mut vstr) = stack.pop() else {
     stack.push(vint);
-    return state.eval_error("TOO FEW ARGUMENTS", w);
+    return state.eval_error(ErrorKind::TooFewArguments, w);
   };
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w);
+    return state.eval_error(ErrorKind::MathBaseZero, w);
   };
   let string = &mut vstr.value_stack().first_mut().unwrap().vword_mut().str_word;
   let mut iter = string.grapheme_indices(true);
@@ -56,14 +56,14 @@ pub fn cog_gcut(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     Ok(mut i) => if i < 0 || i.abs() as usize > string.len() {
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       loop {
         if i == 0 { break }
         if iter.next().is_none() {
           state.current().stack.push(vstr);
           state.current().stack.push(vint);
-          return state.eval_error("OUT OF BOUNDS", w)
+          return state.eval_error(ErrorKind::OutOfBounds, w)
         }
         i -= 1
       }
@@ -90,7 +90,7 @@ pub fn cog_gcut(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vint2 = stack.pop().unwrap();
   let vint1 = stack.pop().unwrap();
   let mut vstr = stack.pop().unwrap();
@@ -98,19 +98,19 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
     stack.push(vstr);
     stack.push(vint1);
     stack.push(vint2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint1.value_stack_ref().first().unwrap().is_word() || !vint2.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint1);
     stack.push(vint2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint1);
     state.current().stack.push(vint2);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let string = &mut vstr.value_stack().first_mut().unwrap().vword_mut().str_word;
   let int1 = match math.math().stoi(&vint1.value_stack_ref().first().unwrap().vword_ref().str_word) {
@@ -119,7 +119,7 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     },
     Err(e) => {
       state.set_math(math);
@@ -135,7 +135,7 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     },
     Err(e) => {
       state.set_math(math);
@@ -158,7 +158,7 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let mut iter = string.grapheme_indices(true);
@@ -167,7 +167,7 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let i1 = match iter.next() {
@@ -179,7 +179,7 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let i2 = match iter.next() {
@@ -196,18 +196,18 @@ pub fn cog_gsubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_glen(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v_stack = v.value_stack_ref();
-  if v_stack.len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if v_stack.len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let word_v = v_stack.first().unwrap();
-  if !word_v.is_word() { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if !word_v.is_word() { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let length = word_v.vword_ref().str_word.graphemes(true).count();
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
   if math.math().base() == 1 && length != 0 {
-    return state.with_math(math).eval_error("MATH BASE ONE", w)
+    return state.with_math(math).eval_error(ErrorKind::MathBaseOne, w)
   }
-  if length > isize::MAX as usize { return state.with_math(math).eval_error("OUT OF BOUNDS", w) }
+  if length > isize::MAX as usize { return state.with_math(math).eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(length as isize, &mut state) {
     Ok(s) => {
       state.set_math(math);
@@ -223,23 +223,23 @@ pub fn cog_glen(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_gat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut vint = stack.pop().unwrap();
   let vstr = stack.pop().unwrap();
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let vstr_stack = vstr.value_stack_ref();
   let string = &vstr_stack.first().unwrap().vword_ref().str_word;
@@ -249,7 +249,7 @@ pub fn cog_gat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
     Ok(i) => if i < 0 || i.abs() as usize >= string.len() {
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       i as usize
     },
@@ -262,7 +262,7 @@ pub fn cog_gat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   if !string.is_char_boundary(int) {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("INVALID CHAR BOUNDARY", w)
+    return state.eval_error(ErrorKind::InvalidCharBoundary, w)
   }
   let s = &mut vint.value_stack().first_mut().unwrap().vword_mut().str_word;
   s.clear();
@@ -274,23 +274,23 @@ pub fn cog_gat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_gnth(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut vint = stack.pop().unwrap();
   let vstr = stack.pop().unwrap();
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let vstr_stack = vstr.value_stack_ref();
   let string = &vstr_stack.first().unwrap().vword_ref().str_word;
@@ -300,7 +300,7 @@ pub fn cog_gnth(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     Ok(i) => if i < 0 || i.abs() as usize >= string.len() {
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       i as usize
     },
@@ -321,7 +321,7 @@ pub fn cog_gnth(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     None => {
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   state.current().stack.push(vstr);
@@ -330,11 +330,11 @@ pub fn cog_gnth(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_greplace(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 4 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 4 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
-  if i > j { return state.eval_error("OUT OF BOUNDS", w) }
+  if i > j { return state.eval_error(ErrorKind::OutOfBounds, w) }
   let j_val = state.current().stack.pop().unwrap();
   let i_val = state.current().stack.pop().unwrap();
   let (mut v1, v2) = get_2_words!(state, w, {
@@ -351,7 +351,7 @@ pub fn cog_greplace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
       stack.push(v2);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let int1 = match iter.next() {
@@ -364,7 +364,7 @@ pub fn cog_greplace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
       stack.push(v2);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let int2 = match iter.next() {
@@ -380,7 +380,7 @@ pub fn cog_greplace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 }
 
 pub fn cog_gslice(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
@@ -398,7 +398,7 @@ pub fn cog_gslice(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       stack.push(v1);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let int1 = match iter.next() {
@@ -410,7 +410,7 @@ pub fn cog_gslice(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       stack.push(v1);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   if i >= j {
@@ -436,14 +436,14 @@ pub fn cog_gslice(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_greverse(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Value::Word(vw) = v.value_stack_ref().first().unwrap() else {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut new_vword = state.pool.get_vword(vw.str_word.len());
   for g in vw.str_word.graphemes(true).rev() {
@@ -457,25 +457,25 @@ pub fn cog_greverse(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 
 pub fn cog_gtoi(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let char_v = v.value_stack().first_mut().unwrap();
   let Value::Word(vword_char) = char_v else {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut graphemes = vword_char.str_word.graphemes(true);
   let g = graphemes.next();
   if g.is_none() || graphemes.next().is_some() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let Some(math) = state.get_math() else {
     state.current().stack.push(v);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let mut agg: isize = 0;
   for c in g.unwrap().chars().rev() {
@@ -146,7 +146,7 @@ impl Custom for RecvCustom {
     fwrite_check!(f, b"(receiver)");
   }
   fn copyfunc(&self, state: &mut CognitionState) -> Box<dyn Custom> {
-    state.eval_error_mut("RECEIVER CANNOT BE DUPLICATED", None);
+    state.eval_error_other_mut("RECEIVER CANNOT BE DUPLICATED", None);
     Box::new(Void{})
   }
   fn custom_pool(&mut self, pool: &mut Pool) -> CustomPoolPackage {
@@ -51,10 +51,10 @@ fn new_cogstate(state: &mut CognitionState, mut v: Value) -> CognitionState {
 // Returns a custom thread handler type
 pub fn cog_spawn(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if !v.is_stack() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let wrapper = CogStateWrapper(new_cogstate(&mut state, v));
   let callword = w.map(|v| ValueWrapper(state.value_copy(v)));
@@ -70,10 +70,10 @@ pub fn cog_spawn(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_try_spawn(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if !v.is_stack() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let wrapper = CogStateWrapper(new_cogstate(&mut state, v));
   let callword = w.map(|v| ValueWrapper(state.value_copy(v)));
@@ -88,19 +88,19 @@ pub fn cog_try_spawn(mut state: CognitionState, w: Option<&Value>) -> CognitionS
       state.push_quoted(Value::Custom(vcustom));
       state
     },
-    Err(_) => state.eval_error("FALIED TO SPAWN THREAD (OS ERROR)", w)
+    Err(_) => state.eval_error_other("FALIED TO SPAWN THREAD (OS ERROR)", w)
   }
 }
 
 pub fn cog_spawn_named(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vname = get_word!(state, w);
   let stack = &mut state.current().stack;
   let v = stack.pop().unwrap();
   if !v.is_stack() {
     stack.push(v);
     stack.push(vname);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let name = state.string_copy(&vname.value_stack_ref().first().unwrap().vword_ref().str_word);
   state.pool.add_val(vname);
@@ -117,14 +117,14 @@ pub fn cog_spawn_named(mut state: CognitionState, w: Option<&Value>) -> Cognitio
 }
 
 pub fn cog_try_spawn_named(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vname = get_word!(state, w);
   let stack = &mut state.current().stack;
   let v = stack.pop().unwrap();
   if !v.is_stack() {
     stack.push(v);
     stack.push(vname);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let name = state.string_copy(&vname.value_stack_ref().first().unwrap().vword_ref().str_word);
   state.pool.add_val(vname);
@@ -141,7 +141,7 @@ pub fn cog_try_spawn_named(mut state: CognitionState, w: Option<&Value>) -> Cogn
       state.push_quoted(Value::Custom(vcustom));
       state
     },
-    Err(_) => state.eval_error("FALIED TO SPAWN THREAD (OS ERROR)", w)
+    Err(_) => state.eval_error_other("FALIED TO SPAWN THREAD (OS ERROR)", w)
   }
 }
 
@@ -205,17 +205,17 @@ pub fn cog_thread(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
             join_states(&mut state, handle.join().expect("Thread panicked").0);
             break 'return_ok true
           } else {
-            state = state.eval_error("NULL THREAD", w);
+            state = state.eval_error_other("NULL THREAD", w);
             break 'return_ok false
           }
         },
         Err(_) => {
-          state = state.eval_error("POISONED THREAD", w);
+          state = state.eval_error_other("POISONED THREAD", w);
           break 'return_ok false
         }
       }
     }
-    state = state.eval_error("BAD ARGUMENT TYPE", w);
+    state = state.eval_error(ErrorKind::BadArgumentType, w);
     false
   } {
     state.pool.add_val(v);
@@ -235,26 +235,26 @@ pub fn cog_channel(mut state: CognitionState, _: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_send(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vtx = get_custom!(state, w);
   let v = state.current().stack.pop().unwrap();
   if v.value_stack_ref().len() != 1 {
     state.current().stack.push(v);
     state.current().stack.push(vtx);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   state.current().stack.push(v);
   let vcustom = vtx.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(send_custom) = vcustom.custom.as_any().downcast_ref::<SendCustom>() else {
     state.current().stack.push(vtx);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let sender = send_custom.tx.as_ref().expect("uninitialized SendCustom on stack");
   let vdata = state.current().stack.pop().unwrap();
   if let Err(e) = sender.send(ValueWrapper(vdata)) {
     state.current().stack.push(e.0.0);
     state.current().stack.push(vtx);
-    return state.eval_error("DISCONNECTED CHANNEL", w)
+    return state.eval_error_other("DISCONNECTED CHANNEL", w)
   }
   state.pool.add_val(vtx);
   state
@@ -265,12 +265,12 @@ pub fn cog_recv(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let vcustom = vrx.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(recv_custom) = vcustom.custom.as_any().downcast_ref::<RecvCustom>() else {
     state.current().stack.push(vrx);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let receiver = recv_custom.rx.as_ref().expect("uninitialized RecvCustom on stack");
   match receiver.recv() {
     Ok(value) => state.current().stack.push(value.0),
-    Err(_) => state.eval_error_mut("DISCONNECTED CHANNEL", w)
+    Err(_) => state.eval_error_other_mut("DISCONNECTED CHANNEL", w)
   }
   state.current().stack.push(vrx);
   state
@@ -281,20 +281,20 @@ pub fn cog_try_recv(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   let vcustom = vrx.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(recv_custom) = vcustom.custom.as_any().downcast_ref::<RecvCustom>() else {
     state.current().stack.push(vrx);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let receiver = recv_custom.rx.as_ref().expect("uninitialized RecvCustom on stack");
   match receiver.try_recv() {
     Ok(value) => state.current().stack.push(value.0),
     Err(TryRecvError::Empty) => state = builtins::combinators::cog_stack(state, w),
-    Err(TryRecvError::Disconnected) => state.eval_error_mut("DISCONNECTED CHANNEL", w)
+    Err(TryRecvError::Disconnected) => state.eval_error_other_mut("DISCONNECTED CHANNEL", w)
   }
   state.current().stack.push(vrx);
   state
 }
 
 pub fn cog_recv_timeout(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vd = get_custom!(state, w);
   let vrx = get_custom!(state, w, { state.current().stack.push(vd) });
   let vd_custom = vd.value_stack_ref().first().unwrap().vcustom_ref();
@@ -304,13 +304,13 @@ pub fn cog_recv_timeout(mut state: CognitionState, w: Option<&Value>) -> Cogniti
   let (Some(duration_custom), Some(recv_custom)) = (duration_custom, recv_custom) else {
     state.current().stack.push(vrx);
     state.current().stack.push(vd);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let receiver = recv_custom.rx.as_ref().expect("uninitialized RecvCustom on stack");
   match receiver.recv_timeout(duration_custom.duration) {
     Ok(value) => state.current().stack.push(value.0),
     Err(RecvTimeoutError::Timeout) => state = builtins::combinators::cog_stack(state, w),
-    Err(RecvTimeoutError::Disconnected) => state.eval_error_mut("DISCONNECTED CHANNEL", w)
+    Err(RecvTimeoutError::Disconnected) => state.eval_error_other_mut("DISCONNECTED CHANNEL", w)
   }
   state.pool.add_val(vd);
   state.current().stack.push(vrx);
@@ -318,7 +318,7 @@ pub fn cog_recv_timeout(mut state: CognitionState, w: Option<&Value>) -> Cogniti
 }
 
 pub fn cog_share(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let shared_vcustom = get_shared_custom(&mut state.pool, Some(v));
   state.push_quoted(Value::Custom(shared_vcustom));
   state
@@ -329,7 +329,7 @@ pub fn cog_steal(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let vcustom = v.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(shared_custom) = vcustom.custom.as_any().downcast_ref::<SharedCustom>() else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let mut lock = shared_custom.value.as_ref().expect("uninitialized SharedCustom on stack").lock();
   match &mut lock {
@@ -343,24 +343,24 @@ pub fn cog_steal(mut state: CognitionState, w: Option<&Value>) -> CognitionState
       None => {
         drop(lock);
         state.current().stack.push(v);
-        state.eval_error("NULL SHARED", w)
+        state.eval_error_other("NULL SHARED", w)
       }
     },
     Err(_) => {
       drop(lock);
       state.current().stack.push(v);
-      state.eval_error("POISONED SHARED", w)
+      state.eval_error_other("POISONED SHARED", w)
     }
   }
 }
 
 pub fn cog_give(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vshare = get_custom!(state, w);
   let vcustom = vshare.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(shared_custom) = vcustom.custom.as_any().downcast_ref::<SharedCustom>() else {
     state.current().stack.push(vshare);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let mut lock = shared_custom.value.as_ref().expect("uninitialized SharedCustom on stack").lock();
   match &mut lock {
@@ -377,7 +377,7 @@ pub fn cog_give(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     Err(_) => {
       drop(lock);
       state.current().stack.push(vshare);
-      state.eval_error("POISONED SHARED", w)
+      state.eval_error_other("POISONED SHARED", w)
     }
   }
 }
@@ -387,7 +387,7 @@ pub fn cog_stolen_questionmark(mut state: CognitionState, w: Option<&Value>) ->
   let vcustom = v.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(shared_custom) = vcustom.custom.as_any().downcast_ref::<SharedCustom>() else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let lock = shared_custom.value.as_ref().expect("uninitialized SharedCustom on stack").lock();
   let vword = if let Ok(true) = lock.map(|mutex_guard| mutex_guard.is_none()) {
@@ -407,7 +407,7 @@ pub fn cog_poisoned_questionmark(mut state: CognitionState, w: Option<&Value>) -
   let vcustom = v.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(shared_custom) = vcustom.custom.as_any().downcast_ref::<SharedCustom>() else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let lock = shared_custom.value.as_ref().expect("uninitialized SharedCustom on stack").lock();
   let vword = if lock.is_err() {
@@ -428,7 +428,7 @@ pub fn cog_clear_poison(mut state: CognitionState, w: Option<&Value>) -> Cogniti
   let vcustom = v.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(shared_custom) = vcustom.custom.as_any().downcast_ref::<SharedCustom>() else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   shared_custom.value.as_ref().expect("uninitialized SharedCustom on stack").clear_poison();
   state.current().stack.push(v);
@@ -436,13 +436,13 @@ pub fn cog_clear_poison(mut state: CognitionState, w: Option<&Value>) -> Cogniti
 }
 
 pub fn cog_eval_shared(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   state = cog_swap(state, None);
   let v = get_custom!(state, w);
   let vcustom = v.value_stack_ref().first().unwrap().vcustom_ref();
   let Some(shared_custom) = vcustom.custom.as_any().downcast_ref::<SharedCustom>() else {
     state.current().stack.push(v);
-    return cog_swap(state, None).eval_error("BAD ARGUMENT TYPE", w);
+    return cog_swap(state, None).eval_error(ErrorKind::BadArgumentType, w);
   };
   let mut lock = shared_custom.value.as_ref().expect("uninitialized SharedCustom on stack").lock();
   match &mut lock {
@@ -459,13 +459,13 @@ pub fn cog_eval_shared(mut state: CognitionState, w: Option<&Value>) -> Cognitio
       None => {
         drop(lock);
         state.current().stack.push(v);
-        cog_swap(state, None).eval_error("NULL SHARED", w)
+        cog_swap(state, None).eval_error_other("NULL SHARED", w)
       }
     },
     Err(_) => {
       drop(lock);
       state.current().stack.push(v);
-      cog_swap(state, None).eval_error("POISONED SHARED", w)
+      cog_swap(state, None).eval_error_other("POISONED SHARED", w)
     }
   }
 }
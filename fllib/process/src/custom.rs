@@ -24,7 +24,7 @@ impl Custom for ChildCustom {
     fwrite_check!(f, b"(child)");
   }
   fn copyfunc(&self, state: &mut CognitionState) -> Box<dyn Custom> {
-    state.eval_error_mut("CHILD CANNOT BE DUPLICATED", None);
+    state.eval_error_other_mut("CHILD CANNOT BE DUPLICATED", None);
     Box::new(Void{})
   }
   fn custom_pool(&mut self, pool: &mut Pool) -> CustomPoolPackage {
@@ -10,16 +10,16 @@ use std::ffi::OsString;
 use time::DurationCustom;
 
 pub fn cog_sleep(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) };
+  let Some(v) = state.current().stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) };
   let duration = match v.value_stack_ref().first().unwrap() {
     Value::Custom(vcustom) => match unsafe { vcustom.custom.as_custom_ref::<DurationCustom>() } {
       Some(d) => if d.neg { Duration::ZERO } else { d.duration.clone() },
-      None => return state.eval_error("BAD ARGUMENT TYPE", w)
+      None => return state.eval_error(ErrorKind::BadArgumentType, w)
     },
     _ => {
       let i = get_unsigned!(state, w, isize, ACTIVE) as usize;
-      if i > u64::MAX as usize { return state.eval_error("OUT OF BOUNDS", w) }
+      if i > u64::MAX as usize { return state.eval_error(ErrorKind::OutOfBounds, w) }
       Duration::from_secs(i as u64)
     }
   };
@@ -73,17 +73,17 @@ fn push_all(state: &mut CognitionState, vio: Value, venv: Value, vcur: Value, va
 
 fn bad_argument(mut state: CognitionState, w: Option<&Value>, vio: Value, venv: Value, vcur: Value, varg: Value, vcmd: Value) -> CognitionState {
   push_all(&mut state, vio, venv, vcur, varg, vcmd);
-  state.eval_error("BAD ARGUMENT TYPE", w)
+  state.eval_error(ErrorKind::BadArgumentType, w)
 }
 
 fn bad_file_io(mut state: CognitionState, w: Option<&Value>, vio: Value, venv: Value, vcur: Value, varg: Value, vcmd: Value) -> CognitionState {
   push_all(&mut state, vio, venv, vcur, varg, vcmd);
-  state.eval_error("BAD FILE DESCRIPTOR", w)
+  state.eval_error_other("BAD FILE DESCRIPTOR", w)
 }
 
 fn get_spawn_args(mut state: CognitionState, w: Option<&Value>)
                       -> Result<(CognitionState, Value, Value, Value, Value, Value, ChildStdio), CognitionState> {
-  if state.current_ref().stack.len() < 5 { return Err(state.eval_error("TOO FEW ARGUMENTS", w)) }
+  if state.current_ref().stack.len() < 5 { return Err(state.eval_error(ErrorKind::TooFewArguments, w)) }
   let stack = &mut state.current().stack;
   let vio = stack.pop().unwrap();
   let venv = stack.pop().unwrap();
@@ -147,6 +147,7 @@ fn get_spawn_args(mut state: CognitionState, w: Option<&Value>)
 
 // "command" [arg1,arg2,...] "current-dir" [[env,val],[env,val],...] [<stdin,stdout,stderr>] spawn -- [ (child) ]
 pub fn cog_spawn(state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Process);
   let (mut state, vcmd, varg, vcur, venv, vio, stdio) = match get_spawn_args(state, w) {
     Ok(results) => results, Err(state) => return state
   };
@@ -163,7 +164,7 @@ pub fn cog_spawn(state: CognitionState, w: Option<&Value>) -> CognitionState {
   }
   let Ok(child) = command.spawn() else {
     push_all(&mut state, vio, venv, vcur, varg, vcmd);
-    return state.eval_error("FAILED TO SPAWN PROCESS", w)
+    return state.eval_error_other("FAILED TO SPAWN PROCESS", w)
   };
   state.pool.add_val(vcmd);
   state.pool.add_val(varg);
@@ -180,15 +181,15 @@ pub fn cog_wait(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let custom = v.value_stack().first_mut().unwrap().vcustom_mut().custom.as_any_mut();
   let Some(child_custom) = custom.downcast_mut::<ChildCustom>() else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let Ok(exit_status) = child_custom.child.wait() else {
     state.current().stack.push(v);
-    return state.eval_error("CHILD NOT RUNNING", w)
+    return state.eval_error_other("CHILD NOT RUNNING", w)
   };
   state.current().stack.push(v);
   if let Some(code) = exit_status.code() {
-    let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
+    let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
     match math.math().itos(code as isize, &mut state) {
       Ok(s) => {
         let mut v = state.pool.get_vword(s.len());
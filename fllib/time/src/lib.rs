@@ -5,10 +5,10 @@ use cognition::*;
 
 macro_rules! duration_from {
   ($state:ident,$w:ident,$from:expr) => {
-    let i = get_int!($state, $w, isize, ACTIVE, "DURATION OVERFLOW");
+    let i = get_int!($state, $w, isize, ACTIVE);
     let neg = i < 0;
     let i = if neg { -i } else { i } as usize;
-    if i > u64::MAX as usize { return $state.eval_error("DURATION OVERFLOW", $w) }
+    if i > u64::MAX as usize { return $state.eval_error_other("DURATION OVERFLOW", $w) }
     let v = $state.current().stack.pop().unwrap();
     $state.pool.add_val(v);
     let vcustom = get_duration_custom(&mut $state.pool, $from(i as u64), neg);
@@ -22,22 +22,22 @@ macro_rules! duration_as {
     let custom = v.value_stack_ref().first().unwrap().vcustom_ref().custom.as_any();
     let Some(duration_custom) = custom.downcast_ref::<DurationCustom>() else {
       $state.current().stack.push(v);
-      return $state.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state.eval_error(ErrorKind::BadArgumentType, $w)
     };
     let Some(math) = $state.get_math() else {
       $state.current().stack.push(v);
-      return $state.eval_error("MATH BASE ZERO", $w)
+      return $state.eval_error(ErrorKind::MathBaseZero, $w)
     };
     if math.math().base() == 0 {
       $state.set_math(math);
       $state.current().stack.push(v);
-      return $state.eval_error("MATH BASE ZERO", $w)
+      return $state.eval_error(ErrorKind::MathBaseZero, $w)
     }
     let i = duration_custom.duration.$to() as u128;
     if i > isize::MAX as u128 {
       $state.set_math(math);
       $state.current().stack.push(v);
-      return $state.eval_error("INTEGER OVERFLOW", $w)
+      return $state.eval_error_other("INTEGER OVERFLOW", $w)
     }
     let i = if duration_custom.neg { (i as isize) * -1 } else { i as isize };
     let mut s = match math.math().itos(i, &mut $state) {
@@ -76,7 +76,7 @@ macro_rules! add_durations {
       let Some(dsum) = $d1.duration.checked_add($d2.duration) else {
         $state.current().stack.push($v1);
         $state.current().stack.push($v2);
-        return $state.eval_error("DURATION OVERFLOW", $w)
+        return $state.eval_error_other("DURATION OVERFLOW", $w)
       };
       $d2.duration = dsum;
     }
@@ -113,7 +113,7 @@ pub fn cog_duration_zero_questionmark(mut state: CognitionState, w: Option<&Valu
   get_custom!(state, w, ACTIVE);
   let vcustom = state.current().stack.last().unwrap().value_stack_ref().first().unwrap().vcustom_ref();
   let Some(d) = vcustom.custom.as_any().downcast_ref::<DurationCustom>() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let vword = if d.duration.is_zero() {
     let mut vword = state.pool.get_vword(1);
@@ -124,7 +124,7 @@ pub fn cog_duration_zero_questionmark(mut state: CognitionState, w: Option<&Valu
 }
 
 pub fn cog_add_duration(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut v2 = get_custom!(state, w);
   let v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack().first_mut().unwrap().vcustom_mut().custom.as_any_mut();
@@ -132,7 +132,7 @@ pub fn cog_add_duration(mut state: CognitionState, w: Option<&Value>) -> Cogniti
   let (Some(d1), Some(d2)) = (v1custom.downcast_ref::<DurationCustom>(), v2custom.downcast_mut::<DurationCustom>()) else {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   add_durations!(state, w, v1, v2, d1, d2);
   state.pool.add_val(v1);
@@ -141,7 +141,7 @@ pub fn cog_add_duration(mut state: CognitionState, w: Option<&Value>) -> Cogniti
 }
 
 pub fn cog_sub_duration(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut v2 = get_custom!(state, w);
   let v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack().first_mut().unwrap().vcustom_mut().custom.as_any_mut();
@@ -149,7 +149,7 @@ pub fn cog_sub_duration(mut state: CognitionState, w: Option<&Value>) -> Cogniti
   let (Some(d1), Some(d2)) = (v1custom.downcast_ref::<DurationCustom>(), v2custom.downcast_mut::<DurationCustom>()) else {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   d2.neg = !d2.neg;
   add_durations!(state, w, v1, v2, d1, d2);
@@ -159,7 +159,7 @@ pub fn cog_sub_duration(mut state: CognitionState, w: Option<&Value>) -> Cogniti
 }
 
 pub fn cog_duration_sum_overload(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 4 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 4 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v4 = state.current().stack.pop().unwrap();
   let v3 = state.current().stack.pop().unwrap();
   let v2 = state.current().stack.pop().unwrap();
@@ -183,7 +183,7 @@ pub fn cog_duration_sum_overload(mut state: CognitionState, w: Option<&Value>) -
 }
 
 fn add_duration(mut state: CognitionState, w: Option<&Value>, add: bool) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_custom!(state, w);
   let mut v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack_ref().first().unwrap().vcustom_ref().custom.as_any();
@@ -206,7 +206,7 @@ fn add_duration(mut state: CognitionState, w: Option<&Value>, add: bool) -> Cogn
     } else {
       state.current().stack.push(v1);
       state.current().stack.push(v2);
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     }
     state.pool.add_val(v2);
     state.current().stack.push(v1);
@@ -215,9 +215,9 @@ fn add_duration(mut state: CognitionState, w: Option<&Value>, add: bool) -> Cogn
   state.current().stack.push(v1);
   state.current().stack.push(v2);
   if overflow {
-    state.eval_error("INSTANT OVERFLOW", w)
+    state.eval_error_other("INSTANT OVERFLOW", w)
   } else {
-    state.eval_error("INSTANT UNDERFLOW", w)
+    state.eval_error_other("INSTANT UNDERFLOW", w)
   }
 }
 
@@ -229,7 +229,7 @@ pub fn cog_earlier(state: CognitionState, w: Option<&Value>) -> CognitionState {
 }
 
 pub fn cog_duration_since(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_custom!(state, w);
   let v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack_ref().first().unwrap().vcustom_ref().custom.as_any();
@@ -254,7 +254,7 @@ pub fn cog_duration_since(mut state: CognitionState, w: Option<&Value>) -> Cogni
   } else {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let vcustom = get_duration_custom(&mut state.pool, duration, neg);
   state.push_quoted(Value::Custom(vcustom));
@@ -264,7 +264,7 @@ pub fn cog_duration_since(mut state: CognitionState, w: Option<&Value>) -> Cogni
 }
 
 pub fn cog_instant_duration_since(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_custom!(state, w);
   let v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack_ref().first().unwrap().vcustom_ref().custom.as_any();
@@ -281,7 +281,7 @@ pub fn cog_instant_duration_since(mut state: CognitionState, w: Option<&Value>)
   } else {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let vcustom = get_duration_custom(&mut state.pool, duration, neg);
   state.push_quoted(Value::Custom(vcustom));
@@ -291,7 +291,7 @@ pub fn cog_instant_duration_since(mut state: CognitionState, w: Option<&Value>)
 }
 
 pub fn cog_system_time_duration_since(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_custom!(state, w);
   let v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack_ref().first().unwrap().vcustom_ref().custom.as_any();
@@ -308,7 +308,7 @@ pub fn cog_system_time_duration_since(mut state: CognitionState, w: Option<&Valu
   } else {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let vcustom = get_duration_custom(&mut state.pool, duration, neg);
   state.push_quoted(Value::Custom(vcustom));
@@ -329,7 +329,7 @@ pub fn cog_elapsed(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
     }
   } else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   state.pool.add_val(v);
   state.push_quoted(Value::Custom(vcustom));
@@ -343,7 +343,7 @@ pub fn cog_instant_elapsed(mut state: CognitionState, w: Option<&Value>) -> Cogn
     get_duration_custom(&mut state.pool, instant.instant.elapsed(), false)
   } else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   state.pool.add_val(v);
   state.push_quoted(Value::Custom(vcustom));
@@ -360,7 +360,7 @@ pub fn cog_system_time_elapsed(mut state: CognitionState, w: Option<&Value>) ->
     }
   } else {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   state.pool.add_val(v);
   state.push_quoted(Value::Custom(vcustom));
@@ -425,7 +425,7 @@ pub fn cog_system_time_questionmark(mut state: CognitionState, w: Option<&Value>
 }
 
 fn is_later(mut state: CognitionState, w: Option<&Value>, result: &mut bool) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_custom!(state, w);
   let v1 = get_custom!(state, w, { state.current().stack.push(v2) });
   let v2custom = v2.value_stack_ref().first().unwrap().vcustom_ref().custom.as_any();
@@ -437,7 +437,7 @@ fn is_later(mut state: CognitionState, w: Option<&Value>, result: &mut bool) ->
   } else {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   state.current().stack.push(v1);
   state.current().stack.push(v2);
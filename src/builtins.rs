@@ -8,6 +8,7 @@ pub mod metastack;
 pub mod misc;
 pub mod parser;
 pub mod serde;
+pub mod sort;
 pub mod stackops;
 pub mod strings;
 pub mod wordtable;
@@ -26,6 +27,7 @@ pub fn add_builtins(state: &mut CognitionState) {
   misc::add_builtins(state);
   parser::add_builtins(state);
   serde::add_builtins(state);
+  sort::add_builtins(state);
   stackops::add_builtins(state);
   strings::add_builtins(state);
   wordtable::add_builtins(state);
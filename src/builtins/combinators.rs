@@ -1,13 +1,13 @@
 use crate::*;
 
 pub fn cog_eval(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() == 0 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() == 0 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   state.control.eval();
   state
 }
 
 pub fn cog_quote(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let mut wrapper = state.pool.get_vstack(1);
   wrapper.container.stack.push(v);
   state.current().stack.push(Value::Stack(wrapper));
@@ -45,10 +45,10 @@ pub fn cog_sub(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
 
 pub fn cog_cast(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v2) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v2) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let Some(v1) = stack.pop() else {
     stack.push(v2);
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   let v2stack = v2.value_stack();
   if v2stack.len() == 1 {
@@ -80,13 +80,13 @@ pub fn cog_cast(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   }
   stack.push(v1);
   stack.push(v2);
-  state.eval_error("BAD ARGUMENT TYPE", w)
+  state.eval_error(ErrorKind::BadArgumentType, w)
 }
 
 pub fn cog_compose(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v2) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  let Some(v1) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v2) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  let Some(v1) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   v2.value_stack().reverse();
   while let Some(v) = v2.value_stack().pop() {
     v1.value_stack().push(v);
@@ -97,8 +97,8 @@ pub fn cog_compose(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 
 pub fn cog_prepose(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v2) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  let Some(v1) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v2) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  let Some(v1) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   std::mem::swap(v1.value_stack(), v2.value_stack());
   v2.value_stack().reverse();
   while let Some(v) = v2.value_stack().pop() {
@@ -109,7 +109,7 @@ pub fn cog_prepose(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_displace(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 4 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 4 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
@@ -123,7 +123,7 @@ pub fn cog_displace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
     stack.push(v2);
     stack.push(i_val);
     stack.push(j_val);
-    return state.eval_error("OUT OF BOUNDS", w)
+    return state.eval_error(ErrorKind::OutOfBounds, w)
   }
   let iter = v1.value_stack().splice(i..j, v2.value_stack().drain(..));
   let mut tmp_stack = state.pool.get_stack(j - i);
@@ -137,13 +137,13 @@ pub fn cog_displace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 }
 
 pub fn cog_invert(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   v.value_stack().reverse();
   state
 }
 
 pub fn cog_dip(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vdip = state.current().stack.pop().unwrap();
   let v = state.current().stack.pop().unwrap();
   state.current().stack.push(vdip);
@@ -156,7 +156,7 @@ pub fn cog_dip(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_if(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v2 = stack.pop().unwrap();
   let v1 = stack.pop().unwrap();
   let v_truth = stack.pop().unwrap();
@@ -164,13 +164,13 @@ pub fn cog_if(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
     stack.push(v_truth);
     stack.push(v1);
     stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Value::Word(vword_truth) = v_truth.value_stack_ref().first().unwrap() else {
     stack.push(v_truth);
     stack.push(v1);
     stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let truth = vword_truth.str_word.len() > 0;
   state.pool.add_val(v_truth);
@@ -186,7 +186,7 @@ pub fn cog_if(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 }
 
 pub fn cog_split(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let i = get_unsigned!(state, w, isize, ACTIVE) as usize;
   let stack = &mut state.current().stack;
   let i_val = stack.pop().unwrap();
@@ -194,7 +194,7 @@ pub fn cog_split(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   if i > v1.value_stack_ref().len() {
     stack.push(v1);
     stack.push(i_val);
-    return state.eval_error("OUT OF BOUNDS", w)
+    return state.eval_error(ErrorKind::OutOfBounds, w)
   }
   state.pool.add_val(i_val);
   let mut new_v = if let Value::Stack(ref vstack) = v1 {
@@ -216,7 +216,7 @@ pub fn cog_split(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_vat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let i = get_unsigned!(state, w, isize, ACTIVE) as usize;
   let stack = &mut state.current().stack;
   let i_val = stack.pop().unwrap();
@@ -224,7 +224,7 @@ pub fn cog_vat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   if i >= v1.value_stack_ref().len() {
     stack.push(v1);
     stack.push(i_val);
-    return state.eval_error("OUT OF BOUNDS", w)
+    return state.eval_error(ErrorKind::OutOfBounds, w)
   }
   state.pool.add_val(i_val);
   let v_new = state.value_copy(&v1.value_stack_ref()[i]);
@@ -234,9 +234,9 @@ pub fn cog_vat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 }
 
 pub fn cog_substack(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
-  if i < 0 || j < 0 { return state.eval_error("OUT OF BOUNDS", w) }
+  if i < 0 || j < 0 { return state.eval_error(ErrorKind::OutOfBounds, w) }
   let i = i as usize;
   let j = j as usize;
   let stack = &mut state.current().stack;
@@ -248,7 +248,7 @@ pub fn cog_substack(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
     stack.push(v1);
     stack.push(i_val);
     stack.push(j_val);
-    return state.eval_error("OUT OF BOUNDS", w)
+    return state.eval_error(ErrorKind::OutOfBounds, w)
   }
   state.pool.add_val(i_val);
   state.pool.add_val(j_val);
@@ -268,7 +268,7 @@ pub fn cog_substack(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 // Empty stack is pushed after elements which
 // retains the properties of the original stack
 pub fn cog_uncompose(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(mut v1) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v1) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v1stack = v1.value_stack();
   while let Some(v) = v1stack.pop() {
     state.push_quoted(v)
@@ -279,7 +279,7 @@ pub fn cog_uncompose(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 
 pub fn cog_decompose(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let Some(Value::Stack(mut vstack)) = state.current().stack.pop() else {
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   state.ensure_quoted(&mut vstack.container.stack);
   state.current().stack.push(Value::Stack(vstack));
@@ -287,11 +287,11 @@ pub fn cog_decompose(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 }
 
 pub fn cog_size(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() == 0 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() == 0 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let length = state.current_ref().stack.last().unwrap().value_stack_ref().len();
-  let Some(mathborrower) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if mathborrower.math().base() == 0 { return state.with_math(mathborrower).eval_error("MATH BASE ZERO", w) }
-  if length > isize::MAX as usize { return state.with_math(mathborrower).eval_error("OUT OF BOUNDS", w) }
+  let Some(mathborrower) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if mathborrower.math().base() == 0 { return state.with_math(mathborrower).eval_error(ErrorKind::MathBaseZero, w) }
+  if length > isize::MAX as usize { return state.with_math(mathborrower).eval_error(ErrorKind::OutOfBounds, w) }
   match mathborrower.math().itos(length as isize, &mut state) {
     Ok(s) => {
       state.set_math(mathborrower);
@@ -309,7 +309,7 @@ pub fn cog_size(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_type(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let mut vword = state.pool.get_vword(6);
   if v.is_stack() {
     vword.str_word.push_str("VSTACK")
@@ -321,6 +321,360 @@ pub fn cog_type(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   state
 }
 
+fn push_element(state: &mut CognitionState, v: Value) {
+  if v.is_stack() || v.is_macro() { state.current().stack.push(v) }
+  else { state.push_quoted(v) }
+}
+
+fn word_truth(v: &Value) -> Option<bool> {
+  let stack = v.value_stack_ref();
+  if stack.len() != 1 || !stack[0].is_word() { return None }
+  Some(!stack[0].vword_ref().str_word.is_empty())
+}
+
+// Evaluates a quote inside a loop. False if the loop should stop because the
+// quote returned, exited, or left the container it was evaluated in, or a
+// limit was hit. Every iteration spends a step of fuel, so that even an empty
+// quote can't loop past a fuel limit.
+pub(crate) fn eval_step(mut state: CognitionState, wd: &WordDef, w: Option<&Value>, depth: usize) -> (CognitionState, bool) {
+  if !state.check_limits(w) { return (state, false) }
+  let state = state.evalstack(wd.clone(), w, false);
+  let go = !state.exited && !state.control.is_return() && state.stack.len() == depth;
+  (state, go)
+}
+
+/// The stack of the container a quote is evaluated in, swapped out for a
+/// scratch stack by swap_stack and put back by restore_stack
+pub(crate) struct SavedStack {
+  stack: Stack,
+  container: *const VStack,
+}
+
+pub(crate) fn swap_stack(state: &mut CognitionState, scratch: Stack) -> SavedStack {
+  let container: *const VStack = &**state.stack.last().expect("Cognition metastack was empty").vstack_ref();
+  SavedStack{ stack: std::mem::replace(&mut state.current().stack, scratch), container }
+}
+
+// Containers are boxed, so the one a stack was saved from keeps its address
+// if the quote cd'd out of it, leaving it on the metastack, or uncd'd out of
+// it, leaving it on the stack it went up to
+pub(crate) fn find_container(state: &mut CognitionState, container: *const VStack) -> Option<&mut Container> {
+  let is = |v: &Value| v.is_stack() && std::ptr::eq(&**v.vstack_ref(), container);
+  if let Some(i) = state.stack.iter().rposition(is) {
+    return Some(&mut state.stack[i].vstack_mut().container)
+  }
+  let stack = &mut state.current().stack;
+  let i = stack.iter().rposition(is)?;
+  Some(&mut stack[i].vstack_mut().container)
+}
+
+/// Puts a saved stack back into the container it came from, dropping
+/// whatever the quote left in its place. If the quote dropped the container,
+/// the saved stack goes with it.
+pub(crate) fn restore_stack(state: &mut CognitionState, saved: SavedStack) {
+  let SavedStack{ stack, container } = saved;
+  let mut rest = match find_container(state, container) {
+    Some(container) => std::mem::replace(&mut container.stack, stack),
+    None => stack
+  };
+  for v in rest.drain(..) { state.pool.add_val(v) }
+  state.pool.add_stack(rest);
+}
+
+fn new_like(state: &mut CognitionState, v: &Value, capacity: usize) -> Value {
+  if let Value::Stack(vstack) = v {
+    let mut new_vstack = state.pool.get_vstack(capacity);
+    state.contain_copy_attributes(&vstack.container, &mut new_vstack.container);
+    Value::Stack(new_vstack)
+  } else {
+    Value::Macro(state.pool.get_vmacro(capacity))
+  }
+}
+
+// ( stack quote -- ) evaluates the quote with each element pushed in turn
+pub fn cog_each(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  if !stack[stack.len() - 2].is_stack() && !stack[stack.len() - 2].is_macro() {
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let v_quote = stack.pop().unwrap();
+  let mut v = stack.pop().unwrap();
+  let wd = state.pool.get_word_def(v_quote);
+  let depth = state.stack.len();
+  let elements = v.value_stack();
+  elements.reverse();
+  while let Some(x) = elements.pop() {
+    push_element(&mut state, x);
+    let go;
+    (state, go) = eval_step(state, &wd, w, depth);
+    if !go { break }
+  }
+  state.pool.add_word_def(wd);
+  state.pool.add_val(v);
+  state
+}
+
+// ( quote n -- ) evaluates the quote n times
+pub fn cog_times(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let n = get_unsigned!(state, w, isize) as usize;
+  let v_quote = state.current().stack.pop().unwrap();
+  let wd = state.pool.get_word_def(v_quote);
+  let depth = state.stack.len();
+  for _ in 0..n {
+    let go;
+    (state, go) = eval_step(state, &wd, w, depth);
+    if !go { break }
+  }
+  state.pool.add_word_def(wd);
+  state
+}
+
+// ( cond body -- ) evaluates the body for as long as the condition leaves a
+// nonempty word
+pub fn cog_while(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let v_body = stack.pop().unwrap();
+  let v_cond = stack.pop().unwrap();
+  let wd_body = state.pool.get_word_def(v_body);
+  let wd_cond = state.pool.get_word_def(v_cond);
+  let depth = state.stack.len();
+  loop {
+    let go;
+    (state, go) = eval_step(state, &wd_cond, w, depth);
+    if !go { break }
+    let Some(v) = state.current().stack.pop() else {
+      state.eval_error_mut(ErrorKind::TooFewArguments, w);
+      break
+    };
+    let truth = word_truth(&v);
+    state.pool.add_val(v);
+    match truth {
+      Some(true) => {},
+      Some(false) => break,
+      None => { state.eval_error_mut(ErrorKind::BadArgumentType, w); break }
+    }
+    let go;
+    (state, go) = eval_step(state, &wd_body, w, depth);
+    if !go { break }
+  }
+  state.pool.add_word_def(wd_cond);
+  state.pool.add_word_def(wd_body);
+  state
+}
+
+// ( stack quote -- stack ) evaluates the quote on each element by itself, and
+// composes whatever it leaves into the result
+pub fn cog_map(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  if !stack[stack.len() - 2].is_stack() && !stack[stack.len() - 2].is_macro() {
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let v_quote = stack.pop().unwrap();
+  let mut v = stack.pop().unwrap();
+  let wd = state.pool.get_word_def(v_quote);
+  let mut result = new_like(&mut state, &v, v.value_stack_ref().len());
+  let scratch = state.pool.get_stack(DEFAULT_STACK_SIZE);
+  let saved = swap_stack(&mut state, scratch);
+  let depth = state.stack.len();
+  let elements = v.value_stack();
+  elements.reverse();
+  let mut go = true;
+  while let Some(x) = elements.pop() {
+    push_element(&mut state, x);
+    (state, go) = eval_step(state, &wd, w, depth);
+    if !go { break }
+    let mut left = std::mem::take(&mut state.current().stack);
+    for mut y in left.drain(..) {
+      result.value_stack().append(y.value_stack());
+      state.pool.add_val(y);
+    }
+    state.current().stack = left;
+  }
+  restore_stack(&mut state, saved);
+  state.pool.add_word_def(wd);
+  state.pool.add_val(v);
+  if go { state.current().stack.push(result) }
+  else { state.pool.add_val(result) }
+  state
+}
+
+// ( stack quote -- stack ) keeps the elements for which the quote, evaluated
+// on the element by itself, leaves a nonempty word
+pub fn cog_filter(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  if !stack[stack.len() - 2].is_stack() && !stack[stack.len() - 2].is_macro() {
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let v_quote = stack.pop().unwrap();
+  let mut v = stack.pop().unwrap();
+  let wd = state.pool.get_word_def(v_quote);
+  let scratch = state.pool.get_stack(1);
+  let saved = swap_stack(&mut state, scratch);
+  let depth = state.stack.len();
+  let mut keep = Vec::with_capacity(v.value_stack_ref().len());
+  let mut result = Ok(());
+  for x in v.value_stack_ref().iter() {
+    let x = state.value_copy(x);
+    push_element(&mut state, x);
+    let go;
+    (state, go) = eval_step(state, &wd, w, depth);
+    if !go { result = Err(None); break }
+    let stack = &state.current_ref().stack;
+    let truth = if stack.len() == 1 { word_truth(&stack[0]) } else { None };
+    while let Some(y) = state.current().stack.pop() { state.pool.add_val(y) }
+    match truth {
+      Some(t) => keep.push(t),
+      None => { result = Err(Some(ErrorKind::BadArgumentType)); break }
+    }
+  }
+  restore_stack(&mut state, saved);
+  match result {
+    Ok(()) => {
+      let mut new_v = new_like(&mut state, &v, keep.iter().filter(|k| **k).count());
+      for (x, k) in v.value_stack().drain(..).zip(keep) {
+        if k { new_v.value_stack().push(x) }
+        else { state.pool.add_val(x) }
+      }
+      state.pool.add_word_def(wd);
+      state.pool.add_val(v);
+      state.current().stack.push(new_v);
+      state
+    },
+    Err(None) => {
+      state.pool.add_word_def(wd);
+      state.pool.add_val(v);
+      state
+    },
+    Err(Some(e)) => {
+      let v_quote = Arc::try_unwrap(wd).unwrap_or_else(|wd| state.value_copy(&wd));
+      state.current().stack.push(v);
+      state.current().stack.push(v_quote);
+      state.eval_error(e, w)
+    }
+  }
+}
+
+// ( stack init quote -- acc ) evaluates the quote on the accumulator and each
+// element in turn, where it must leave exactly the new accumulator
+pub fn cog_fold(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  if !stack[stack.len() - 3].is_stack() && !stack[stack.len() - 3].is_macro() {
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let v_quote = stack.pop().unwrap();
+  let v_init = stack.pop().unwrap();
+  let v = stack.pop().unwrap();
+  let wd = state.pool.get_word_def(v_quote);
+  let init_copy = state.value_copy(&v_init);
+  let mut scratch = state.pool.get_stack(2);
+  scratch.push(v_init);
+  let saved = swap_stack(&mut state, scratch);
+  let depth = state.stack.len();
+  let mut result = Ok(());
+  for x in v.value_stack_ref().iter() {
+    let x = state.value_copy(x);
+    push_element(&mut state, x);
+    let go;
+    (state, go) = eval_step(state, &wd, w, depth);
+    if !go { result = Err(None); break }
+    if state.current_ref().stack.len() != 1 { result = Err(Some(ErrorKind::BadArgumentType)); break }
+  }
+  let acc = if result.is_ok() { state.current().stack.pop() } else { None };
+  restore_stack(&mut state, saved);
+  match result {
+    Ok(()) => {
+      state.pool.add_word_def(wd);
+      state.pool.add_val(v);
+      state.pool.add_val(init_copy);
+      state.current().stack.push(acc.unwrap());
+      state
+    },
+    Err(None) => {
+      state.pool.add_word_def(wd);
+      state.pool.add_val(v);
+      state.pool.add_val(init_copy);
+      state
+    },
+    Err(Some(e)) => {
+      let v_quote = Arc::try_unwrap(wd).unwrap_or_else(|wd| state.value_copy(&wd));
+      state.current().stack.push(v);
+      state.current().stack.push(init_copy);
+      state.current().stack.push(v_quote);
+      state.eval_error(e, w)
+    }
+  }
+}
+
+// an empty stack or word means no limit
+fn get_limit(v: &Value, math: &Math) -> Result<Option<usize>, ErrorKind> {
+  let stack = v.value_stack_ref();
+  if stack.is_empty() { return Ok(None) }
+  if stack.len() != 1 || !stack[0].is_word() { return Err(ErrorKind::BadArgumentType) }
+  let s = &stack[0].vword_ref().str_word;
+  if s.is_empty() { return Ok(None) }
+  match math.stoi(s)? {
+    i if i < 0 => Err(ErrorKind::OutOfBounds),
+    i => Ok(Some(i as usize))
+  }
+}
+
+fn tighter(a: Option<usize>, b: Option<usize>) -> Option<usize> {
+  match (a, b) {
+    (Some(a), Some(b)) => Some(a.min(b)),
+    (a, b) => a.or(b)
+  }
+}
+
+// ( quote fuel depth size -- ) evaluates the quote in at most fuel steps,
+// nesting at most depth evaluations further and keeping at most size values on
+// the metastack, not counting nested ones, where an empty stack leaves a limit
+// off. Limits can only be
+// tightened, and steps taken are charged to any outer fuel. Exceeding a limit
+// set here only unwinds to here.
+pub fn cog_eval_limited(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 4 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let args = stack.split_off(stack.len() - 4);
+  let math = get_math!(state, w, { state.current().stack.extend(args) });
+  let limits = (get_limit(&args[1], math.math()), get_limit(&args[2], math.math()), get_limit(&args[3], math.math()));
+  state.set_math(math);
+  let (fuel, depth, size) = match limits {
+    (Ok(f), Ok(d), Ok(s)) => (f, d, s),
+    (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+      state.current().stack.extend(args);
+      return state.eval_error(e, w)
+    }
+  };
+  let mut args = args.into_iter();
+  let v_quote = args.next().unwrap();
+  for v in args { state.pool.add_val(v) }
+  let outer = state.limits;
+  let inner = Limits{ fuel: tighter(outer.fuel, fuel),
+                      depth: tighter(outer.depth, depth.map(|d| state.depth + d)),
+                      stack: tighter(outer.stack, size) };
+  state.limits = inner;
+  let wd = state.pool.get_word_def(v_quote);
+  state = state.evalstack(wd, w, false);
+  let spent = inner.fuel.map_or(0, |f| f - state.limits.fuel.unwrap_or(0));
+  state.limits = Limits{ fuel: outer.fuel.map(|f| f.saturating_sub(spent)), ..outer };
+  if state.limit_hit {
+    let outer_hit = state.limits.fuel == Some(0)
+      || inner.depth.is_some() && inner.depth == outer.depth
+      || inner.stack.is_some() && inner.stack == outer.stack;
+    if !outer_hit {
+      state.limit_hit = false;
+      state.exited = false;
+    }
+  }
+  state
+}
+
 pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "eval", cog_eval);
   add_builtin!(state, "quote", cog_quote);
@@ -342,4 +696,11 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "decompose", cog_decompose);
   add_builtin!(state, "size", cog_size);
   add_builtin!(state, "type", cog_type);
+  add_builtin!(state, "each", cog_each);
+  add_builtin!(state, "times", cog_times);
+  add_builtin!(state, "while", cog_while);
+  add_builtin!(state, "map", cog_map);
+  add_builtin!(state, "filter", cog_filter);
+  add_builtin!(state, "fold", cog_fold);
+  add_builtin!(state, "eval-limited", cog_eval_limited);
 }
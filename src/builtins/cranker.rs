@@ -18,7 +18,7 @@ pub fn cog_crank(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_metacrank(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let (meta, base) = get_2_unsigned!(state, w, isize, ACTIVE);
-  if meta < 0 || base < 0 || base > i32::MAX as isize { return state.eval_error("OUT OF BOUNDS", w) }
+  if meta < 0 || base < 0 || base > i32::MAX as isize { return state.eval_error(ErrorKind::OutOfBounds, w) }
   let meta = meta as usize;
   let base = base as i32;
   for _ in 0..2 { let v = state.current().stack.pop().unwrap(); state.pool.add_val(v) }
@@ -49,7 +49,7 @@ macro_rules! cog_crank_val {
     let val = if let Some(ref cranks) = $state.current().cranks {
       if let $letpat = cranks.get(0) { $valexpr } else { 0 }
     } else { 0 };
-    let Some(math) = $state.get_math() else { return $state.eval_error("MATH BASE ZERO", $w) };
+    let Some(math) = $state.get_math() else { return $state.eval_error(ErrorKind::MathBaseZero, $w) };
     match math.math().itos(val as isize, &mut $state) {
       Ok(s) => {
         $state.set_math(math);
@@ -1,4 +1,5 @@
 use crate::*;
+use crate::builtins::combinators::find_container;
 use super::io::*;
 use std::fs::File;
 
@@ -48,6 +49,18 @@ pub fn push_err_on_stack(state: &mut CognitionState, v: &Value) {
   }
 }
 
+// a stack holding what epop would push for the error e
+pub fn error_stack(state: &mut CognitionState, e: &Value) -> Value {
+  let scratch = state.pool.get_stack(3);
+  let saved = std::mem::replace(&mut state.current().stack, scratch);
+  push_err_on_stack(state, e);
+  let mut ve = state.pool.get_vstack(3);
+  let mut scratch = std::mem::replace(&mut state.current().stack, saved);
+  ve.container.stack.append(&mut scratch);
+  state.pool.add_stack(scratch);
+  Value::Stack(ve)
+}
+
 pub fn cog_epeek(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   if let Some(estack) = &mut state.current().err_stack {
     if let Some(v) = estack.pop() {
@@ -56,7 +69,20 @@ pub fn cog_epeek(mut state: CognitionState, w: Option<&Value>) -> CognitionState
       return state;
     }
   }
-  cog_epop(state.eval_error("NO ERRORS", w), w)
+  cog_epop(state.eval_error(ErrorKind::NoErrors, w), w)
+}
+
+// pushes the kind of the top error, as a name such as 'too-few-arguments',
+// or 'other' for errors without a known kind
+pub fn cog_ekind(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let Some(Value::Error(e)) = state.current_ref().err_stack.as_ref().and_then(|e| e.last()) else {
+    return state.eval_error(ErrorKind::NoErrors, w)
+  };
+  let name = e.kind.name();
+  let mut vword = state.pool.get_vword(name.len());
+  vword.str_word.push_str(name);
+  state.push_quoted(Value::Word(vword));
+  state
 }
 
 pub fn cog_epop(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
@@ -67,35 +93,29 @@ pub fn cog_epop(mut state: CognitionState, w: Option<&Value>) -> CognitionState
       return state;
     }
   }
-  cog_epop(state.eval_error("NO ERRORS", w), w)
+  cog_epop(state.eval_error(ErrorKind::NoErrors, w), w)
 }
 
-pub fn cog_epush(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+// Pops an error in the form epop pushes and builds the VError, or leaves the
+// arguments and returns the kind of error to raise
+fn pop_verror(state: &mut CognitionState) -> Result<Box<VError>, ErrorKind> {
   let stack = &mut state.current().stack;
-  if stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 3 { return Err(ErrorKind::TooFewArguments) }
+  let well_formed = |s: &[Value]| {
+    s[0].value_stack_ref().len() == 1 && s[0].value_stack_ref()[0].is_word()
+      && s[1].value_stack_ref().len() <= 1 && s[1].value_stack_ref().iter().all(|x| x.is_word())
+      && matches!(s[2].value_stack_ref().len(), 0 | 3) && s[2].value_stack_ref().iter().all(|x| x.is_word())
+  };
+  if !well_formed(&stack[stack.len() - 3..]) { return Err(ErrorKind::BadArgumentType) }
   let mut v3 = stack.pop().unwrap();
   let v2 = stack.pop().unwrap();
   let v1 = stack.pop().unwrap();
   let stack3 = v3.value_stack();
-  let stack2 = v2.value_stack_ref();
-  let stack1 = v1.value_stack_ref();
-  if stack1.len() != 1 || stack2.len() > 1 || !(stack3.len() == 0 || stack3.len() == 3) {
-    stack.push(v1);
-    stack.push(v2);
-    stack.push(v3);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
-  }
-  let w1 = stack1.first().unwrap();
-  let w2 = stack2.first();
-  if stack3.iter().any(|x| !x.is_word()) { return state.eval_error("BAD ARGUMENT TYPE", w) }
-  let Value::Word(ref v1word) = w1 else { return state.eval_error("BAD ARGUMENT TYPE", w) };
-  let str_word = match w2 {
-    Some(Value::Word(ref v2word)) => Some(state.string_copy(&v2word.str_word)),
-    None => None,
-    _ => return state.eval_error("BAD ARGUMENT TYPE", w),
-  };
+  let v1word = v1.value_stack_ref().first().unwrap().vword_ref();
+  let str_word = v2.value_stack_ref().first().map(|v2word| state.string_copy(&v2word.vword_ref().str_word));
   let mut v = state.pool.get_verror(v1word.str_word.len());
   v.error.push_str(&v1word.str_word);
+  v.kind = ErrorKind::from_message(&v.error);
   v.str_word = str_word;
   if stack3.len() == 3 {
     let mut loc = state.pool.get_verror_loc(0);
@@ -107,6 +127,14 @@ pub fn cog_epush(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   state.pool.add_val(v1);
   state.pool.add_val(v2);
   state.pool.add_val(v3);
+  Ok(v)
+}
+
+pub fn cog_epush(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let v = match pop_verror(&mut state) {
+    Ok(v) => v,
+    Err(kind) => return state.eval_error(kind, w)
+  };
   if state.current().err_stack.is_none() {
     state.current().err_stack = Some(state.pool.get_stack(1));
   }
@@ -123,7 +151,7 @@ pub fn cog_edrop(mut state: CognitionState, w: Option<&Value>) -> CognitionState
       return state;
     }
   }
-  state.eval_error("NO ERRORS", w)
+  state.eval_error(ErrorKind::NoErrors, w)
 }
 
 pub fn cog_eprint(state: CognitionState, w: Option<&Value>) -> CognitionState {
@@ -135,19 +163,20 @@ pub fn cog_eprint(state: CognitionState, w: Option<&Value>) -> CognitionState {
       return state;
     }
   }
-  state.eval_error("NO ERRORS", w)
+  state.eval_error(ErrorKind::NoErrors, w)
 }
 
 pub fn cog_fewrite(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(mut v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.filename_denied(0, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
+  let Some(mut v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let err_stack = &state.current_ref().err_stack;
   let Some(estack) = err_stack else {
     state.current().stack.push(v);
-    return state.eval_error("NO ERRORS", w)
+    return state.eval_error(ErrorKind::NoErrors, w)
   };
   if estack.last().is_none() {
     state.current().stack.push(v);
-    return state.eval_error("NO ERRORS", w)
+    return state.eval_error(ErrorKind::NoErrors, w)
   }
 
   match v.value_stack().first_mut().unwrap() {
@@ -165,7 +194,7 @@ pub fn cog_fewrite(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         estack.last().as_ref().unwrap().fprint(bufwriter.bufwriter.as_mut().unwrap(), "\n", false);
       } else {
         state.current().stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -175,28 +204,29 @@ pub fn cog_fewrite(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         estack.last().as_ref().unwrap().fprint(&mut file, "\n", is_terminal);
       } else {
         state.current().stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       state.current().stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_feprint(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(mut v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.filename_denied(0, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
+  let Some(mut v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let err_stack = &state.current_ref().err_stack;
   let Some(estack) = err_stack else {
     state.current().stack.push(v);
-    return state.eval_error("NO ERRORS", w)
+    return state.eval_error(ErrorKind::NoErrors, w)
   };
   if estack.last().is_none() {
     state.current().stack.push(v);
-    return state.eval_error("NO ERRORS", w)
+    return state.eval_error(ErrorKind::NoErrors, w)
   }
 
   match v.value_stack().first_mut().unwrap() {
@@ -214,7 +244,7 @@ pub fn cog_feprint(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         estack.last().as_ref().unwrap().fprint(bufwriter.bufwriter.as_mut().unwrap(), "\n", false);
       } else {
         state.current().stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -224,13 +254,13 @@ pub fn cog_feprint(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         estack.last().as_ref().unwrap().fprint(&mut file, "\n", is_terminal);
       } else {
         state.current().stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       state.current().stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -249,34 +279,143 @@ pub fn cog_eshow(mut state: CognitionState, _: Option<&Value>) -> CognitionState
 
 pub fn cog_ethrow(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(mut v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let err_stack = v.value_stack();
   if err_stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let err_v = err_stack.first().unwrap();
   let Value::Word(err_w) = err_v else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
-  let mut e = state.pool.get_verror(err_w.str_word.len());
-  e.error.push_str(&err_w.str_word);
-  if let Some(Value::Word(vw)) = w { e.str_word = Some(state.string_copy(&vw.str_word)); }
+  // raised as a builtin's error is, so the container's handler sees it
+  state.raise_mut(ErrorKind::from_message(&err_w.str_word), &err_w.str_word, w);
   state.pool.add_val(v);
-  e.loc = state.verr_loc();
-  if state.current().err_stack.is_none() {
-    state.current().err_stack = Some(state.pool.get_stack(1));
+  if state.trying > 0 {
+    state.thrown = true;
+    state.exited = true;
+  }
+  state
+}
+
+// Evaluates body, then takes any errors it left on the error stack of the
+// container try was called in, wherever body cd'd to, and returns them as a
+// stack holding, for each error, what epop would have pushed.
+fn eval_try(mut state: CognitionState, body: Value, w: Option<&Value>) -> (CognitionState, Option<Value>) {
+  let container: *const VStack = &**state.stack.last().expect("Cognition metastack was empty").vstack_ref();
+  let errors = state.current_ref().err_stack.as_ref().map_or(0, |e| e.len());
+  let wd = state.pool.get_word_def(body);
+  state.trying += 1;
+  state = state.evalstack(wd, w, false);
+  state.trying -= 1;
+  if state.thrown {
+    state.thrown = false;
+    state.exited = false;
   }
-  state.current().err_stack.as_mut().unwrap().push(Value::Error(e));
+  if state.exited { return (state, None) }
+  let Some(estack) = find_container(&mut state, container).and_then(|c| c.err_stack.as_mut()) else { return (state, None) };
+  if estack.len() <= errors { return (state, None) }
+  let mut caught = estack.split_off(errors);
+  let mut vcaught = state.pool.get_vstack(caught.len());
+  for e in caught.drain(..) {
+    let ve = error_stack(&mut state, &e);
+    state.pool.add_val(e);
+    vcaught.container.stack.push(ve);
+  }
+  state.pool.add_stack(caught);
+  (state, Some(Value::Stack(vcaught)))
+}
+
+// ( body handler -- ) evaluates body, and if it raised any errors, removes
+// them and evaluates handler with them as a stack on top. ethrow ends the body.
+pub fn cog_try(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let handler = stack.pop().unwrap();
+  let body = stack.pop().unwrap();
+  let (mut state, caught) = eval_try(state, body, w);
+  match caught {
+    Some(caught) => {
+      state.current().stack.push(caught);
+      let wd = state.pool.get_word_def(handler);
+      state.evalstack(wd, w, false)
+    },
+    None => {
+      state.pool.add_val(handler);
+      state
+    }
+  }
+}
+
+// ( body handler finally -- ) as try, then evaluates finally whether or not
+// there were errors
+pub fn cog_try_finally(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let finally = stack.pop().unwrap();
+  state = cog_try(state, w);
+  if state.exited {
+    state.pool.add_val(finally);
+    return state
+  }
+  let wd = state.pool.get_word_def(finally);
+  state.evalstack(wd, w, false)
+}
+
+// ( handler -- ) sets the current container's error handler. Each error raised
+// while the container is current is taken off the error stack and handler is
+// evaluated with it on top, in the form epop would push wrapped in a stack.
+pub fn cog_ehandler(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if !v.is_stack() && !v.is_macro() {
+    state.current().stack.push(v);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let wd = state.pool.get_word_def(v);
+  if let Some(old) = state.current().on_error.replace(wd) { state.pool.add_word_def(old) }
+  state
+}
+
+pub fn cog_unehandler(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
+  if let Some(old) = state.current().on_error.take() { state.pool.add_word_def(old) }
+  state
+}
+
+// ( error -- ) raises an error, as given to a handler, in the parent
+// container, whose handler then sees it, or in the current one at the root
+pub fn cog_eraise(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if !v.is_stack() || v.value_stack_ref().len() != 3 {
+    state.current().stack.push(v);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let depth = state.current_ref().stack.len();
+  state.current().stack.append(v.value_stack());
+  let verror = match pop_verror(&mut state) {
+    Ok(verror) => verror,
+    Err(kind) => {
+      let rest = state.current().stack.split_off(depth);
+      v.value_stack().extend(rest);
+      state.current().stack.push(v);
+      return state.eval_error(kind, w)
+    }
+  };
+  state.pool.add_val(v);
+  let cur = if state.stack.len() > 1 { Some(state.pop_cur()) } else { None };
+  state.push_error(verror);
+  if let Some(cur) = cur { state = state.push_cur(cur) }
   state
 }
 
 pub fn cog_esize(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let length = if let Some(ref e) = state.current_ref().err_stack { e.len() } else { 0 };
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
-  if length > isize::MAX as usize { return state.with_math(math).eval_error("OUT OF BOUNDS", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
+  if length > isize::MAX as usize { return state.with_math(math).eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(length as isize, &mut state) {
     Ok(s) => {
       state.set_math(math);
@@ -294,6 +433,7 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "eclean", cog_eclean);
   add_builtin!(state, "epeek", cog_epeek);
   add_builtin!(state, "epop", cog_epop);
+  add_builtin!(state, "ekind", cog_ekind);
   add_builtin!(state, "epush", cog_epush);
   add_builtin!(state, "edrop", cog_edrop);
   add_builtin!(state, "eprint", cog_eprint);
@@ -301,5 +441,10 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "feprint", cog_feprint);
   add_builtin!(state, "eshow", cog_eshow);
   add_builtin!(state, "ethrow", cog_ethrow);
+  add_builtin!(state, "try", cog_try);
+  add_builtin!(state, "try-finally", cog_try_finally);
+  add_builtin!(state, "ehandler", cog_ehandler);
+  add_builtin!(state, "unehandler", cog_unehandler);
+  add_builtin!(state, "eraise", cog_eraise);
   add_builtin!(state, "esize", cog_esize);
 }
@@ -3,8 +3,8 @@ use libloading;
 
 pub fn cog_fllib_questionmark(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let vword = if v.value_stack_ref().first().unwrap().is_fllib() {
     let mut vword = state.pool.get_vword(1);
     vword.str_word.push('t');
@@ -17,6 +17,7 @@ pub fn cog_fllib_questionmark(mut state: CognitionState, w: Option<&Value>) -> C
 }
 
 pub fn cog_fllib(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Fllib);
   let (v1, v2) = get_2_words!(state, w);
   let lib_name = &v1.value_stack_ref().first().unwrap().vword_ref().str_word;
   let filename = &v2.value_stack_ref().first().unwrap().vword_ref().str_word;
@@ -46,17 +47,17 @@ pub fn cog_unload(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_fllib_filename(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v_stack = v.value_stack();
   if v_stack.len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let word_v = v_stack.pop().unwrap();
   if !word_v.is_word() {
     v_stack.push(word_v);
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let mut vword = word_v.vword();
   vword.str_word = libloading::library_filename(vword.str_word).into_string().unwrap();
@@ -86,17 +87,17 @@ pub fn cog_fllibs(mut state: CognitionState, _: Option<&Value>) -> CognitionStat
 
 pub fn cog_name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let vstack = v.value_stack();
   if vstack.len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let vfirst = vstack.pop().unwrap();
   if !vfirst.is_fllib() {
     vstack.push(vfirst);
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if let Some(ref s) = vfirst.vfllib_ref().str_word {
     let mut vword = state.pool.get_vword(s.len());
@@ -109,7 +110,7 @@ pub fn cog_name(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_set_name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let vname = get_word!(state, w);
   let stack = &mut state.current().stack;
   let mut v = stack.pop().unwrap();
@@ -117,13 +118,13 @@ pub fn cog_set_name(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   if vstack.len() != 1 {
     stack.push(v);
     stack.push(vname);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let vfirst = vstack.first_mut().unwrap();
   if !vfirst.is_fllib() {
     stack.push(v);
     stack.push(vname);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if let Some(s) = vfirst.vfllib_mut().str_word.take() {
     state.pool.add_string(s);
@@ -137,17 +138,17 @@ pub fn cog_set_name(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 
 pub fn cog_library(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let vstack = v.value_stack();
   if vstack.len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let vfirst = vstack.pop().unwrap();
   if !vfirst.is_fllib() {
     vstack.push(vfirst);
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if let Some(ref library) = vfirst.vfllib_ref().library {
     let mut vword = state.pool.get_vword(library.lib_path.len());
@@ -164,15 +165,15 @@ pub fn cog_library(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 
 pub fn cog_same_questionmark(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = stack.last().unwrap();
   let v1 = stack.get(stack.len() - 2).unwrap();
   if v1.value_stack_ref().len() != 1 || v2.value_stack_ref().len() != 1 {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let truth = match (v1.value_stack_ref().first().unwrap(), v2.value_stack_ref().first().unwrap()) {
     (Value::FLLib(vfllib1),Value::FLLib(vfllib2)) => vfllib1.fllib == vfllib2.fllib,
-    _ => return state.eval_error("BAD ARGUMENT TYPE", w),
+    _ => return state.eval_error(ErrorKind::BadArgumentType, w),
   };
   let vword = if truth {
     let mut vword = state.pool.get_vword(1);
@@ -371,7 +371,7 @@ pub fn cog_questionmark(state: CognitionState, _: Option<&Value>) -> CognitionSt
 }
 
 pub fn cog_period(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   v.print("\n");
   state.pool.add_val(v);
   state
@@ -379,10 +379,10 @@ pub fn cog_period(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_print(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().iter().any(|x| !x.is_word()) {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let mut stdout = io::stdout();
   for wv in v.value_stack_ref().iter() {
@@ -395,10 +395,10 @@ pub fn cog_print(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_wprint(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().iter().any(|x| !x.is_word() && !x.is_fllib() && !x.is_custom()) {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let mut vword = state.pool.get_vword(DEFAULT_STRING_LENGTH);
   let mut string_writer = StringWriter::from(&mut vword.str_word);
@@ -425,7 +425,7 @@ pub fn cog_read(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let stream = io::stdin();
   if stream.read_line(&mut vword.str_word).is_err() {
     state.pool.add_vword(vword);
-    return state.eval_error("READ FAILED", w);
+    return state.eval_error(ErrorKind::ReadFailed, w);
   }
   state.push_quoted(Value::Word(vword));
   state
@@ -460,11 +460,12 @@ pub fn cog_empty(mut state: CognitionState, _: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_fopen(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, FsRead);
   let v = get_word!(state, w);
   let string = &v.value_stack_ref().first().unwrap().vword_ref().str_word;
   let Ok(file) = File::options().read(true).create(false).open(string) else {
     state.current().stack.push(v);
-    return state.eval_error("INVALID FILENAME", w);
+    return state.eval_error(ErrorKind::InvalidFilename, w);
   };
   let boxed_custom = Box::new(ReadCustom{ reader: Some(Box::new(file)) });
   let vcustom = VCustom::with_custom(boxed_custom);
@@ -473,13 +474,14 @@ pub fn cog_fopen(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_file(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, FsRead, FsWrite);
   let v = get_word!(state, w);
   let string = &v.value_stack_ref().first().unwrap().vword_ref().str_word;
   let boxed_custom = if let Ok(f) = File::options().write(true).read(true).create(true).open(string) {
     Box::new(FileCustom{ file: Some(f) })
   } else {
     state.current().stack.push(v);
-    return state.eval_error("INVALID FILENAME", w);
+    return state.eval_error(ErrorKind::InvalidFilename, w);
   };
   let vcustom = VCustom::with_custom(boxed_custom);
   state.push_quoted(Value::Custom(vcustom));
@@ -487,13 +489,14 @@ pub fn cog_file(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_file_new(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, FsRead, FsWrite);
   let v = get_word!(state, w);
   let string = &v.value_stack_ref().first().unwrap().vword_ref().str_word;
   let boxed_custom = if let Ok(f) = File::options().write(true).read(true).create_new(true).open(string) {
     Box::new(FileCustom{ file: Some(f) })
   } else {
     state.current().stack.push(v);
-    return state.eval_error("INVALID FILENAME", w);
+    return state.eval_error(ErrorKind::InvalidFilename, w);
   };
   let vcustom = VCustom::with_custom(boxed_custom);
   state.push_quoted(Value::Custom(vcustom));
@@ -501,13 +504,14 @@ pub fn cog_file_new(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 }
 
 pub fn cog_file_append(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, FsRead, FsWrite);
   let v = get_word!(state, w);
   let string = &v.value_stack_ref().first().unwrap().vword_ref().str_word;
   let boxed_custom = if let Ok(f) = File::options().append(true).read(true).create(true).open(string) {
     Box::new(FileCustom{ file: Some(f) })
   } else {
     state.current().stack.push(v);
-    return state.eval_error("INVALID FILENAME", w);
+    return state.eval_error(ErrorKind::InvalidFilename, w);
   };
   let vcustom = VCustom::with_custom(boxed_custom);
   state.push_quoted(Value::Custom(vcustom));
@@ -516,15 +520,15 @@ pub fn cog_file_append(mut state: CognitionState, w: Option<&Value>) -> Cognitio
 
 pub fn cog_reader(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack().first_mut().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let custom = &mut vcustom.custom;
   if let Some(file) = custom.as_any_mut().downcast_mut::<FileCustom>() {
     if file.file.as_ref().unwrap().metadata().is_err() {
-      return state.eval_error("INVALID FILE", w)
+      return state.eval_error(ErrorKind::InvalidFile, w)
     }
     let boxed: Box<dyn ReadAny> = Box::new(file.file.take().unwrap());
     let reader = Some(boxed);
@@ -535,21 +539,21 @@ pub fn cog_reader(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
     vcustom.custom = Box::new(ReadCustom{ reader });
   } else if custom.as_any_mut().downcast_mut::<ReadCustom>().is_some() {
   } else if custom.as_any_mut().downcast_mut::<BufReadCustom>().is_some() {
-  } else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+  } else { return state.eval_error(ErrorKind::BadArgumentType, w) };
   state
 }
 
 pub fn cog_writer(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack().first_mut().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let custom = &mut vcustom.custom;
   if let Some(file) = custom.as_any_mut().downcast_mut::<FileCustom>() {
     if file.file.as_ref().unwrap().metadata().map_or(false, |m| m.permissions().readonly()) {
-      return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+      return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
     }
     let boxed: Box<dyn WriteAny> = Box::new(file.file.take().unwrap());
     let writer = Some(boxed);
@@ -560,28 +564,28 @@ pub fn cog_writer(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
     vcustom.custom = Box::new(WriteCustom{ writer });
   } else if custom.as_any_mut().downcast_mut::<BufReadCustom>().is_some() {
   } else if custom.as_any_mut().downcast_mut::<BufWriteCustom>().is_some() {
-  } else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+  } else { return state.eval_error(ErrorKind::BadArgumentType, w) };
   state
 }
 
 pub fn cog_bufreader(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack().first_mut().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let custom = &mut vcustom.custom;
   let boxed: Box<dyn ReadAny> = if let Some(file) = custom.as_any_mut().downcast_mut::<FileCustom>() {
     if file.file.as_ref().unwrap().metadata().is_err() {
-      return state.eval_error("INVALID FILE", w)
+      return state.eval_error(ErrorKind::InvalidFile, w)
     }
     Box::new(file.file.take().unwrap())
   } else if let Some(reader) = custom.as_any_mut().downcast_mut::<ReadCustom>() {
     reader.reader.take().unwrap()
   } else if let Some(stream) = custom.as_any_mut().downcast_mut::<ReadWriteCustom>() {
      Box::new(stream.stream.take().unwrap())
-  } else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+  } else { return state.eval_error(ErrorKind::BadArgumentType, w) };
   let bufreader = Some(io::BufReader::new(boxed));
   vcustom.custom = Box::new(BufReadCustom{ bufreader });
   state
@@ -589,22 +593,22 @@ pub fn cog_bufreader(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 
 pub fn cog_bufwriter(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack().first_mut().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let custom = &mut vcustom.custom;
   let boxed: Box<dyn WriteAny> = if let Some(file) = custom.as_any_mut().downcast_mut::<FileCustom>() {
     if file.file.as_ref().unwrap().metadata().map_or(false, |m| m.permissions().readonly()) {
-      return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+      return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
     }
     Box::new(file.file.take().unwrap())
   } else if let Some(writer) = custom.as_any_mut().downcast_mut::<WriteCustom>() {
     writer.writer.take().unwrap()
   } else if let Some(stream) = custom.as_any_mut().downcast_mut::<ReadWriteCustom>() {
      Box::new(stream.stream.take().unwrap())
-  } else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+  } else { return state.eval_error(ErrorKind::BadArgumentType, w) };
   let bufwriter = Some(io::BufWriter::new(boxed));
   vcustom.custom = Box::new(BufWriteCustom{ bufwriter });
   state
@@ -612,10 +616,10 @@ pub fn cog_bufwriter(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 
 pub fn cog_unbuffer(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack().first_mut().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let custom = &mut vcustom.custom;
   if let Some(bufreader) = custom.as_any_mut().downcast_mut::<BufReadCustom>() {
@@ -632,37 +636,38 @@ pub fn cog_unbuffer(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
       }
     }
   } else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   state
 }
 
 pub fn cog_stream(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last_mut() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last_mut() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack().first_mut().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let custom = &mut vcustom.custom;
   if let Some(file) = custom.as_any_mut().downcast_mut::<FileCustom>() {
     if file.file.as_ref().unwrap().metadata().map_or(false, |m| m.permissions().readonly()) {
-      return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+      return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
     }
     let boxed: Box<dyn ReadWriteAny> = Box::new(file.file.take().unwrap());
     let stream = Some(boxed);
     vcustom.custom = Box::new(ReadWriteCustom{ stream });
   } else if custom.as_any_mut().downcast_mut::<ReadWriteCustom>().is_some() {
-  } else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+  } else { return state.eval_error(ErrorKind::BadArgumentType, w) };
   state
 }
 
 pub fn cog_fquestionmark(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  if state.filename_denied(0, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -672,7 +677,7 @@ pub fn cog_fquestionmark(mut state: CognitionState, w: Option<&Value>) -> Cognit
         let file = file.file.as_mut().unwrap();
         if file.metadata().map_or(false, |m| m.permissions().readonly()) {
           stack.push(v);
-          return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+          return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
         }
         let is_terminal = file.is_terminal();
         questionmark(&state, file, is_terminal);
@@ -684,7 +689,7 @@ pub fn cog_fquestionmark(mut state: CognitionState, w: Option<&Value>) -> Cognit
         questionmark(&state, bufwriter.bufwriter.as_mut().unwrap().as_write_mut(), false);
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -697,25 +702,26 @@ pub fn cog_fquestionmark(mut state: CognitionState, w: Option<&Value>) -> Cognit
         questionmark(&state, &mut file, is_terminal);
       } else {
         stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_fperiod(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  if state.filename_denied(0, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut v = stack.pop().unwrap();
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -726,7 +732,7 @@ pub fn cog_fperiod(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         let file = file.file.as_mut().unwrap();
         if file.metadata().map_or(false, |m| m.permissions().readonly()) {
           stack.push(v);
-          return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+          return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
         }
         let is_terminal = file.is_terminal();
         print_v.fprint(file, "\n", is_terminal);
@@ -749,7 +755,7 @@ pub fn cog_fperiod(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         state.pool.add_val(print_v);
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -768,30 +774,31 @@ pub fn cog_fperiod(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
         state.pool.add_val(print_v);
       } else {
         stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_fwrite(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  if state.filename_denied(0, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut v = stack.pop().unwrap();
   let v2 = stack.last().unwrap();
   if v.value_stack_ref().len() != 1 || v2.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !v2.value_stack_ref().first().unwrap().is_word() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -802,7 +809,7 @@ pub fn cog_fwrite(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
         let file = file.file.as_mut().unwrap();
         if file.metadata().map_or(false, |m| m.permissions().readonly()) {
           stack.push(v);
-          return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+          return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
         }
         fwrite_check!(file, &print_v.value_stack_ref().first().unwrap().vword_ref().str_word.as_bytes());
         flush!(file);
@@ -824,7 +831,7 @@ pub fn cog_fwrite(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
         state.pool.add_val(print_v);
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -836,30 +843,31 @@ pub fn cog_fwrite(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
         state.pool.add_val(print_v);
       } else {
         stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_fprint(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  if state.filename_denied(0, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut v = stack.pop().unwrap();
   let v2 = stack.last().unwrap();
   if v.value_stack_ref().len() != 1 || v2.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !v2.value_stack_ref().first().unwrap().is_word() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -870,7 +878,7 @@ pub fn cog_fprint(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
         let file = file.file.as_mut().unwrap();
         if file.metadata().map_or(false, |m| m.permissions().readonly()) {
           stack.push(v);
-          return state.eval_error("INSUFFICIENT FILE PERMISSIONS", w)
+          return state.eval_error(ErrorKind::InsufficientFilePermissions, w)
         }
         fwrite_check!(file, &print_v.value_stack_ref().first().unwrap().vword_ref().str_word.as_bytes());
         flush!(file);
@@ -892,7 +900,7 @@ pub fn cog_fprint(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
         state.pool.add_val(print_v);
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -904,24 +912,25 @@ pub fn cog_fprint(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
         state.pool.add_val(print_v);
       } else {
         stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_fread(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  if state.filename_denied(0, Capability::FsRead) { return state.eval_error(ErrorKind::PermissionDenied, w) }
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -933,7 +942,7 @@ pub fn cog_fread(mut state: CognitionState, w: Option<&Value>) -> CognitionState
             if metadata.is_file() {
               break 'word state.pool.get_vword(metadata.len() as usize) }}
           stack.push(v);
-          return state.eval_error("INVALID FILE", w)
+          return state.eval_error(ErrorKind::InvalidFile, w)
         };
         if let Err(e) = file.file.as_mut().unwrap().read_to_string(&mut vword.str_word) {
           let _ = io::stderr().write(format!("{e}").as_bytes());
@@ -959,7 +968,7 @@ pub fn cog_fread(mut state: CognitionState, w: Option<&Value>) -> CognitionState
         state.push_quoted(Value::Word(vword));
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
@@ -970,7 +979,7 @@ pub fn cog_fread(mut state: CognitionState, w: Option<&Value>) -> CognitionState
             if metadata.is_file() {
               break 'word state.pool.get_vword(metadata.len() as usize) }}
           stack.push(v);
-          return state.eval_error("INVALID FILE", w)
+          return state.eval_error(ErrorKind::InvalidFile, w)
         };
         if let Err(e) = file.read_to_string(&mut vword.str_word) {
           let _ = io::stderr().write(format!("{e}").as_bytes());
@@ -978,13 +987,13 @@ pub fn cog_fread(mut state: CognitionState, w: Option<&Value>) -> CognitionState
         state.push_quoted(Value::Word(vword));
       } else {
         stack.push(v);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v);
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -992,20 +1001,20 @@ pub fn cog_fread(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut v = stack.pop().unwrap();
   let v_delim = stack.last().unwrap();
   if v.value_stack_ref().len() != 1 || v_delim.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Value::Word(delim_word) = v_delim.value_stack_ref().first().unwrap() else {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if delim_word.str_word.len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let byte = delim_word.str_word.as_bytes()[0].clone();
   let val = v.value_stack().first_mut().unwrap();
@@ -1025,7 +1034,7 @@ pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> Cognition
           Err(e) => {
             let _ = io::stderr().write(format!("{e}").as_bytes());
             state.current().stack.push(v);
-            return state.eval_error("INVALID STRING", w)
+            return state.eval_error(ErrorKind::InvalidString, w)
           }
         };
         let c = state.current().stack.pop().unwrap();
@@ -1046,7 +1055,7 @@ pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> Cognition
             Err(e) => {
               let _ = io::stderr().write(format!("{e}").as_bytes());
               state.current().stack.push(v);
-              return state.eval_error("INVALID STRING", w)
+              return state.eval_error(ErrorKind::InvalidString, w)
             }
           };
           let c = state.current().stack.pop().unwrap();
@@ -1054,17 +1063,17 @@ pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> Cognition
           state.push_quoted(Value::Word(vword));
         } else {
           stack.push(v);
-          return state.eval_error("BAD ARGUMENT TYPE", w)
+          return state.eval_error(ErrorKind::BadArgumentType, w)
         }
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -1072,20 +1081,20 @@ pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> Cognition
 
 // pub fn cog_skip_until(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 //   let stack = &mut state.current().stack;
-//   if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+//   if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
 //   let mut v = stack.pop().unwrap();
 //   let v_delim = stack.last().unwrap();
 //   if v.value_stack_ref().len() != 1 || v_delim.value_stack_ref().len() != 1 {
 //     stack.push(v);
-//     return state.eval_error("BAD ARGUMENT TYPE", w)
+//     return state.eval_error(ErrorKind::BadArgumentType, w)
 //   }
 //   let Value::Word(delim_word) = v_delim.value_stack_ref().first().unwrap() else {
 //     stack.push(v);
-//     return state.eval_error("BAD ARGUMENT TYPE", w)
+//     return state.eval_error(ErrorKind::BadArgumentType, w)
 //   };
 //   if delim_word.str_word.len() != 1 {
 //     stack.push(v);
-//     return state.eval_error("BAD ARGUMENT TYPE", w)
+//     return state.eval_error(ErrorKind::BadArgumentType, w)
 //   }
 //   let byte = delim_word.str_word.as_bytes()[0].clone();
 //   let val = v.value_stack().first_mut().unwrap();
@@ -1107,17 +1116,17 @@ pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> Cognition
 //           state.pool.add_val(c);
 //         } else {
 //           stack.push(v);
-//           return state.eval_error("BAD ARGUMENT TYPE", w)
+//           return state.eval_error(ErrorKind::BadArgumentType, w)
 //         }
 //       } else {
 //         stack.push(v);
-//         return state.eval_error("BAD ARGUMENT TYPE", w)
+//         return state.eval_error(ErrorKind::BadArgumentType, w)
 //       }
 //       state.current().stack.push(v);
 //     },
 //     _ => return {
 //       stack.push(v);
-//       state.eval_error("BAD ARGUMENT TYPE", w)
+//       state.eval_error(ErrorKind::BadArgumentType, w)
 //     },
 //   }
 //   state
@@ -1125,10 +1134,10 @@ pub fn cog_read_until(mut state: CognitionState, w: Option<&Value>) -> Cognition
 
 pub fn cog_read_line(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -1151,16 +1160,16 @@ pub fn cog_read_line(mut state: CognitionState, w: Option<&Value>) -> CognitionS
           state.current().stack.push(v);
         } else {
           stack.push(v);
-          return state.eval_error("BAD ARGUMENT TYPE", w)
+          return state.eval_error(ErrorKind::BadArgumentType, w)
         }
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       };
     },
     _ => return {
       stack.push(v);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -1172,7 +1181,7 @@ macro_rules! match_seek {
       Err(_) => {
         $stack.push($v);
         $stack.push($idxval);
-        return $state.eval_error("SEEK FAILED", $w)
+        return $state.eval_error(ErrorKind::SeekFailed, $w)
       },
       Ok(_) => $state.pool.add_val($idxval)
     }
@@ -1187,16 +1196,16 @@ macro_rules! try_seek {
     } else {
       $stack.push($v);
       $stack.push($idxval);
-      return $state.eval_error("NOT SEEKABLE", $w)
+      return $state.eval_error(ErrorKind::NotSeekable, $w)
     }
   }
 }
 
 pub fn cog_seek(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let idx = get_unsigned!(state, w, isize, ACTIVE) as usize;
   let idxval = if idx > u64::MAX as usize {
-    return state.eval_error("OUT OF BOUNDS", w);
+    return state.eval_error(ErrorKind::OutOfBounds, w);
   } else {
     state.current().stack.pop().unwrap()
   };
@@ -1206,7 +1215,7 @@ pub fn cog_seek(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
     stack.push(idxval);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -1226,19 +1235,19 @@ pub fn cog_seek(mut state: CognitionState, w: Option<&Value>) -> CognitionState
         } else {
           stack.push(v);
           stack.push(idxval);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else {
         stack.push(v);
         stack.push(idxval);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
     _ => return {
       stack.push(v);
       stack.push(idxval);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -1246,10 +1255,10 @@ pub fn cog_seek(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_seek_end(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -1257,44 +1266,44 @@ pub fn cog_seek_end(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
       let custom = &mut vcustom.custom;
       if let Some(file) = custom.as_any_mut().downcast_mut::<FileCustom>() {
         if file.file.as_mut().unwrap().seek(io::SeekFrom::End(0)).is_err() {
-          return state.eval_error("SEEK FAILED", w)
+          return state.eval_error(ErrorKind::SeekFailed, w)
         }
       } else if let Some(bufreader) = custom.as_any_mut().downcast_mut::<BufReadCustom>() {
         if let Some(file) = bufreader.bufreader.as_mut().unwrap().as_any_mut().downcast_mut::<File>() {
           if file.seek(io::SeekFrom::End(0)).is_err() {
-            return state.eval_error("SEEK FAILED", w)
+            return state.eval_error(ErrorKind::SeekFailed, w)
           }
         } else {
           stack.push(v);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else if let Some(bufwriter) = custom.as_any_mut().downcast_mut::<BufWriteCustom>() {
         if let Some(file) = bufwriter.bufwriter.as_mut().unwrap().as_any_mut().downcast_mut::<File>() {
           if file.seek(io::SeekFrom::End(0)).is_err() {
-            return state.eval_error("SEEK FAILED", w)
+            return state.eval_error(ErrorKind::SeekFailed, w)
           }
         } else {
           stack.push(v);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else if let Some(stream) = custom.as_any_mut().downcast_mut::<ReadWriteCustom>() {
         if let Some(file) = ReadWriteAny::as_any_mut(stream.stream.as_mut().unwrap()).downcast_mut::<File>() {
           if file.seek(io::SeekFrom::End(0)).is_err() {
-            return state.eval_error("SEEK FAILED", w)
+            return state.eval_error(ErrorKind::SeekFailed, w)
           }
         } else if ReadWriteAny::as_any(stream.stream.as_ref().unwrap()).downcast_ref::<io::Empty>().is_none() {
           stack.push(v);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
     _ => {
       stack.push(v);
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -1306,12 +1315,12 @@ macro_rules! streampos {
       Ok(i) => {
         let Some(math) = $state.get_math() else {
           $state.current().stack.push($v);
-          return $state.eval_error("MATH BASE ZERO", $w)
+          return $state.eval_error(ErrorKind::MathBaseZero, $w)
         };
         if i as usize > isize::MAX as usize {
           $state.set_math(math);
           $state.current().stack.push($v);
-          return $state.eval_error("OUT OF BOUNDS", $w)
+          return $state.eval_error(ErrorKind::OutOfBounds, $w)
         }
         match math.math().itos(i as isize, &mut $state) {
           Ok(s) => {
@@ -1331,7 +1340,7 @@ macro_rules! streampos {
       },
       Err(_) => {
         $state.current().stack.push($v);
-        return $state.eval_error("STREAM POSITION FAILED", $w)
+        return $state.eval_error(ErrorKind::StreamPositionFailed, $w)
       }
     }
   }
@@ -1339,10 +1348,10 @@ macro_rules! streampos {
 
 pub fn cog_streampos(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -1357,7 +1366,7 @@ pub fn cog_streampos(mut state: CognitionState, w: Option<&Value>) -> CognitionS
           streampos!(state, w, empty, v);
         } else {
           stack.push(v);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else if let Some(bufwriter) = custom.as_any_mut().downcast_mut::<BufWriteCustom>() {
         if let Some(file) = bufwriter.bufwriter.as_mut().unwrap().as_any_mut().downcast_mut::<File>() {
@@ -1366,7 +1375,7 @@ pub fn cog_streampos(mut state: CognitionState, w: Option<&Value>) -> CognitionS
           streampos!(state, w, empty, v);
         } else {
           stack.push(v);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else if let Some(stream) = custom.as_any_mut().downcast_mut::<ReadWriteCustom>() {
         if let Some(file) = ReadWriteAny::as_any_mut(stream.stream.as_mut().unwrap()).downcast_mut::<File>() {
@@ -1375,23 +1384,23 @@ pub fn cog_streampos(mut state: CognitionState, w: Option<&Value>) -> CognitionS
           streampos!(state, w, empty, v);
         } else {
           stack.push(v);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else {
         stack.push(v);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
     },
     _ => {
       stack.push(v);
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_seek_relative(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current_ref().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let idx = get_int!(state, w, i64, ACTIVE);
   let stack = &mut state.current().stack;
   let idxval = stack.pop().unwrap();
@@ -1399,7 +1408,7 @@ pub fn cog_seek_relative(mut state: CognitionState, w: Option<&Value>) -> Cognit
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
     stack.push(idxval);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let val = v.value_stack().first_mut().unwrap();
   match val {
@@ -1419,19 +1428,19 @@ pub fn cog_seek_relative(mut state: CognitionState, w: Option<&Value>) -> Cognit
         } else {
           stack.push(v);
           stack.push(idxval);
-          return state.eval_error("NOT SEEKABLE", w)
+          return state.eval_error(ErrorKind::NotSeekable, w)
         }
       } else {
         stack.push(v);
         stack.push(idxval);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v);
     },
     _ => return {
       stack.push(v);
       stack.push(idxval);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -1440,7 +1449,7 @@ pub fn cog_seek_relative(mut state: CognitionState, w: Option<&Value>) -> Cognit
 macro_rules! iotype_questionmark {
   ($state:ident,$w:ident,$type:ty) => {{
     let Some(v) = $state.current_ref().stack.last() else {
-      return $state.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state.eval_error(ErrorKind::TooFewArguments, $w)
     };
     let vstack = v.value_stack_ref();
     if vstack.len() == 1 {
@@ -1,5 +1,7 @@
 use crate::*;
-use crate::math::BASE_MAX;
+use crate::math::{BASE_MAX, Operand};
+
+use std::cmp::Ordering;
 
 macro_rules! ensure_math {
   ($state:ident) => {
@@ -11,23 +13,23 @@ macro_rules! ensure_math {
 
 pub fn cog_base(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  let Some(math) = &mut cur.math else { return state.eval_error("MATH DIGITS UNINITIALIZED", w) };
+  let Some(v) = cur.stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  let Some(math) = &mut cur.math else { return state.eval_error(ErrorKind::MathDigitsUninitialized, w) };
   let i = if math.base() == 0 {
     let i = v.value_stack_ref().len();
-    if i > BASE_MAX as usize { return state.eval_error("OUT OF BOUNDS", w) }
+    if i > BASE_MAX as usize { return state.eval_error(ErrorKind::OutOfBounds, w) }
     i as i32
   } else {
     if v.value_stack_ref().len() != 1 {
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     }
     let base_v = &v.value_stack_ref()[0];
     if !base_v.is_word() {
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     }
     match math.stoi(&base_v.vword_ref().str_word) {
       Ok(i) => if i > BASE_MAX as isize || i < 0 {
-        return state.eval_error("MATH DIGITS UNINITIALIZED", w);
+        return state.eval_error(ErrorKind::MathDigitsUninitialized, w);
       } else { i as i32 },
       Err(e) => return state.eval_error(e, w),
     }
@@ -40,6 +42,23 @@ pub fn cog_base(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   state
 }
 
+pub fn cog_precision(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let cur = state.current();
+  let Some(v) = cur.stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  let Some(math) = cur.math.as_mut().filter(|m| m.base() != 0) else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
+  let precision_v = &v.value_stack_ref()[0];
+  if !precision_v.is_word() { return state.eval_error(ErrorKind::BadArgumentType, w) }
+  match math.stoi(&precision_v.vword_ref().str_word) {
+    Ok(i) if i >= 0 => math.set_precision(i as usize),
+    Ok(_) => return state.eval_error(ErrorKind::OutOfBounds, w),
+    Err(e) => return state.eval_error(e, w),
+  }
+  let v = cur.stack.pop().unwrap();
+  state.pool.add_val(v);
+  state
+}
+
 pub fn cog_negc(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   get_char_option!(state, c, w);
   ensure_math!(state);
@@ -92,16 +111,16 @@ pub fn cog_meta_delim(mut state: CognitionState, w: Option<&Value>) -> Cognition
 pub fn cog_digits(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let mut cur_v = state.pop_cur();
   let cur = cur_v.metastack_container();
-  let Some(v) = cur.stack.last() else { return state.push_cur(cur_v).eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.push_cur(cur_v).eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = cur.stack.last() else { return state.push_cur(cur_v).eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.push_cur(cur_v).eval_error(ErrorKind::BadArgumentType, w) }
   let word_v = &v.value_stack_ref()[0];
-  if !word_v.is_word() { return state.push_cur(cur_v).eval_error("BAD ARGUMENT TYPE", w) }
+  if !word_v.is_word() { return state.push_cur(cur_v).eval_error(ErrorKind::BadArgumentType, w) }
   let s = &word_v.vword_ref().str_word;
-  if s.len() > i32::MAX as usize { return state.push_cur(cur_v).eval_error("OUT OF BOUNDS", w) }
+  if s.len() > i32::MAX as usize { return state.push_cur(cur_v).eval_error(ErrorKind::OutOfBounds, w) }
   if cur.math.is_none() {
     cur.math = Some(state.pool.get_math(0))
   }
-  if cur.math.as_ref().unwrap().base() != 0 { return state.push_cur(cur_v).eval_error("MATH BASE NONZERO", w) }
+  if cur.math.as_ref().unwrap().base() != 0 { return state.push_cur(cur_v).eval_error(ErrorKind::MathBaseNonzero, w) }
   cur.math.as_mut().unwrap().set_digits(s);
   let v = cur.stack.pop().unwrap();
   state.pool.add_val(v);
@@ -112,7 +131,7 @@ pub fn cog_get_base(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   if let Some(math) = state.current().math.take() {
     if math.base() == 1 {
       state.current().math = Some(math);
-      return state.eval_error("MATH BASE ONE", w)
+      return state.eval_error(ErrorKind::MathBaseOne, w)
     } else if math.base() > 1 {
       let one = math.get_digits().get(1).expect("Math missing digits");
       let zero = math.get_digits().first().expect("Math missing digits");
@@ -129,6 +148,21 @@ pub fn cog_get_base(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   state
 }
 
+pub fn cog_get_precision(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let Some(math) = state.current().math.take_if(|m| m.base() != 0) else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  let result = math.itos(math.precision() as isize, &mut state);
+  state.current().math = Some(math);
+  match result {
+    Ok(s) => {
+      let mut v = state.pool.get_vword(0);
+      v.str_word = s;
+      state.push_quoted(Value::Word(v));
+      state
+    },
+    Err(e) => state.eval_error(e, w)
+  }
+}
+
 pub fn cog_get_negc(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
   if let Some(math) = state.current().math.take() {
     if let Some(c) = math.get_negc() {
@@ -226,16 +260,16 @@ macro_rules! binary_logic_operation {
   ($name:tt,$a:tt,$b:tt,$op:expr) => {
     pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
       let stack = &mut state.current().stack;
-      if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+      if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
       let v2 = stack.pop().unwrap();
       let v1 = stack.last_mut().unwrap();
       if v1.value_stack_ref().len() != 1 || v2.value_stack_ref().len() != 1 {
-        return state.eval_error("TOO FEW ARGUMENTS", w)
+        return state.eval_error(ErrorKind::TooFewArguments, w)
       }
       let str1 = v1.value_stack().first_mut().unwrap();
       let str2 = v2.value_stack_ref().first().unwrap();
       if !str1.is_word() || !str2.is_word() {
-        return state.eval_error("TOO FEW ARGUMENTS", w)
+        return state.eval_error(ErrorKind::TooFewArguments, w)
       }
       let vword1 = str1.vword_mut();
       let $a = &vword1.str_word;
@@ -271,41 +305,168 @@ pub fn cog_not(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   state
 }
 
-macro_rules! interim_binary_operation {
-  ($name:tt,$a:tt,$b:tt,$operation:expr) => {
+macro_rules! binary_math_operation {
+  ($name:tt,$method:ident) => {
+    pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+      let (mut v1, v2) = get_2_words!(state, w);
+      let math = get_math!(state, w, {
+        state.current().stack.push(v1);
+        state.current().stack.push(v2);
+      });
+      let s1 = &v1.value_stack_ref()[0].vword_ref().str_word;
+      let s2 = &v2.value_stack_ref()[0].vword_ref().str_word;
+      match math.math().$method(s1, s2, &mut state) {
+        Ok(mut s) => {
+          state.set_math(math);
+          std::mem::swap(&mut v1.value_stack()[0].vword_mut().str_word, &mut s);
+          state.pool.add_string(s);
+          state.pool.add_val(v2);
+          state.current().stack.push(v1);
+          state
+        },
+        Err(e) => {
+          state.set_math(math);
+          state.current().stack.push(v1);
+          state.current().stack.push(v2);
+          state.eval_error(e, w)
+        }
+      }
+    }
+  }
+}
+
+binary_math_operation!{ cog_plus, sum }
+binary_math_operation!{ cog_minus, difference }
+binary_math_operation!{ cog_mul, product }
+binary_math_operation!{ cog_div, quotient }
+binary_math_operation!{ cog_mod, modulo }
+binary_math_operation!{ cog_rem, remainder }
+binary_math_operation!{ cog_gcd, gcd }
+binary_math_operation!{ cog_lcm, lcm }
+binary_math_operation!{ cog_band, band }
+binary_math_operation!{ cog_bor, bor }
+binary_math_operation!{ cog_bxor, bxor }
+binary_math_operation!{ cog_shl, shl }
+binary_math_operation!{ cog_shr, shr }
+binary_math_operation!{ cog_pow, power }
+
+pub fn cog_divmod(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let (mut v1, mut v2) = get_2_words!(state, w);
+  let math = get_math!(state, w, {
+    state.current().stack.push(v1);
+    state.current().stack.push(v2);
+  });
+  let s1 = &v1.value_stack_ref()[0].vword_ref().str_word;
+  let s2 = &v2.value_stack_ref()[0].vword_ref().str_word;
+  let result = math.math().divmod(s1, s2, &mut state);
+  state.set_math(math);
+  match result {
+    Ok((mut q, mut r)) => {
+      std::mem::swap(&mut v1.value_stack()[0].vword_mut().str_word, &mut q);
+      std::mem::swap(&mut v2.value_stack()[0].vword_mut().str_word, &mut r);
+      state.pool.add_string(q);
+      state.pool.add_string(r);
+      state.current().stack.push(v1);
+      state.current().stack.push(v2);
+      state
+    },
+    Err(e) => {
+      state.current().stack.push(v1);
+      state.current().stack.push(v2);
+      state.eval_error(e, w)
+    }
+  }
+}
+
+// ( num src dst -- num ) where src and dst are stacks carrying a Math, or
+// empty stacks standing for the nearest Math
+pub fn cog_convert(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let len = stack.len();
+  let (v, v_src, v_dst) = (&stack[len - 3], &stack[len - 2], &stack[len - 1]);
+  if v.value_stack_ref().len() != 1 || !v.value_stack_ref()[0].is_word() { return state.eval_error(ErrorKind::BadArgumentType, w) }
+  let is_math = |m: &Value| m.is_stack() && (m.vstack_ref().container.math.is_some() || m.value_stack_ref().is_empty());
+  if !is_math(v_src) || !is_math(v_dst) { return state.eval_error(ErrorKind::BadArgumentType, w) }
+  let v_dst = stack.pop().unwrap();
+  let v_src = stack.pop().unwrap();
+  let mut v = stack.pop().unwrap();
+  let nearest = if v_src.vstack_ref().container.math.is_some() && v_dst.vstack_ref().container.math.is_some() { None }
+  else {
+    Some(get_math!(state, w, {
+      state.current().stack.push(v);
+      state.current().stack.push(v_src);
+      state.current().stack.push(v_dst);
+    }))
+  };
+  let src = v_src.vstack_ref().container.math.as_ref().unwrap_or_else(|| nearest.as_ref().unwrap().math());
+  let dst = v_dst.vstack_ref().container.math.as_ref().unwrap_or_else(|| nearest.as_ref().unwrap().math());
+  let s = &v.value_stack_ref()[0].vword_ref().str_word;
+  let result = src.convert(s, dst, &mut state);
+  if let Some(math) = nearest { state.set_math(math) }
+  match result {
+    Ok(mut s) => {
+      std::mem::swap(&mut v.value_stack()[0].vword_mut().str_word, &mut s);
+      state.pool.add_string(s);
+      state.pool.add_val(v_src);
+      state.pool.add_val(v_dst);
+      state.current().stack.push(v);
+      state
+    },
+    Err(e) => {
+      state.current().stack.push(v);
+      state.current().stack.push(v_src);
+      state.current().stack.push(v_dst);
+      state.eval_error(e, w)
+    }
+  }
+}
+
+macro_rules! unary_math_operation {
+  ($name:tt,$method:ident) => {
     pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-      let ($a, $b) = get_2_ints!(state, w, isize);
-      let math = state.get_math().unwrap();
-      match math.math().itos(($operation) as isize, &mut state) {
-        Ok(s) => {
+      let mut v = get_word!(state, w);
+      let math = get_math!(state, w, {
+        state.current().stack.push(v);
+      });
+      let s = &v.value_stack_ref()[0].vword_ref().str_word;
+      match math.math().$method(s, &mut state) {
+        Ok(mut s) => {
           state.set_math(math);
-          let mut vw = state.pool.get_vword(s.len());
-          vw.str_word.push_str(&s);
+          std::mem::swap(&mut v.value_stack()[0].vword_mut().str_word, &mut s);
           state.pool.add_string(s);
-          state.push_quoted(Value::Word(vw));
+          state.current().stack.push(v);
           state
         },
-        Err(e) => state.with_math(math).eval_error(e, w)
+        Err(e) => {
+          state.set_math(math);
+          state.current().stack.push(v);
+          state.eval_error(e, w)
+        }
       }
     }
   }
 }
 
-// real integer operations
-interim_binary_operation!{ cog_plus, a, b, a+b }
-interim_binary_operation!{ cog_minus, a, b, a-b }
-interim_binary_operation!{ cog_mul, a, b, a*b }
-interim_binary_operation!{ cog_div, a, b, a/b }
-interim_binary_operation!{ cog_pow, a, b, a.pow(b.try_into().unwrap()) }
+unary_math_operation!{ cog_abs, abs }
+unary_math_operation!{ cog_sign, sign }
+unary_math_operation!{ cog_bnot, bnot }
+unary_math_operation!{ cog_popcount, popcount }
+unary_math_operation!{ cog_sqrt, sqrt }
+unary_math_operation!{ cog_exp, exp }
+unary_math_operation!{ cog_ln, ln }
+unary_math_operation!{ cog_sin, sin }
+unary_math_operation!{ cog_cos, cos }
+unary_math_operation!{ cog_atan, atan }
 
 pub fn cog_neg(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let mut v = get_word!(state, w);
   let Some(math) = state.get_math() else {
     state.current().stack.push(v);
-    return state.eval_error("MATH BASE ZERO", w) };
+    return state.eval_error(ErrorKind::MathBaseZero, w) };
   if math.math().base() == 0 {
     state.current().stack.push(v);
-    return state.with_math(math).eval_error("MATH BASE ZERO", w)
+    return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w)
   }
   let vstr = &mut v.value_stack().first_mut().unwrap().vword_mut().str_word;
   math.math().neg(vstr, &mut state);
@@ -314,27 +475,284 @@ pub fn cog_neg(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   state
 }
 
-macro_rules! interim_comparison_operation {
-  ($name:tt,$a:tt,$b:tt,$operation:expr) => {
+macro_rules! comparison_operation {
+  ($name:tt,$method:ident,$result:tt,$operation:expr) => {
     pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-      let ($a, $b) = get_2_ints!(state, w, isize);
-      let mut vw = state.pool.get_vword(1);
-      if $operation { vw.str_word.push('t') }
-      state.push_quoted(Value::Word(vw));
-      state
+      let (mut v1, v2) = get_2_words!(state, w);
+      let math = get_math!(state, w, {
+        state.current().stack.push(v1);
+        state.current().stack.push(v2);
+      });
+      let s1 = &v1.value_stack_ref()[0].vword_ref().str_word;
+      let s2 = &v2.value_stack_ref()[0].vword_ref().str_word;
+      match math.math().$method(s1, s2, &mut state) {
+        Ok($result) => {
+          state.set_math(math);
+          let str_word = &mut v1.value_stack()[0].vword_mut().str_word;
+          str_word.clear();
+          if $operation { str_word.push('t') }
+          state.pool.add_val(v2);
+          state.current().stack.push(v1);
+          state
+        },
+        Err(e) => {
+          state.set_math(math);
+          state.current().stack.push(v1);
+          state.current().stack.push(v2);
+          state.eval_error(e, w)
+        }
+      }
+    }
+  }
+}
+
+comparison_operation!{ cog_lthan, compare, o, o == Ordering::Less }
+comparison_operation!{ cog_leq, compare, o, o != Ordering::Greater }
+comparison_operation!{ cog_eq, equal, e, e }
+comparison_operation!{ cog_geq, compare, o, o != Ordering::Less }
+comparison_operation!{ cog_gthan, compare, o, o == Ordering::Greater }
+comparison_operation!{ cog_neq, equal, e, !e }
+
+// keeps whichever argument satisfies the ordering, or the first if they are equal
+macro_rules! extremum_operation {
+  ($name:tt,$ordering:expr) => {
+    pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+      let (v1, v2) = get_2_words!(state, w);
+      let math = get_math!(state, w, {
+        state.current().stack.push(v1);
+        state.current().stack.push(v2);
+      });
+      let s1 = &v1.value_stack_ref()[0].vword_ref().str_word;
+      let s2 = &v2.value_stack_ref()[0].vword_ref().str_word;
+      let result = math.math().compare(s1, s2, &mut state);
+      state.set_math(math);
+      match result {
+        Ok(o) => {
+          let (keep, drop) = if o == $ordering.reverse() { (v2, v1) } else { (v1, v2) };
+          state.pool.add_val(drop);
+          state.current().stack.push(keep);
+          state
+        },
+        Err(e) => {
+          state.current().stack.push(v1);
+          state.current().stack.push(v2);
+          state.eval_error(e, w)
+        }
+      }
+    }
+  }
+}
+
+extremum_operation!{ cog_min, Ordering::Less }
+extremum_operation!{ cog_max, Ordering::Greater }
+
+/// Checks that an ops table entry is a stack of n words
+fn op_entry(v: &Value, n: usize) -> Result<&Stack, ErrorKind> {
+  if !v.is_stack() && !v.is_macro() { return Err(ErrorKind::BadArgumentType) }
+  let entry = v.value_stack_ref();
+  if entry.len() != n || entry.iter().any(|x| !x.is_word()) { return Err(ErrorKind::BadArgumentType) }
+  Ok(entry)
+}
+
+fn op_digit(math: &Math, v: &Value) -> Result<Digit, ErrorKind> {
+  math.stodigit(&v.vword_ref().str_word)
+}
+
+// entries: [ a r ]
+fn build_unary_op(math: &Math, entries: &Stack, pool: &mut Pool) -> Result<Op, ErrorKind> {
+  let mut table = pool.get_un_op();
+  for v in entries.iter() {
+    let result = op_entry(v, 2).and_then(|e| Ok((op_digit(math, &e[0])?, op_digit(math, &e[1])?)));
+    match result {
+      Ok((a, r)) => { table.insert(a, r); },
+      Err(e) => { pool.add_op(Op::Unary(table)); return Err(e) }
+    }
+  }
+  Ok(Op::Unary(table))
+}
+
+// entries: [ a b r c ]
+fn build_binary_op(math: &Math, entries: &Stack, pool: &mut Pool) -> Result<Op, ErrorKind> {
+  let mut table = pool.get_bin_op();
+  for v in entries.iter() {
+    let result = op_entry(v, 4).and_then(|e| {
+      Ok(((op_digit(math, &e[0])?, op_digit(math, &e[1])?), (op_digit(math, &e[2])?, op_digit(math, &e[3])?)))
+    });
+    match result {
+      Ok((k, r)) => { table.insert(k, r); },
+      Err(e) => { pool.add_op(Op::Binary(table)); return Err(e) }
+    }
+  }
+  Ok(Op::Binary(table))
+}
+
+// entries: [ s r ]
+fn build_str_op(_: &Math, entries: &Stack, pool: &mut Pool) -> Result<Op, ErrorKind> {
+  let mut table = pool.get_str_op();
+  for v in entries.iter() {
+    match op_entry(v, 2) {
+      Ok(e) => {
+        let mut k = pool.get_string(e[0].vword_ref().str_word.len());
+        k.push_str(&e[0].vword_ref().str_word);
+        let mut r = pool.get_string(e[1].vword_ref().str_word.len());
+        r.push_str(&e[1].vword_ref().str_word);
+        if let Some(old) = table.insert(k, r) { pool.add_string(old) }
+      },
+      Err(e) => { pool.add_op(Op::Str(table)); return Err(e) }
+    }
+  }
+  Ok(Op::Str(table))
+}
+
+// entries: [ [ carry a b ... ] [ r c ] ]
+fn build_custom_op(math: &Math, entries: &Stack, pool: &mut Pool) -> Result<Op, ErrorKind> {
+  let mut table = pool.get_custom_op();
+  let mut digits = pool.get_digits(4);
+  let mut arity = None;
+  for v in entries.iter() {
+    let result = (|| {
+      if !v.is_stack() && !v.is_macro() || v.value_stack_ref().len() != 2 { return Err(ErrorKind::BadArgumentType) }
+      let (vk, vr) = (&v.value_stack_ref()[0], &v.value_stack_ref()[1]);
+      if !vk.is_stack() && !vk.is_macro() { return Err(ErrorKind::BadArgumentType) }
+      let n = vk.value_stack_ref().len();
+      if n < 2 || arity.is_some_and(|a| a != n) { return Err(ErrorKind::BadArgumentType) }
+      arity = Some(n);
+      digits.clear();
+      for d in op_entry(vk, n)?.iter() { digits.push(op_digit(math, d)?) }
+      let k = Operand::from_digits(&digits);
+      if !vr.is_stack() && !vr.is_macro() { return Err(ErrorKind::BadArgumentType) }
+      let m = vr.value_stack_ref().len();
+      if m != 1 && m != 2 { return Err(ErrorKind::BadArgumentType) }
+      digits.clear();
+      for d in op_entry(vr, m)?.iter() { digits.push(op_digit(math, d)?) }
+      Ok((k, Operand::from_digits(&digits)))
+    })();
+    match result {
+      Ok((k, r)) => { table.insert(k, r); },
+      Err(e) => {
+        pool.add_digits(digits);
+        pool.add_op(Op::Custom(table));
+        return Err(e)
+      }
+    }
+  }
+  pool.add_digits(digits);
+  if arity.is_none() {
+    pool.add_op(Op::Custom(table));
+    return Err(ErrorKind::BadArgumentType)
+  }
+  Ok(Op::Custom(table))
+}
+
+macro_rules! def_op_operation {
+  ($name:tt,$build:ident) => {
+    pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+      let v_name = get_word!(state, w);
+      let Some(v_table) = state.current().stack.pop() else {
+        state.current().stack.push(v_name);
+        return state.eval_error(ErrorKind::TooFewArguments, w)
+      };
+      let Some(mut math) = state.current().math.take() else {
+        state.current().stack.push(v_table);
+        state.current().stack.push(v_name);
+        return state.eval_error(ErrorKind::MathBaseZero, w)
+      };
+      let result = if math.base() == 0 { Err(ErrorKind::MathBaseZero) }
+      else if !v_table.is_stack() && !v_table.is_macro() { Err(ErrorKind::BadArgumentType) }
+      else { $build(&math, v_table.value_stack_ref(), &mut state.pool) };
+      match result {
+        Ok(op) => {
+          let name = state.string_copy(&v_name.value_stack_ref()[0].vword_ref().str_word);
+          if let Some(old) = math.ops_table.insert(name, op) { state.pool.add_op(old) }
+          state.current().math = Some(math);
+          state.pool.add_val(v_table);
+          state.pool.add_val(v_name);
+          state
+        },
+        Err(e) => {
+          state.current().math = Some(math);
+          state.current().stack.push(v_table);
+          state.current().stack.push(v_name);
+          state.eval_error(e, w)
+        }
+      }
     }
   }
 }
 
-interim_comparison_operation!{ cog_lthan, a, b, a < b }
-interim_comparison_operation!{ cog_leq, a, b, a <= b }
-interim_comparison_operation!{ cog_eq, a, b, a == b }
-interim_comparison_operation!{ cog_geq, a, b, a >= b }
-interim_comparison_operation!{ cog_gthan, a, b, a > b }
-interim_comparison_operation!{ cog_neq, a, b, a != b }
+def_op_operation!{ cog_def_unary_op, build_unary_op }
+def_op_operation!{ cog_def_binary_op, build_binary_op }
+def_op_operation!{ cog_def_str_op, build_str_op }
+def_op_operation!{ cog_def_custom_op, build_custom_op }
+
+pub fn cog_undef_op(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let v = get_word!(state, w);
+  let Some(math) = &mut state.current().math else {
+    state.current().stack.push(v);
+    return state.eval_error(ErrorKind::UndefinedMathOp, w)
+  };
+  let Some((key, op)) = math.ops_table.remove_entry(&v.value_stack_ref()[0].vword_ref().str_word) else {
+    state.current().stack.push(v);
+    return state.eval_error(ErrorKind::UndefinedMathOp, w)
+  };
+  state.pool.add_string(key);
+  state.pool.add_op(op);
+  state.pool.add_val(v);
+  state
+}
+
+pub fn cog_op(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let v_name = get_word!(state, w);
+  let name = &v_name.value_stack_ref()[0].vword_ref().str_word;
+  let math = get_math!(state, w, { state.current().stack.push(v_name) });
+  let arity = math.math().op_arity(name).ok_or(ErrorKind::UndefinedMathOp);
+  state.set_math(math);
+  let n = match arity {
+    Ok(n) => n,
+    Err(e) => {
+      state.current().stack.push(v_name);
+      return state.eval_error(e, w)
+    }
+  };
+  let stack = &mut state.current().stack;
+  if stack.len() < n {
+    stack.push(v_name);
+    return state.eval_error(ErrorKind::TooFewArguments, w)
+  }
+  if stack[stack.len() - n..].iter().any(|v| v.value_stack_ref().len() != 1 || !v.value_stack_ref()[0].is_word()) {
+    stack.push(v_name);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let len = stack.len();
+  let mut args = stack.split_off(len - n);
+  let math = state.get_math().unwrap();
+  let strs: Vec<&str> = args.iter().map(|v| v.value_stack_ref()[0].vword_ref().str_word.as_str()).collect();
+  let result = math.math().apply_op(name, &strs, &mut state);
+  state.set_math(math);
+  match result {
+    Ok(mut s) => {
+      let mut args_iter = args.drain(..);
+      let mut v = args_iter.next().unwrap();
+      std::mem::swap(&mut v.value_stack()[0].vword_mut().str_word, &mut s);
+      for arg in args_iter { state.pool.add_val(arg) }
+      state.pool.add_string(s);
+      state.pool.add_stack(args);
+      state.pool.add_val(v_name);
+      state.current().stack.push(v);
+      state
+    },
+    Err(e) => {
+      state.current().stack.append(&mut args);
+      state.pool.add_stack(args);
+      state.current().stack.push(v_name);
+      state.eval_error(e, w)
+    }
+  }
+}
 
 pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "base", cog_base);
+  add_builtin!(state, "precision", cog_precision);
   add_builtin!(state, "negc", cog_negc);
   add_builtin!(state, "radix", cog_radix);
   add_builtin!(state, "cd-delim", cog_cd_delim);
@@ -342,12 +760,14 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "meta-delim", cog_meta_delim);
   add_builtin!(state, "digits", cog_digits);
   add_builtin!(state, "get-base", cog_get_base);
+  add_builtin!(state, "get-precision", cog_get_precision);
   add_builtin!(state, "get-negc", cog_get_negc);
   add_builtin!(state, "get-radix", cog_get_radix);
   add_builtin!(state, "get-cd-delim", cog_get_cd_delim);
   add_builtin!(state, "get-meta-radix", cog_get_meta_radix);
   add_builtin!(state, "get-meta-delim", cog_get_meta_delim);
   add_builtin!(state, "get-digits", cog_get_digits);
+  add_builtin!(state, "convert", cog_convert);
   add_builtin!(state, "=", cog_equals);
   add_builtin!(state, "!=", cog_nequals);
   add_builtin!(state, "and", cog_and);
@@ -358,11 +778,39 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "-", cog_minus);
   add_builtin!(state, "*", cog_mul);
   add_builtin!(state, "/", cog_div);
+  add_builtin!(state, "mod", cog_mod);
+  add_builtin!(state, "rem", cog_rem);
+  add_builtin!(state, "divmod", cog_divmod);
+  add_builtin!(state, "gcd", cog_gcd);
+  add_builtin!(state, "lcm", cog_lcm);
   add_builtin!(state, "pow", cog_pow);
+  add_builtin!(state, "abs", cog_abs);
+  add_builtin!(state, "sign", cog_sign);
+  add_builtin!(state, "band", cog_band);
+  add_builtin!(state, "bor", cog_bor);
+  add_builtin!(state, "bxor", cog_bxor);
+  add_builtin!(state, "bnot", cog_bnot);
+  add_builtin!(state, "shl", cog_shl);
+  add_builtin!(state, "shr", cog_shr);
+  add_builtin!(state, "popcount", cog_popcount);
+  add_builtin!(state, "sqrt", cog_sqrt);
+  add_builtin!(state, "exp", cog_exp);
+  add_builtin!(state, "ln", cog_ln);
+  add_builtin!(state, "sin", cog_sin);
+  add_builtin!(state, "cos", cog_cos);
+  add_builtin!(state, "atan", cog_atan);
+  add_builtin!(state, "def-unary-op", cog_def_unary_op);
+  add_builtin!(state, "def-binary-op", cog_def_binary_op);
+  add_builtin!(state, "def-str-op", cog_def_str_op);
+  add_builtin!(state, "def-custom-op", cog_def_custom_op);
+  add_builtin!(state, "undef-op", cog_undef_op);
+  add_builtin!(state, "op", cog_op);
   add_builtin!(state, "<", cog_lthan);
   add_builtin!(state, "<=", cog_leq);
   add_builtin!(state, "==", cog_eq);
   add_builtin!(state, ">=", cog_geq);
   add_builtin!(state, ">", cog_gthan);
   add_builtin!(state, "!==", cog_neq);
+  add_builtin!(state, "min", cog_min);
+  add_builtin!(state, "max", cog_max);
 }
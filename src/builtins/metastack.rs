@@ -2,10 +2,10 @@ use crate::*;
 
 pub fn cog_cd(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if !v.is_stack() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   state.ensure_quoted(v.value_stack());
   state.stack.push(v);
@@ -15,10 +15,10 @@ pub fn cog_cd(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 pub fn cog_ccd(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let mut cur_v = state.pop_cur();
   let stack = &mut cur_v.metastack_container().stack;
-  let Some(mut v) = stack.pop() else { return state.push_cur(cur_v).eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.push_cur(cur_v).eval_error(ErrorKind::TooFewArguments, w) };
   if !v.is_stack() {
     stack.push(v);
-    return state.push_cur(cur_v).eval_error("BAD ARGUMENT TYPE", w)
+    return state.push_cur(cur_v).eval_error(ErrorKind::BadArgumentType, w)
   }
   state.pool.add_val(cur_v);
   state.ensure_quoted(v.value_stack());
@@ -89,10 +89,10 @@ pub fn cog_su(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
 
 pub fn cog_chroot(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if !v.is_stack() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let tmpstack = state.stack;
   state.chroots.push(tmpstack);
@@ -9,15 +9,15 @@ pub fn cog_return(mut state: CognitionState, _: Option<&Value>) -> CognitionStat
 
 pub fn cog_exit(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let word_v = v.value_stack_ref().first().unwrap();
   if !word_v.is_word() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let code = state.string_copy(&word_v.vword_ref().str_word);
   state.exit_code = Some(code);
@@ -68,8 +68,8 @@ pub fn cog_getargs(mut state: CognitionState, _: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_setargs(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().iter().any(|x| !x.is_word()) { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().iter().any(|x| !x.is_word()) { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let tmpstack = state.args;
   state.args = match v {
     Value::Stack(mut vstack) => {
@@ -96,10 +96,10 @@ pub fn cog_void(mut state: CognitionState, _: Option<&Value>) -> CognitionState
 
 pub fn cog_void_questionmark(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let Value::Custom(vcustom) = v.value_stack_ref().first().unwrap() else {
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let vword = if vcustom.custom.as_any().downcast_ref::<Void>().is_some() {
     let mut vword = state.pool.get_vword(1);
@@ -114,8 +114,8 @@ pub fn cog_void_questionmark(mut state: CognitionState, w: Option<&Value>) -> Co
 
 pub fn cog_custom_questionmark(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let vword = if v.value_stack_ref().first().unwrap().is_custom() {
     let mut vword = state.pool.get_vword(1);
     vword.str_word.push('t');
@@ -137,6 +137,7 @@ pub fn cog_ctype(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_var(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  require!(state, w, Env);
   let mut vw = get_word!(state, w);
   let vword = vw.value_stack_ref().first().unwrap().vword_ref();
   match std::env::var(&vword.str_word) {
@@ -161,11 +162,11 @@ pub fn cog_getp(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
   let Some(math) = state.get_math() else {
     state.pool.add_vword(vword);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   if math.math().base() == 0 {
     state.pool.add_vword(vword);
-    return state.with_math(math).eval_error("MATH BASE ZERO", w)
+    return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w)
   }
   for i in array {
     let s = match math.math().itos(i, &mut state) {
@@ -189,11 +190,11 @@ pub fn cog_setp(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let v = get_word!(state, w);
   let Some(math) = state.get_math() else {
     state.current().stack.push(v);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   if math.math().base() == 0 {
     state.current().stack.push(v);
-    return state.with_math(math).eval_error("MATH BASE ZERO", w)
+    return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w)
   }
   let mut array: [isize;32] = [0;32];
   let mut idx_beg = 0;
@@ -229,6 +230,69 @@ pub fn cog_setp(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   state.with_math(math)
 }
 
+// starts profiling afresh, discarding anything collected so far
+pub fn cog_profile_start(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
+  state.profiler = Some(profiler::Profiler::new());
+  state
+}
+
+pub fn cog_profile_stop(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
+  state.profiler = None;
+  state
+}
+
+fn number_word(state: &mut CognitionState, math: &MathBorrower, n: u128) -> Result<Value, ErrorKind> {
+  if n > isize::MAX as u128 { return Err(ErrorKind::OutOfBounds) }
+  let s = math.math().itos(n as isize, state)?;
+  let mut vword = state.pool.get_vword(s.len());
+  vword.str_word.push_str(&s);
+  state.pool.add_string(s);
+  Ok(Value::Word(vword))
+}
+
+// ( -- entries pool ) where entries holds [ name calls inclusive exclusive ]
+// for each profiled word, longest inclusive time first, with times in
+// microseconds, and pool is [ hits misses ]
+pub fn cog_profile(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let Some(profiler) = state.profiler.take() else { return state.eval_error(ErrorKind::NotProfiling, w) };
+  let math = get_math!(state, w, { state.profiler = Some(profiler) });
+  let sorted = profiler.sorted();
+  let mut entries = state.pool.get_vstack(sorted.len());
+  let mut pool = state.pool.get_vstack(2);
+  let mut result = Ok(());
+  for (name, entry) in sorted {
+    let mut ventry = state.pool.get_vstack(4);
+    let mut vname = state.pool.get_vword(name.len());
+    vname.str_word.push_str(name);
+    ventry.container.stack.push(Value::Word(vname));
+    for n in [entry.calls as u128, entry.inclusive.as_micros(), entry.exclusive.as_micros()] {
+      match number_word(&mut state, &math, n) {
+        Ok(v) => ventry.container.stack.push(v),
+        Err(e) => { result = Err(e); break }
+      }
+    }
+    entries.container.stack.push(Value::Stack(ventry));
+    if result.is_err() { break }
+  }
+  for n in [state.pool.hits as u128, state.pool.misses as u128] {
+    if result.is_err() { break }
+    match number_word(&mut state, &math, n) {
+      Ok(v) => pool.container.stack.push(v),
+      Err(e) => result = Err(e)
+    }
+  }
+  state.profiler = Some(profiler);
+  state.set_math(math);
+  if let Err(e) = result {
+    state.pool.add_vstack(entries);
+    state.pool.add_vstack(pool);
+    return state.eval_error(e, w)
+  }
+  state.current().stack.push(Value::Stack(entries));
+  state.current().stack.push(Value::Stack(pool));
+  state
+}
+
 pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "nothing");
   add_builtin!(state, "nop", cog_nop);
@@ -245,6 +309,9 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "var", cog_var);
   add_builtin!(state, "getp", cog_getp);
   add_builtin!(state, "setp", cog_setp);
+  add_builtin!(state, "profile-start", cog_profile_start);
+  add_builtin!(state, "profile-stop", cog_profile_stop);
+  add_builtin!(state, "profile", cog_profile);
 
   state.add_const_word("VERSION", VERSION);
 }
@@ -21,7 +21,7 @@ pub fn cog_getf(mut state: CognitionState, _: Option<&Value>) -> CognitionState
 
 pub fn cog_f(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let Some(list) = state.current().stack.pop() else {
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   let lstack = list.value_stack_ref();
   if lstack.len() == 0 {
@@ -33,7 +33,7 @@ pub fn cog_f(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   }
   if lstack.iter().any(|x| !x.is_word()) {
     state.current().stack.push(list);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   if state.current().faliases.is_none() {
     state.current().faliases = Some(state.pool.get_faliases(DEFAULT_FALIASES_SIZE));
@@ -48,7 +48,7 @@ pub fn cog_f(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_aliasf(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let Some(list) = state.current().stack.pop() else {
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   let lstack = list.value_stack_ref();
   if lstack.len() == 0 {
@@ -57,7 +57,7 @@ pub fn cog_aliasf(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
   }
   if lstack.iter().any(|x| !x.is_word()) {
     state.current().stack.push(list);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   if state.current().faliases.is_none() {
     state.current().faliases = Some(state.pool.get_faliases(DEFAULT_FALIASES_SIZE));
@@ -70,7 +70,7 @@ pub fn cog_aliasf(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_unaliasf(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let Some(list) = state.current().stack.pop() else {
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   let lstack = list.value_stack_ref();
   if lstack.len() == 0 || state.current().faliases.is_none() {
@@ -79,7 +79,7 @@ pub fn cog_unaliasf(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   }
   if lstack.iter().any(|x| !x.is_word()) {
     state.current().stack.push(list);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let mut faliases = state.current().faliases.take().unwrap();
   for v in lstack.iter() { faliases.remove(&v.vword_ref().str_word); }
@@ -89,16 +89,16 @@ pub fn cog_unaliasf(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 
 pub fn cog_d(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if cur.delims.is_none() {
     state.current().delims = Some(state.pool.get_string(vword.str_word.len()));
@@ -113,16 +113,16 @@ pub fn cog_d(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_i(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if cur.ignored.is_none() {
     state.current().ignored = Some(state.pool.get_string(vword.str_word.len()));
@@ -137,16 +137,16 @@ pub fn cog_i(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_s(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if cur.singlets.is_none() {
     state.current().singlets = Some(state.pool.get_string(vword.str_word.len()));
@@ -239,16 +239,16 @@ pub fn cog_gets(mut state: CognitionState, _: Option<&Value>) -> CognitionState
 
 pub fn cog_delim(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut delims = cur.delims.take();
   for c in vword.str_word.chars() {
@@ -266,16 +266,16 @@ pub fn cog_delim(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_ignore(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut ignored = cur.ignored.take();
   for c in vword.str_word.chars() {
@@ -293,16 +293,16 @@ pub fn cog_ignore(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_singlet(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut singlets = cur.singlets.take();
   for c in vword.str_word.chars() {
@@ -320,16 +320,16 @@ pub fn cog_singlet(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 
 pub fn cog_undelim(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if let Some(delims) = &mut state.current().delims {
     for c in vword.str_word.chars() { delims.retain(|x| x != c); }
@@ -340,16 +340,16 @@ pub fn cog_undelim(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 
 pub fn cog_unignore(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if let Some(ignored) = &mut state.current().ignored {
     for c in vword.str_word.chars() { ignored.retain(|x| x != c); }
@@ -360,16 +360,16 @@ pub fn cog_unignore(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 
 pub fn cog_unsinglet(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = cur.stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() != 1 {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let word_val = &stack[0];
   let Value::Word(vword) = word_val else {
     cur.stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   if let Some(singlets) = &mut state.current().singlets {
     for c in vword.str_word.chars() { singlets.retain(|x| x != c); }
@@ -379,8 +379,8 @@ pub fn cog_unsinglet(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 }
 
 pub fn cog_filename(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(parser) = &mut state.parser else { return state.eval_error("NO PARSER", w) };
-  let Some(filename) = parser.filename.take() else { return state.eval_error("NO FILENAME", w) };
+  let Some(parser) = &mut state.parser else { return state.eval_error(ErrorKind::NoParser, w) };
+  let Some(filename) = parser.filename.take() else { return state.eval_error(ErrorKind::NoFilename, w) };
   let mut vword = state.pool.get_vword(filename.len());
   vword.str_word.push_str(&filename);
   state.parser.as_mut().unwrap().filename = Some(filename);
@@ -389,12 +389,12 @@ pub fn cog_filename(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 }
 
 pub fn cog_line(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(ref parser) = state.parser else { return state.eval_error("NO PARSER", w) };
-  if parser.filename.is_none() { return state.eval_error("NO FILENAME", w) }
+  let Some(ref parser) = state.parser else { return state.eval_error(ErrorKind::NoParser, w) };
+  if parser.filename.is_none() { return state.eval_error(ErrorKind::NoFilename, w) }
   let line = parser.line;
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
-  if line > isize::MAX as usize { return state.with_math(math).eval_error("OUT OF BOUNDS", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
+  if line > isize::MAX as usize { return state.with_math(math).eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(line as isize, &mut state) {
     Ok(s) => {
       state.set_math(math);
@@ -409,12 +409,12 @@ pub fn cog_line(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_column(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(ref parser) = state.parser else { return state.eval_error("NO PARSER", w) };
-  if parser.filename.is_none() { return state.eval_error("NO FILENAME", w) }
+  let Some(ref parser) = state.parser else { return state.eval_error(ErrorKind::NoParser, w) };
+  if parser.filename.is_none() { return state.eval_error(ErrorKind::NoFilename, w) }
   let column = parser.column;
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
-  if column > isize::MAX as usize { return state.with_math(math).eval_error("OUT OF BOUNDS", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
+  if column > isize::MAX as usize { return state.with_math(math).eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(column as isize, &mut state) {
     Ok(s) => {
       state.set_math(math);
@@ -429,15 +429,15 @@ pub fn cog_column(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 }
 
 pub fn cog_streval(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(mut v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if !(stack.len() == 1 || stack.len() == 2 || stack.len() == 4) {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if stack.iter().any(|x| !x.is_word()) {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let parser_loc = if stack.len() == 4 {
     let filename = &stack.get(1).unwrap().vword_ref().str_word;
@@ -479,7 +479,7 @@ pub fn cog_streval(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_strstack(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let stack = v.value_stack_ref();
   if stack.len() == 0 {
     state.pool.add_val(v);
@@ -487,7 +487,7 @@ pub fn cog_strstack(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   }
   if stack.iter().any(|x| !x.is_word()) {
     state.current().stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let mut quot = state.pool.get_vstack(DEFAULT_STACK_SIZE);
   for v in stack.iter() {
@@ -14,12 +14,12 @@ macro_rules! serialize_value {
   }
 }
 
-pub fn serialize_value(state: &mut CognitionState, vdata: Value, vformat: Value, writer: &mut dyn Write) -> Option<(Value, Value, &'static str)> {
+pub fn serialize_value(state: &mut CognitionState, vdata: Value, vformat: Value, writer: &mut dyn Write) -> Option<(Value, Value, ErrorKind)> {
   let format_name = &vformat.value_stack_ref().first().unwrap().vword_ref().str_word;
   let format = Some(format_name);
   let func = get_serialize_fn!(
     state, format_name, format, 6, f,
-    { return Some((vdata, vformat, "INVALID SERDE FORMAT")) },
+    { return Some((vdata, vformat, ErrorKind::InvalidSerdeFormat)) },
     { unreachable!() }, _ext,
     { unreachable!() }
   );
@@ -29,18 +29,18 @@ pub fn serialize_value(state: &mut CognitionState, vdata: Value, vformat: Value,
       state.pool.add_val(vformat);
       None
     },
-    Err(_) => Some((vdata, vformat, "SERIALIZATION FAILED"))
+    Err(_) => Some((vdata, vformat, ErrorKind::SerializationFailed))
   }
 }
 
 pub fn cog_serialize(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_word!(state, w);
   let v1 = state.current().stack.pop().unwrap();
   if v1.value_stack_ref().len() != 1 {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let format_name = &v2.value_stack_ref().first().unwrap().vword_ref().str_word;
   let format = Some(format_name);
@@ -49,7 +49,7 @@ pub fn cog_serialize(mut state: CognitionState, w: Option<&Value>) -> CognitionS
     {
       state.current().stack.push(v1);
       state.current().stack.push(v2);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
@@ -58,14 +58,14 @@ pub fn cog_serialize(mut state: CognitionState, w: Option<&Value>) -> CognitionS
   if func(&v1.value_stack_ref().first().unwrap(), &mut vec).is_err() {
     state.current().stack.push(v1);
     state.current().stack.push(v2);
-    return state.eval_error("SERIALIZATION FAILED", w)
+    return state.eval_error(ErrorKind::SerializationFailed, w)
   }
   let string = match String::from_utf8(vec) {
     Ok(s) => s,
     Err(_) => {
       state.current().stack.push(v1);
       state.current().stack.push(v2);
-      return state.eval_error("INVALID STRING", w)
+      return state.eval_error(ErrorKind::InvalidString, w)
     }
   };
   let mut vword = state.pool.get_vword(0);
@@ -78,7 +78,8 @@ pub fn cog_serialize(mut state: CognitionState, w: Option<&Value>) -> CognitionS
 }
 
 pub fn cog_fserialize(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.filename_denied(1, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
+  if state.current().stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v3 = get_word!(state, w);
   let stack = &mut state.current().stack;
   let mut v2 = stack.pop().unwrap();
@@ -88,7 +89,7 @@ pub fn cog_fserialize(mut state: CognitionState, w: Option<&Value>) -> Cognition
     stack.push(v1);
     stack.push(v2);
     stack.push(v3);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let vwriter = v2.value_stack().first_mut().unwrap();
   match vwriter {
@@ -106,7 +107,7 @@ pub fn cog_fserialize(mut state: CognitionState, w: Option<&Value>) -> Cognition
         stack.push(v1);
         stack.push(v2);
         stack.push(v3);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v2);
     },
@@ -119,7 +120,7 @@ pub fn cog_fserialize(mut state: CognitionState, w: Option<&Value>) -> Cognition
         stack.push(v1);
         stack.push(v2);
         stack.push(v3);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.current().stack.push(v2);
     },
@@ -127,7 +128,7 @@ pub fn cog_fserialize(mut state: CognitionState, w: Option<&Value>) -> Cognition
       stack.push(v1);
       stack.push(v2);
       stack.push(v3);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
@@ -142,7 +143,7 @@ pub fn cog_deserialize(mut state: CognitionState, w: Option<&Value>) -> Cognitio
     {
       state.current().stack.push(vdata);
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
@@ -158,7 +159,7 @@ pub fn cog_deserialize(mut state: CognitionState, w: Option<&Value>) -> Cognitio
     Err(_) => {
       state.current().stack.push(vdata);
       state.current().stack.push(vformat);
-      state.eval_error("DESERIALIZATION FAILED", w)
+      state.eval_error(ErrorKind::DeserializationFailed, w)
     }
   }
 }
@@ -171,7 +172,7 @@ pub fn cog_state(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     state, format_name, format, 4, f,
     {
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
@@ -179,13 +180,13 @@ pub fn cog_state(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let mut vec = Vec::<u8>::with_capacity(DEFAULT_STRING_LENGTH);
   if func(&state, &mut vec).is_err() {
     state.current().stack.push(vformat);
-    return state.eval_error("SERIALIZATION FAILED", w)
+    return state.eval_error(ErrorKind::SerializationFailed, w)
   }
   let string = match String::from_utf8(vec) {
     Ok(s) => s,
     Err(_) => {
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID STRING", w)
+      return state.eval_error(ErrorKind::InvalidString, w)
     }
   };
   let mut vword = state.pool.get_vword(0);
@@ -206,30 +207,31 @@ macro_rules! serialize_state {
   }
 }
 
-pub fn serialize_state(state: &mut CognitionState, vformat: Value, writer: &mut dyn Write) -> Option<(Value, &'static str)> {
+pub fn serialize_state(state: &mut CognitionState, vformat: Value, writer: &mut dyn Write) -> Option<(Value, ErrorKind)> {
   let format_name = &vformat.value_stack_ref().first().unwrap().vword_ref().str_word;
   let format = Some(format_name);
   let func = get_serialize_fn!(
     state, format_name, format, 4, f,
-    { return Some((vformat, "INVALID SERDE FORMAT")) },
+    { return Some((vformat, ErrorKind::InvalidSerdeFormat)) },
     { unreachable!() }, _ext,
     { unreachable!() }
   );
   match func(state, writer) {
     Ok(_) => { state.pool.add_val(vformat); None },
-    Err(e) => { println!("{e}"); Some((vformat, "SERIALIZATION FAILED")) }
+    Err(e) => { println!("{e}"); Some((vformat, ErrorKind::SerializationFailed)) }
   }
 }
 
 pub fn cog_fstate(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.filename_denied(1, Capability::FsWrite) { return state.eval_error(ErrorKind::PermissionDenied, w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let v2 = get_word!(state, w);
   let stack = &mut state.current().stack;
   let mut v1 = stack.pop().unwrap();
   if v1.value_stack_ref().len() != 1 {
     stack.push(v1);
     stack.push(v2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let vwriter = v1.value_stack().first_mut().unwrap();
   match vwriter {
@@ -246,7 +248,7 @@ pub fn cog_fstate(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       } else {
         stack.push(v1);
         stack.push(v2);
-        return state.eval_error("BAD ARGUMENT TYPE", w)
+        return state.eval_error(ErrorKind::BadArgumentType, w)
       }
       state.current().stack.push(v1);
     },
@@ -258,21 +260,21 @@ pub fn cog_fstate(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       } else {
         stack.push(v1);
         stack.push(v2);
-        return state.eval_error("INVALID FILENAME", w)
+        return state.eval_error(ErrorKind::InvalidFilename, w)
       };
       state.pool.add_val(v1);
     },
     _ => return {
       stack.push(v1);
       stack.push(v2);
-      state.eval_error("BAD ARGUMENT TYPE", w)
+      state.eval_error(ErrorKind::BadArgumentType, w)
     },
   }
   state
 }
 
 pub fn cog_restate(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let (vdata, vformat) = get_2_words!(state, w);
 
   let data = &vdata.value_stack_ref().first().unwrap().vword_ref().str_word;
@@ -284,20 +286,22 @@ pub fn cog_restate(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
     {
       state.current().stack.push(vdata);
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
   );
-  let new_state = serde::cogstate_init();
+  let mut new_state = serde::cogstate_init();
+  new_state.capabilities = state.capabilities;
   if let Ok(s) = deserialize_fn(data, true, new_state) { return s }
   state.current().stack.push(vdata);
   state.current().stack.push(vformat);
-  state.eval_error("DESERIALIZATION FAILED", w)
+  state.eval_error(ErrorKind::DeserializationFailed, w)
 }
 
 pub fn cog_load(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  require!(state, w, Fllib);
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let (vdata, vformat) = get_2_words!(state, w);
   let format_name = &vformat.value_stack_ref().first().unwrap().vword_ref().str_word;
   let format = Some(format_name);
@@ -306,7 +310,7 @@ pub fn cog_load(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     {
       state.current().stack.push(vdata);
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
@@ -314,14 +318,10 @@ pub fn cog_load(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   let data = &vdata.value_stack_ref().first().unwrap().vword_ref().str_word;
   match deserialize_fn(data, state) {
     Ok(state) => state,
-    Err(mut e) => {
-      e.0.current().stack.push(vdata);
-      e.0.current().stack.push(vformat);
-      match format!("{}", e.1).as_str() {
-        "INVALID FILENAME" => e.0.eval_error("INVALID FILENAME", w),
-        "INVALID FLLIB" => e.0.eval_error("INVALID FLLIB", w),
-        _ => e.0.eval_error("DESERIALIZATION FAILED", w)
-      }
+    Err((mut state, kind, _)) => {
+      state.current().stack.push(vdata);
+      state.current().stack.push(vformat);
+      state.eval_error(kind, w)
     }
   }
 }
@@ -334,7 +334,7 @@ pub fn cog_describe_fllibs(mut state: CognitionState, w: Option<&Value>) -> Cogn
     state, format_name, format, 5, f,
     {
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
@@ -342,13 +342,13 @@ pub fn cog_describe_fllibs(mut state: CognitionState, w: Option<&Value>) -> Cogn
   let mut vec = Vec::<u8>::with_capacity(DEFAULT_STRING_LENGTH);
   if func(&state.fllibs, &mut vec).is_err() {
     state.current().stack.push(vformat);
-    return state.eval_error("SERIALIZATION FAILED", w)
+    return state.eval_error(ErrorKind::SerializationFailed, w)
   }
   let string = match String::from_utf8(vec) {
     Ok(s) => s,
     Err(_) => {
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID STRING", w)
+      return state.eval_error(ErrorKind::InvalidString, w)
     }
   };
   let mut vword = state.pool.get_vword(0);
@@ -360,7 +360,7 @@ pub fn cog_describe_fllibs(mut state: CognitionState, w: Option<&Value>) -> Cogn
 }
 
 pub fn cog_serialize_map(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current().stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if state.current().stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vformat = get_word!(state, w);
   let stack = &mut state.current().stack;
   let mut vmap = stack.pop().unwrap();
@@ -374,7 +374,7 @@ pub fn cog_serialize_map(mut state: CognitionState, w: Option<&Value>) -> Cognit
   if !vmap.value_stack().iter().all(requirement) {
     stack.push(vmap);
     stack.push(vformat);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let format_name = &vformat.value_stack_ref().first().unwrap().vword_ref().str_word;
   let format = Some(format_name);
@@ -382,7 +382,7 @@ pub fn cog_serialize_map(mut state: CognitionState, w: Option<&Value>) -> Cognit
     state, format_name, format, 8, f,
     {
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID SERDE FORMAT", w)
+      return state.eval_error(ErrorKind::InvalidSerdeFormat, w)
     },
     { unreachable!() }, _ext,
     { unreachable!() }
@@ -390,13 +390,13 @@ pub fn cog_serialize_map(mut state: CognitionState, w: Option<&Value>) -> Cognit
   let mut vec = Vec::<u8>::with_capacity(DEFAULT_STRING_LENGTH);
   if func(&vmap, &mut vec).is_err() {
     state.current().stack.push(vformat);
-    return state.eval_error("SERIALIZATION FAILED", w)
+    return state.eval_error(ErrorKind::SerializationFailed, w)
   }
   let string = match String::from_utf8(vec) {
     Ok(s) => s,
     Err(_) => {
       state.current().stack.push(vformat);
-      return state.eval_error("INVALID STRING", w)
+      return state.eval_error(ErrorKind::InvalidString, w)
     }
   };
   let mut vword = state.pool.get_vword(0);
@@ -451,6 +451,43 @@ pub fn cog_remove_format(mut state: CognitionState, w: Option<&Value>) -> Cognit
   state
 }
 
+// Always unwrap the Option
+pub struct SnapshotCustom { pub snapshot: Option<Snapshot> }
+
+#[custom(serde_as_void)]
+impl Custom for SnapshotCustom {
+  fn printfunc(&self, f: &mut dyn Write) {
+    fwrite_check!(f, b"(snapshot)");
+  }
+  fn copyfunc(&self, state: &mut CognitionState) -> Box<dyn Custom> {
+    let snapshot = state.snapshot_copy(self.snapshot.as_ref().unwrap());
+    Box::new(SnapshotCustom{ snapshot: Some(snapshot) })
+  }
+}
+
+// ( -- [ (snapshot) ] ) in-memory copy of the state, see CognitionState::snapshot
+pub fn cog_snapshot(mut state: CognitionState, _: Option<&Value>) -> CognitionState {
+  let snapshot = state.snapshot();
+  let vcustom = VCustom::with_custom(Box::new(SnapshotCustom{ snapshot: Some(snapshot) }));
+  state.push_quoted(Value::Custom(vcustom));
+  state
+}
+
+// ( [ (snapshot) ] -- ) rolls the metastack and chroots back to the snapshot;
+// evaluation carries on from here, as the parser and family are left alone
+pub fn cog_restore(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let mut v = get_custom!(state, w);
+  let custom = v.value_stack().first_mut().unwrap().vcustom_mut().custom.as_any_mut();
+  let Some(snapshot_custom) = custom.downcast_mut::<SnapshotCustom>() else {
+    state.current().stack.push(v);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  };
+  let snapshot = snapshot_custom.snapshot.take().unwrap();
+  state.pool.add_val(v);
+  state.restore(snapshot);
+  state
+}
+
 pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "serialize", cog_serialize);
   add_builtin!(state, "fserialize", cog_fserialize);
@@ -459,6 +496,8 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "fstate", cog_fstate);
   add_builtin!(state, "restate", cog_restate);
   add_builtin!(state, "load", cog_load);
+  add_builtin!(state, "snapshot", cog_snapshot);
+  add_builtin!(state, "restore", cog_restore);
   // describe-fllibs could be replaced with ( fllibs swap serialize-map )
   add_builtin!(state, "describe-fllibs", cog_describe_fllibs);
   add_builtin!(state, "serialize-map", cog_serialize_map);
@@ -0,0 +1,156 @@
+use crate::*;
+use crate::builtins::combinators::{eval_step, swap_stack, restore_stack};
+
+use std::cmp::Ordering;
+
+/// Stable merge sort of a permutation, stopping at the first error
+fn merge_sort<F>(idx: &mut Vec<usize>, less: &mut F) -> Result<(), ErrorKind>
+where F: FnMut(usize, usize) -> Result<bool, ErrorKind>
+{
+  if idx.len() < 2 { return Ok(()) }
+  let mut right = idx.split_off(idx.len() / 2);
+  merge_sort(idx, less)?;
+  merge_sort(&mut right, less)?;
+  let left = std::mem::replace(idx, Vec::with_capacity(idx.len() + right.len()));
+  let (mut i, mut j) = (0, 0);
+  while i < left.len() && j < right.len() {
+    // equal elements keep their order, so the right side only goes first if strictly less
+    if less(right[j], left[i])? { idx.push(right[j]); j += 1 }
+    else { idx.push(left[i]); i += 1 }
+  }
+  idx.extend_from_slice(&left[i..]);
+  idx.extend_from_slice(&right[j..]);
+  Ok(())
+}
+
+fn permute(stack: &mut Stack, idx: &[usize]) {
+  let mut taken: Vec<Option<Value>> = stack.drain(..).map(Some).collect();
+  stack.extend(idx.iter().map(|i| taken[*i].take().unwrap()));
+}
+
+// elements are words, or stacks holding a single word
+fn word_str(v: &Value) -> Option<&str> {
+  if v.is_word() { return Some(&v.vword_ref().str_word) }
+  if !v.is_stack() && !v.is_macro() { return None }
+  let stack = v.value_stack_ref();
+  if stack.len() != 1 || !stack[0].is_word() { return None }
+  Some(&stack[0].vword_ref().str_word)
+}
+
+// sorts the words of a stack or macro by their strings in byte order
+pub fn cog_sort(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if !v.is_stack() && !v.is_macro() || v.value_stack_ref().iter().any(|x| word_str(x).is_none()) {
+    stack.push(v);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let values = v.value_stack_ref();
+  let mut idx: Vec<usize> = (0..values.len()).collect();
+  let _ = merge_sort(&mut idx, &mut |i, j| {
+    Ok(word_str(&values[i]).unwrap().cmp(word_str(&values[j]).unwrap()) == Ordering::Less)
+  });
+  permute(v.value_stack(), &idx);
+  state.current().stack.push(v);
+  state
+}
+
+// sorts the words of a stack or macro as numbers in the current math
+pub fn cog_nsort(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if !v.is_stack() && !v.is_macro() || v.value_stack_ref().iter().any(|x| word_str(x).is_none()) {
+    stack.push(v);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let math = get_math!(state, w, { state.current().stack.push(v) });
+  let values = v.value_stack_ref();
+  let mut idx: Vec<usize> = (0..values.len()).collect();
+  let result = merge_sort(&mut idx, &mut |i, j| {
+    let (s1, s2) = (word_str(&values[i]).unwrap(), word_str(&values[j]).unwrap());
+    Ok(math.math().compare(s1, s2, &mut state)? == Ordering::Less)
+  });
+  state.set_math(math);
+  if let Err(e) = result {
+    state.current().stack.push(v);
+    return state.eval_error(e, w)
+  }
+  permute(v.value_stack(), &idx);
+  state.current().stack.push(v);
+  state
+}
+
+// ( stack comparator -- sorted ) where the comparator ( a b -- flag ) leaves
+// a nonempty word if a goes strictly before b. It runs on an empty stack, and
+// if it fails or errors the stack and comparator are restored. Like a loop's
+// quote, a comparator that returns, exits or leaves the container stops the
+// sort, and the stack is dropped.
+pub fn cog_sort_by(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+  let stack = &mut state.current().stack;
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
+  let v_cmp = stack.pop().unwrap();
+  let mut v = stack.pop().unwrap();
+  if !v.is_stack() && !v.is_macro() {
+    stack.push(v);
+    stack.push(v_cmp);
+    return state.eval_error(ErrorKind::BadArgumentType, w)
+  }
+  let wd = state.pool.get_word_def(v_cmp);
+  let scratch = state.pool.get_stack(2);
+  let saved = swap_stack(&mut state, scratch);
+  let depth = state.stack.len();
+  let values = v.value_stack_ref();
+  let mut idx: Vec<usize> = (0..values.len()).collect();
+  let mut slot = Some(state);
+  let mut stopped = false;
+  let result = merge_sort(&mut idx, &mut |i, j| {
+    let mut state = slot.take().unwrap();
+    let errors = state.current_ref().err_stack.as_ref().map_or(0, |e| e.len());
+    let (a, b) = (state.value_copy(&values[i]), state.value_copy(&values[j]));
+    for x in [a, b] {
+      if x.is_stack() || x.is_macro() { state.current().stack.push(x) }
+      else { state.push_quoted(x) }
+    }
+    let go;
+    (state, go) = eval_step(state, &wd, w, depth);
+    let result = if !go { stopped = true; Err(ErrorKind::BadComparator) }
+    else if state.current_ref().err_stack.as_ref().map_or(0, |e| e.len()) != errors { Err(ErrorKind::BadComparator) }
+    else {
+      let stack = &state.current_ref().stack;
+      if stack.len() != 1 { Err(ErrorKind::BadComparator) }
+      else { word_str(&stack[0]).map(|s| !s.is_empty()).ok_or(ErrorKind::BadComparator) }
+    };
+    if go {
+      while let Some(x) = state.current().stack.pop() { state.pool.add_val(x) }
+    }
+    slot = Some(state);
+    result
+  });
+  let mut state = slot.unwrap();
+  restore_stack(&mut state, saved);
+  if stopped {
+    state.pool.add_word_def(wd);
+    state.pool.add_val(v);
+    return state
+  }
+  match result {
+    Ok(()) => {
+      permute(v.value_stack(), &idx);
+      state.pool.add_word_def(wd);
+      state.current().stack.push(v);
+      state
+    },
+    Err(e) => {
+      let v_cmp = Arc::try_unwrap(wd).unwrap_or_else(|wd| state.value_copy(&wd));
+      state.current().stack.push(v);
+      state.current().stack.push(v_cmp);
+      state.eval_error(e, w)
+    }
+  }
+}
+
+pub fn add_builtins(state: &mut CognitionState) {
+  add_builtin!(state, "sort", cog_sort);
+  add_builtin!(state, "nsort", cog_nsort);
+  add_builtin!(state, "sort-by", cog_sort_by);
+}
@@ -12,13 +12,13 @@ pub fn cog_clear(mut state: CognitionState, _: Option<&Value>) -> CognitionState
 pub fn cog_drop(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   match state.current().stack.pop() {
     Some(v1) => { state.pool.add_val(v1); state },
-    None     => { state.eval_error("TOO FEW ARGUMENTS", w) },
+    None     => { state.eval_error(ErrorKind::TooFewArguments, w) },
   }
 }
 
 pub fn cog_swap(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v1 = stack.pop().unwrap();
   let v2 = stack.pop().unwrap();
   stack.push(v1);
@@ -27,7 +27,7 @@ pub fn cog_swap(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_dup(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let new_v = state.value_copy(&v);
   state.current().stack.push(v);
   state.current().stack.push(new_v);
@@ -36,9 +36,9 @@ pub fn cog_dup(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_ssize(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let length = state.current().stack.len();
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
-  if length > isize::MAX as usize { return state.with_math(math).eval_error("OUT OF BOUNDS", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
+  if length > isize::MAX as usize { return state.with_math(math).eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(length as isize, &mut state) {
     Ok(s) => {
       let mut v = state.pool.get_vword(s.len());
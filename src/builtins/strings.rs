@@ -1,18 +1,20 @@
 use crate::*;
 
+use std::cmp::Ordering;
+
 pub fn cog_concat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v2 = stack.last().unwrap();
   let v1 = stack.get(stack.len() - 2).unwrap();
-  if v1.value_stack_ref().len() == 0 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if v1.value_stack_ref().len() == 0 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let mut concatlen: usize = 0;
   for v in v1.value_stack_ref().iter() {
-    let Value::Word(vw) = v else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+    let Value::Word(vw) = v else { return state.eval_error(ErrorKind::BadArgumentType, w) };
     concatlen += vw.str_word.len()
   }
   for v in v2.value_stack_ref().iter() {
-    let Value::Word(vw) = v else { return state.eval_error("BAD ARGUMENT TYPE", w) };
+    let Value::Word(vw) = v else { return state.eval_error(ErrorKind::BadArgumentType, w) };
     concatlen += vw.str_word.len()
   }
   let mut new_word = state.pool.get_vword(concatlen);
@@ -31,12 +33,12 @@ pub fn cog_concat(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_unconcat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let mut unconcatlen: usize = 0;
   for val in v.value_stack_ref().iter() {
     let Value::Word(word) = val else {
       stack.push(v);
-      return state.eval_error("BAD ARGUMENT TYPE", w)
+      return state.eval_error(ErrorKind::BadArgumentType, w)
     };
     unconcatlen += word.str_word.len()
   }
@@ -56,25 +58,25 @@ pub fn cog_unconcat(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 
 pub fn cog_cut(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(vint) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(vint) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let Some(mut vstr) = stack.pop() else {
     stack.push(vint);
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let string = &mut vstr.value_stack().first_mut().unwrap().vword_mut().str_word;
   let int = match math.math().stoi(&vint.value_stack_ref().first().unwrap().vword_ref().str_word) {
@@ -82,7 +84,7 @@ pub fn cog_cut(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
       state.set_math(math);
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       state.set_math(math);
       i as usize
@@ -97,7 +99,7 @@ pub fn cog_cut(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   if !string.is_char_boundary(int) {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("INVALID CHAR BOUNDARY", w)
+    return state.eval_error(ErrorKind::InvalidCharBoundary, w)
   }
   state.pool.add_val(vint);
   let mut new_word = state.pool.get_vword(string.len() - int);
@@ -111,24 +113,24 @@ pub fn cog_cut(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_ccut(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(vint) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(vint) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let Some(mut vstr) = stack.pop() else {
     stack.push(vint);
-    return state.eval_error("TOO FEW ARGUMENTS", w);
+    return state.eval_error(ErrorKind::TooFewArguments, w);
   };
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w);
+    return state.eval_error(ErrorKind::MathBaseZero, w);
   };
   let string = &mut vstr.value_stack().first_mut().unwrap().vword_mut().str_word;
   let mut iter = string.char_indices();
@@ -138,14 +140,14 @@ pub fn cog_ccut(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     Ok(mut i) => if i < 0 || i.abs() as usize > string.len() {
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       loop {
         if i == 0 { break }
         if iter.next().is_none() {
           state.current().stack.push(vstr);
           state.current().stack.push(vint);
-          return state.eval_error("OUT OF BOUNDS", w)
+          return state.eval_error(ErrorKind::OutOfBounds, w)
         }
         i -= 1
       }
@@ -172,7 +174,7 @@ pub fn cog_ccut(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_substr(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vint2 = stack.pop().unwrap();
   let vint1 = stack.pop().unwrap();
   let mut vstr = stack.pop().unwrap();
@@ -180,19 +182,19 @@ pub fn cog_substr(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
     stack.push(vstr);
     stack.push(vint1);
     stack.push(vint2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint1.value_stack_ref().first().unwrap().is_word() || !vint2.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint1);
     stack.push(vint2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint1);
     state.current().stack.push(vint2);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let string = &mut vstr.value_stack().first_mut().unwrap().vword_mut().str_word;
   let int1 = match math.math().stoi(&vint1.value_stack_ref().first().unwrap().vword_ref().str_word) {
@@ -201,7 +203,7 @@ pub fn cog_substr(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       i as usize
     },
@@ -219,7 +221,7 @@ pub fn cog_substr(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       state.set_math(math);
       i as usize
@@ -236,7 +238,7 @@ pub fn cog_substr(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
     state.current().stack.push(vstr);
     state.current().stack.push(vint1);
     state.current().stack.push(vint2);
-    return state.eval_error("INVALID CHAR BOUNDARY", w)
+    return state.eval_error(ErrorKind::InvalidCharBoundary, w)
   }
   state.pool.add_val(vint1);
   state.pool.add_val(vint2);
@@ -254,7 +256,7 @@ pub fn cog_substr(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let vint2 = stack.pop().unwrap();
   let vint1 = stack.pop().unwrap();
   let mut vstr = stack.pop().unwrap();
@@ -262,19 +264,19 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
     stack.push(vstr);
     stack.push(vint1);
     stack.push(vint2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint1.value_stack_ref().first().unwrap().is_word() || !vint2.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint1);
     stack.push(vint2);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint1);
     state.current().stack.push(vint2);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let string = &mut vstr.value_stack().first_mut().unwrap().vword_mut().str_word;
   let int1 = match math.math().stoi(&vint1.value_stack_ref().first().unwrap().vword_ref().str_word) {
@@ -283,7 +285,7 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     },
     Err(e) => {
       state.set_math(math);
@@ -299,7 +301,7 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     },
     Err(e) => {
       state.set_math(math);
@@ -322,7 +324,7 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let mut iter = string.char_indices();
@@ -331,7 +333,7 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let i1 = match iter.next() {
@@ -343,7 +345,7 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
       state.current().stack.push(vstr);
       state.current().stack.push(vint1);
       state.current().stack.push(vint2);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let i2 = match iter.next() {
@@ -360,15 +362,15 @@ pub fn cog_csubstr(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_len(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v_stack = v.value_stack_ref();
-  if v_stack.len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if v_stack.len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let word_v = v_stack.first().unwrap();
-  if !word_v.is_word() { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if !word_v.is_word() { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let length = word_v.vword_ref().str_word.len();
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
-  if length > isize::MAX as usize { return state.eval_error("OUT OF BOUNDS", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
+  if length > isize::MAX as usize { return state.eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(length as isize, &mut state) {
     Ok(s) => {
       state.set_math(math);
@@ -383,18 +385,18 @@ pub fn cog_len(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 }
 
 pub fn cog_clen(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let v_stack = v.value_stack_ref();
-  if v_stack.len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if v_stack.len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let word_v = v_stack.first().unwrap();
-  if !word_v.is_word() { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  if !word_v.is_word() { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let length = word_v.vword_ref().str_word.chars().count();
-  let Some(math) = state.get_math() else { return state.eval_error("MATH BASE ZERO", w) };
-  if math.math().base() == 0 { return state.with_math(math).eval_error("MATH BASE ZERO", w) }
+  let Some(math) = state.get_math() else { return state.eval_error(ErrorKind::MathBaseZero, w) };
+  if math.math().base() == 0 { return state.with_math(math).eval_error(ErrorKind::MathBaseZero, w) }
   if math.math().base() == 1 && length != 0 {
-    return state.with_math(math).eval_error("MATH BASE ONE", w)
+    return state.with_math(math).eval_error(ErrorKind::MathBaseOne, w)
   }
-  if length > isize::MAX as usize { return state.with_math(math).eval_error("OUT OF BOUNDS", w) }
+  if length > isize::MAX as usize { return state.with_math(math).eval_error(ErrorKind::OutOfBounds, w) }
   match math.math().itos(length as isize, &mut state) {
     Ok(s) => {
       state.set_math(math);
@@ -410,23 +412,23 @@ pub fn cog_clen(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 
 pub fn cog_cat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut vint = stack.pop().unwrap();
   let vstr = stack.pop().unwrap();
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let vstr_stack = vstr.value_stack_ref();
   let string = &vstr_stack.first().unwrap().vword_ref().str_word;
@@ -435,7 +437,7 @@ pub fn cog_cat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
       state.set_math(math);
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       state.set_math(math);
       i as usize
@@ -449,7 +451,7 @@ pub fn cog_cat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   if !string.is_char_boundary(int) {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("INVALID CHAR BOUNDARY", w)
+    return state.eval_error(ErrorKind::InvalidCharBoundary, w)
   }
   let s = &mut vint.value_stack().first_mut().unwrap().vword_mut().str_word;
   s.clear();
@@ -460,22 +462,22 @@ pub fn cog_cat(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_nth(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  if stack.len() < 2 { return state.eval_error("TOO FEW ARGUMENTS", w) }
+  if stack.len() < 2 { return state.eval_error(ErrorKind::TooFewArguments, w) }
   let mut vint = stack.pop().unwrap();
   let vstr = stack.pop().unwrap();
   if vint.value_stack_ref().len() != 1 || vstr.value_stack_ref().len() != 1 {
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if !vint.value_stack_ref().first().unwrap().is_word() || !vstr.value_stack_ref().first().unwrap().is_word() {
     stack.push(vstr);
     stack.push(vint);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Some(math) = state.get_math() else {
     state.current().stack.push(vstr);
     state.current().stack.push(vint);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   let vstr_stack = vstr.value_stack_ref();
   let string = &vstr_stack.first().unwrap().vword_ref().str_word;
@@ -484,7 +486,7 @@ pub fn cog_nth(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
       state.set_math(math);
       state.current().stack.push(vstr);
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     } else {
       state.set_math(math);
       i as usize
@@ -506,7 +508,7 @@ pub fn cog_nth(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
     },
     None => {
       state.current().stack.push(vint);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   state.current().stack.push(vint);
@@ -514,11 +516,11 @@ pub fn cog_nth(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 }
 
 pub fn cog_replace(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 4 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 4 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
-  if i > j { return state.eval_error("OUT OF BOUNDS", w) }
+  if i > j { return state.eval_error(ErrorKind::OutOfBounds, w) }
   let j_val = state.current().stack.pop().unwrap();
   let i_val = state.current().stack.pop().unwrap();
   let (mut v1, v2) = get_2_words!(state, w, {
@@ -533,7 +535,7 @@ pub fn cog_replace(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
     stack.push(v2);
     stack.push(i_val);
     stack.push(j_val);
-    return state.eval_error("OUT OF BOUNDS", w)
+    return state.eval_error(ErrorKind::OutOfBounds, w)
   }
   str1.replace_range(i..j, str2);
   state.pool.add_val(i_val);
@@ -544,11 +546,11 @@ pub fn cog_replace(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_creplace(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 4 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 4 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
-  if i > j { return state.eval_error("OUT OF BOUNDS", w) }
+  if i > j { return state.eval_error(ErrorKind::OutOfBounds, w) }
   let j_val = state.current().stack.pop().unwrap();
   let i_val = state.current().stack.pop().unwrap();
   let (mut v1, v2) = get_2_words!(state, w, {
@@ -565,7 +567,7 @@ pub fn cog_creplace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
       stack.push(v2);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let int1 = match iter.next() {
@@ -578,7 +580,7 @@ pub fn cog_creplace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
       stack.push(v2);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let int2 = match iter.next() {
@@ -594,7 +596,7 @@ pub fn cog_creplace(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
 }
 
 pub fn cog_slice(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
@@ -610,7 +612,7 @@ pub fn cog_slice(mut state: CognitionState, w: Option<&Value>) -> CognitionState
     stack.push(v1);
     stack.push(i_val);
     stack.push(j_val);
-    return state.eval_error("OUT OF BOUNDS", w)
+    return state.eval_error(ErrorKind::OutOfBounds, w)
   }
   if i >= j {
     let vw = state.pool.get_vword(0);
@@ -630,7 +632,7 @@ pub fn cog_slice(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_cslice(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  if state.current_ref().stack.len() < 3 { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  if state.current_ref().stack.len() < 3 { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let (i, j) = get_2_unsigned!(state, w, isize, ACTIVE);
   let i = i as usize;
   let j = j as usize;
@@ -649,7 +651,7 @@ pub fn cog_cslice(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       stack.push(v1);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   let int1 = match iter.next() {
@@ -661,7 +663,7 @@ pub fn cog_cslice(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
       stack.push(v1);
       stack.push(i_val);
       stack.push(j_val);
-      return state.eval_error("OUT OF BOUNDS", w)
+      return state.eval_error(ErrorKind::OutOfBounds, w)
     }
   }
   if i >= j {
@@ -687,14 +689,14 @@ pub fn cog_cslice(mut state: CognitionState, w: Option<&Value>) -> CognitionStat
 
 pub fn cog_reverse(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let Value::Word(vw) = v.value_stack_ref().first().unwrap() else {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut new_vword = state.pool.get_vword(vw.str_word.len());
   for c in vw.str_word.chars().rev() {
@@ -707,7 +709,7 @@ pub fn cog_reverse(mut state: CognitionState, w: Option<&Value>) -> CognitionSta
 }
 
 pub fn cog_word_questionmark(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let Some(v) = state.current().stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v) = state.current().stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let mut vword = state.pool.get_vword(1);
   if v.value_stack_ref().len() == 1 {
     if v.value_stack_ref().first().unwrap().is_word() {
@@ -719,25 +721,25 @@ pub fn cog_word_questionmark(mut state: CognitionState, w: Option<&Value>) -> Co
 
 pub fn cog_ctoi(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(mut v) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   if v.value_stack_ref().len() != 1 {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   let char_v = v.value_stack().first_mut().unwrap();
   let Value::Word(vword_char) = char_v else {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let mut chars = vword_char.str_word.chars();
   let c = chars.next();
   if c.is_none() || chars.next().is_some() {
     stack.push(v);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   };
   let Some(math) = state.get_math() else {
     state.current().stack.push(v);
-    return state.eval_error("MATH BASE ZERO", w)
+    return state.eval_error(ErrorKind::MathBaseZero, w)
   };
   match math.math().itos(c.unwrap() as isize, &mut state) {
     Ok(mut s) => {
@@ -756,9 +758,9 @@ pub fn cog_ctoi(mut state: CognitionState, w: Option<&Value>) -> CognitionState
 }
 
 pub fn cog_itoc(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
-  let i = get_unsigned!(state, w, u32, ACTIVE, "INVALID CHAR VALUE");
+  let i = get_unsigned!(state, w, u32, ACTIVE, InvalidCharValue);
   let Some(c) = char::from_u32(i) else {
-    return state.eval_error("INVALID CHAR VALUE", w)
+    return state.eval_error(ErrorKind::InvalidCharValue, w)
   };
   let vword = state.current().stack.last_mut().unwrap().value_stack().first_mut().unwrap().vword_mut();
   vword.str_word.clear();
@@ -766,6 +768,72 @@ pub fn cog_itoc(mut state: CognitionState, w: Option<&Value>) -> CognitionState
   state
 }
 
+/// Locale-independent approximation of Unicode collation: strings compare
+/// case-insensitively first, and ties are broken by code point order
+pub fn collate(a: &str, b: &str) -> Ordering {
+  a.chars().flat_map(char::to_lowercase).cmp(b.chars().flat_map(char::to_lowercase)).then_with(|| a.cmp(b))
+}
+
+// byte order of UTF-8 strings is also the order of their chars
+fn byte_order(a: &str, b: &str) -> Ordering { a.cmp(b) }
+
+macro_rules! string_comparison {
+  ($name:tt,$cmp:ident,$o:tt,$op:expr) => {
+    pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+      let (mut v1, v2) = get_2_words!(state, w);
+      let str_word = &mut v1.value_stack()[0].vword_mut().str_word;
+      let $o = $cmp(str_word, &v2.value_stack_ref()[0].vword_ref().str_word);
+      str_word.clear();
+      if $op { str_word.push('t') }
+      state.pool.add_val(v2);
+      state.current().stack.push(v1);
+      state
+    }
+  }
+}
+
+string_comparison!{ cog_str_lthan, byte_order, o, o == Ordering::Less }
+string_comparison!{ cog_str_leq, byte_order, o, o != Ordering::Greater }
+string_comparison!{ cog_str_geq, byte_order, o, o != Ordering::Less }
+string_comparison!{ cog_str_gthan, byte_order, o, o == Ordering::Greater }
+string_comparison!{ cog_ustr_lthan, collate, o, o == Ordering::Less }
+string_comparison!{ cog_ustr_leq, collate, o, o != Ordering::Greater }
+string_comparison!{ cog_ustr_geq, collate, o, o != Ordering::Less }
+string_comparison!{ cog_ustr_gthan, collate, o, o == Ordering::Greater }
+
+// pushes -1, 0 or 1 in the current math
+macro_rules! three_way_comparison {
+  ($name:tt,$cmp:ident) => {
+    pub fn $name(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
+      let (mut v1, v2) = get_2_words!(state, w);
+      let math = get_math!(state, w, {
+        state.current().stack.push(v1);
+        state.current().stack.push(v2);
+      });
+      let o = $cmp(&v1.value_stack_ref()[0].vword_ref().str_word, &v2.value_stack_ref()[0].vword_ref().str_word);
+      let result = math.math().itos(o as isize, &mut state);
+      state.set_math(math);
+      match result {
+        Ok(mut s) => {
+          std::mem::swap(&mut v1.value_stack()[0].vword_mut().str_word, &mut s);
+          state.pool.add_string(s);
+          state.pool.add_val(v2);
+          state.current().stack.push(v1);
+          state
+        },
+        Err(e) => {
+          state.current().stack.push(v1);
+          state.current().stack.push(v2);
+          state.eval_error(e, w)
+        }
+      }
+    }
+  }
+}
+
+three_way_comparison!{ cog_compare, byte_order }
+three_way_comparison!{ cog_ucompare, collate }
+
 pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "concat", cog_concat);
   add_builtin!(state, "unconcat", cog_unconcat);
@@ -785,4 +853,14 @@ pub fn add_builtins(state: &mut CognitionState) {
   add_builtin!(state, "word?", cog_word_questionmark);
   add_builtin!(state, "ctoi", cog_ctoi);
   add_builtin!(state, "itoc", cog_itoc);
+  add_builtin!(state, "str<", cog_str_lthan);
+  add_builtin!(state, "str<=", cog_str_leq);
+  add_builtin!(state, "str>=", cog_str_geq);
+  add_builtin!(state, "str>", cog_str_gthan);
+  add_builtin!(state, "ustr<", cog_ustr_lthan);
+  add_builtin!(state, "ustr<=", cog_ustr_leq);
+  add_builtin!(state, "ustr>=", cog_ustr_geq);
+  add_builtin!(state, "ustr>", cog_ustr_gthan);
+  add_builtin!(state, "compare", cog_compare);
+  add_builtin!(state, "ucompare", cog_ucompare);
 }
@@ -2,21 +2,21 @@ use crate::*;
 
 pub fn cog_def(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let stack = &mut state.current().stack;
-  let Some(v_body) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(v_body) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let Some(v_name) = stack.pop() else {
     stack.push(v_body);
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   let v_name_stack = v_name.value_stack_ref();
   if v_name_stack.len() != 1 {
     stack.push(v_name);
     stack.push(v_body);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   }
   let Value::Word(name_vword) = v_name_stack.first().unwrap() else {
     stack.push(v_name);
     stack.push(v_body);
-    return state.eval_error("BAD ARGUMENT TYPE", w);
+    return state.eval_error(ErrorKind::BadArgumentType, w);
   };
   let name = state.string_copy(&name_vword.str_word);
   state.pool.add_val(v_name);
@@ -27,9 +27,9 @@ pub fn cog_def(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
 
 pub fn cog_undef(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let v = get_word!(state, w);
-  let Some(wt) = &mut state.current().word_table else { return state.eval_error("UNDEFINED WORD", w) };
+  let Some(wt) = &mut state.current().word_table else { return state.eval_error(ErrorKind::UndefinedWord, w) };
   let Some(result) = wt.remove(&v.value_stack_ref().first().unwrap().vword_ref().str_word) else {
-    return state.eval_error("UNDEFINED WORD", w)
+    return state.eval_error(ErrorKind::UndefinedWord, w)
   };
   state.pool.add_word_def(result);
   state.pool.add_val(v);
@@ -53,13 +53,13 @@ pub fn cog_wordlist(mut state: CognitionState, _: Option<&Value>) -> CognitionSt
 
 pub fn cog_unglue(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
-  let Some(v) = cur.stack.last() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
-  if v.value_stack_ref().len() != 1 { return state.eval_error("BAD ARGUMENT TYPE", w) }
+  let Some(v) = cur.stack.last() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
+  if v.value_stack_ref().len() != 1 { return state.eval_error(ErrorKind::BadArgumentType, w) }
   let word_v = v.value_stack_ref().first().unwrap();
-  if !word_v.is_word() { return state.eval_error("BAD ARGUMENT TYPE", w) }
-  if cur.word_table.is_none() { return state.eval_error("UNDEFINED WORD", w) }
+  if !word_v.is_word() { return state.eval_error(ErrorKind::BadArgumentType, w) }
+  if cur.word_table.is_none() { return state.eval_error(ErrorKind::UndefinedWord, w) }
   let Some(wd) = cur.word_table.as_ref().unwrap().get(&word_v.vword_ref().str_word) else {
-    return state.eval_error("UNDEFINED WORD", w)
+    return state.eval_error(ErrorKind::UndefinedWord, w)
   };
   let new_wd = wd.clone();
   let v = cur.stack.pop().unwrap();
@@ -87,16 +87,16 @@ pub fn cog_def_questionmark(mut state: CognitionState, w: Option<&Value>) -> Cog
 pub fn cog_bequeath(mut state: CognitionState, w: Option<&Value>) -> CognitionState {
   let cur = state.current();
   let stack = &mut cur.stack;
-  let Some(mut v_words) = stack.pop() else { return state.eval_error("TOO FEW ARGUMENTS", w) };
+  let Some(mut v_words) = stack.pop() else { return state.eval_error(ErrorKind::TooFewArguments, w) };
   let Some(mut v_child) = stack.pop() else {
     stack.push(v_words);
-    return state.eval_error("TOO FEW ARGUMENTS", w)
+    return state.eval_error(ErrorKind::TooFewArguments, w)
   };
   let v_words_stack = v_words.value_stack();
   if !v_child.is_stack() || v_words_stack.iter().any(|x| !x.is_word()) {
     stack.push(v_child);
     stack.push(v_words);
-    return state.eval_error("BAD ARGUMENT TYPE", w)
+    return state.eval_error(ErrorKind::BadArgumentType, w)
   }
   if v_words_stack.len() == 0 {
     state.pool.add_val(v_words);
@@ -130,7 +130,7 @@ pub fn cog_bequeath(mut state: CognitionState, w: Option<&Value>) -> CognitionSt
   }
   state.current().stack.push(v_child);
   state.current().stack.push(v_words);
-  state.eval_error("UNDEFINED WORD", w)
+  state.eval_error(ErrorKind::UndefinedWord, w)
 }
 
 pub fn add_builtins(state: &mut CognitionState) {
@@ -0,0 +1,59 @@
+use crate::*;
+
+macro_rules! capabilities {
+  ($($cap:ident => $name:literal;)*) => {
+    /// Something a script can do outside of its own state. Builtins and
+    /// foreign library words that need one check CognitionState::capabilities
+    /// and raise PERMISSION DENIED without it.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum Capability {
+      $($cap,)*
+    }
+
+    impl Capability {
+      pub const ALL: &'static [Capability] = &[$(Self::$cap,)*];
+
+      pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+          $($name => Some(Self::$cap),)*
+          _ => None
+        }
+      }
+      /// The name used by crank --allow
+      pub fn name(&self) -> &'static str {
+        match self {
+          $(Self::$cap => $name,)*
+        }
+      }
+    }
+  }
+}
+
+capabilities!{
+  FsRead => "fs-read";
+  FsWrite => "fs-write";
+  Fllib => "fllib";
+  Process => "process";
+  Env => "env";
+}
+
+/// The set of capabilities a state has. Everything is allowed by default;
+/// embedders running untrusted code start from Capabilities::none() and
+/// allow what the code needs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities(u32);
+
+impl Default for Capabilities {
+  fn default() -> Self { Self::all() }
+}
+
+impl Capabilities {
+  pub fn all() -> Self {
+    Capability::ALL.iter().fold(Self::none(), |caps, cap| caps.allow(*cap))
+  }
+  pub fn none() -> Self { Self(0) }
+
+  pub fn has(&self, cap: Capability) -> bool { self.0 & (1 << cap as u32) != 0 }
+  pub fn allow(self, cap: Capability) -> Self { Self(self.0 | 1 << cap as u32) }
+  pub fn deny(self, cap: Capability) -> Self { Self(self.0 & !(1 << cap as u32)) }
+}
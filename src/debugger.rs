@@ -0,0 +1,187 @@
+use crate::*;
+
+use std::cell::RefCell;
+use std::io::{stdin, stdout, Write};
+use std::rc::Rc;
+
+enum Mode {
+  Step,
+  Next(usize),
+  Continue,
+  Detached
+}
+
+/// What the debugger breaks on, and the reason for the latest break hit
+#[derive(Default)]
+struct Breaks {
+  words: HashSet<String>,
+  errors: bool,
+  cranks: bool,
+  hit: Option<String>,
+}
+
+// Installed as the tracer, recording breakpoints hit during a step for the
+// debugger to stop at once the step is done
+struct BreakTracer(Rc<RefCell<Breaks>>);
+
+impl BreakTracer {
+  fn hit(&self, reason: impl FnOnce() -> String) {
+    let mut breaks = self.0.borrow_mut();
+    if breaks.hit.is_none() { breaks.hit = Some(reason()) }
+  }
+}
+
+impl Tracer for BreakTracer {
+  fn lookup(&mut self, _state: &CognitionState, word: &Value, _family: Option<usize>) {
+    let word = &word.vword_ref().str_word;
+    if self.0.borrow().words.contains(word) { self.hit(|| format!("breakpoint '{word}'")) }
+  }
+  fn crank(&mut self, _state: &CognitionState, _wd: &Value) {
+    if self.0.borrow().cranks { self.hit(|| String::from("crank")) }
+  }
+  fn error(&mut self, _state: &CognitionState, verror: &VError) {
+    if self.0.borrow().errors {
+      let word = verror.str_word.as_deref().unwrap_or("(none)");
+      self.hit(|| format!("error {word}: {}", verror.error))
+    }
+  }
+}
+
+/// Interactive debugger. It evaluates each value read at the top level with
+/// an Evaluator, one step at a time, and stops before the first value and
+/// afterwards wherever a breakpoint or a step command says to. Quotes that
+/// builtins such as if or map evaluate run within a single step, though
+/// breakpoints hit inside them still stop after it.
+pub struct Debugger {
+  breaks: Rc<RefCell<Breaks>>,
+  mode: Mode,
+}
+
+const HELP: &str = "\
+s, step        evaluate one value, stepping into definitions
+n, next        evaluate one value, stepping over definitions
+c, continue    run until the next breakpoint
+b WORD         break when WORD is looked up
+d WORD         delete the breakpoint on WORD
+e              toggle breaking when an error is pushed
+k              toggle breaking when a crank fires
+l, breaks      list breakpoints
+p, stack       print the current stack
+E              print the current error stack
+w, where       print the parser location, metastack depth and evaluation depth
+f, family      print the family chain, innermost first
+q, quit        stop debugging and run to the end
+h, help        print this message";
+
+impl Debugger {
+  /// Installs the debugger's tracer on the state
+  pub fn new(state: &mut CognitionState) -> Self {
+    let breaks = Rc::new(RefCell::new(Breaks::default()));
+    state.tracer = Some(Box::new(BreakTracer(breaks.clone())));
+    Self{ breaks, mode: Mode::Step }
+  }
+
+  /// Counterpart to CognitionState::eval for a value read at the top level
+  pub fn eval(&mut self, mut state: CognitionState, v: Value) -> CognitionState {
+    if let Mode::Detached = self.mode { return state.eval(v, None) }
+    let Some(mut evaluator) = Evaluator::from_value(&mut state, v) else { return self.stop_on_hit(state) };
+    while !evaluator.is_done() {
+      let hit = self.breaks.borrow_mut().hit.take();
+      let stop = match (&hit, &self.mode) {
+        (_, Mode::Detached) => return evaluator.finish(state),
+        (Some(_), _) | (None, Mode::Step) => true,
+        (None, Mode::Next(depth)) => evaluator.depth() <= *depth,
+        (None, Mode::Continue) => false
+      };
+      if stop { self.prompt(&state, Some(&evaluator), hit.as_deref()) }
+      if let Mode::Detached = self.mode { return evaluator.finish(state) }
+      state = evaluator.step(state);
+    }
+    self.stop_on_hit(state)
+  }
+
+  // stops for a breakpoint hit after the last step of an evaluation
+  fn stop_on_hit(&mut self, state: CognitionState) -> CognitionState {
+    let hit = self.breaks.borrow_mut().hit.take();
+    if hit.is_some() && !matches!(self.mode, Mode::Detached) {
+      self.prompt(&state, None, hit.as_deref())
+    }
+    state
+  }
+
+  fn prompt(&mut self, state: &CognitionState, evaluator: Option<&Evaluator>, hit: Option<&str>) {
+    if let Some(hit) = hit { println!("{hit}") }
+    if let Some(v) = evaluator.and_then(|e| e.peek()) {
+      print!("value ");
+      v.print("\n");
+    }
+    Self::location(state, evaluator);
+    loop {
+      print!("(debug) ");
+      let _ = stdout().flush();
+      let mut line = String::new();
+      match stdin().read_line(&mut line) {
+        Ok(0) | Err(_) => {
+          self.mode = Mode::Detached;
+          return
+        },
+        Ok(_) => {}
+      }
+      let mut args = line.split_whitespace();
+      let Some(command) = args.next() else { continue };
+      let mut breaks = self.breaks.borrow_mut();
+      match command {
+        "s" | "step" => { self.mode = Mode::Step; return },
+        "n" | "next" => { self.mode = Mode::Next(evaluator.map_or(0, |e| e.depth())); return },
+        "c" | "continue" => { self.mode = Mode::Continue; return },
+        "q" | "quit" => { self.mode = Mode::Detached; return },
+        "b" => for word in args { breaks.words.insert(word.to_string()); },
+        "d" => for word in args { breaks.words.remove(word); },
+        "e" => {
+          breaks.errors = !breaks.errors;
+          println!("break on errors: {}", if breaks.errors { "on" } else { "off" })
+        },
+        "k" => {
+          breaks.cranks = !breaks.cranks;
+          println!("break on cranks: {}", if breaks.cranks { "on" } else { "off" })
+        },
+        "l" | "breaks" => {
+          let mut words: Vec<&String> = breaks.words.iter().collect();
+          words.sort();
+          for word in words { println!("'{word}'") }
+          if breaks.errors { println!("(errors)") }
+          if breaks.cranks { println!("(cranks)") }
+        },
+        "p" | "stack" => for v in state.current_ref().stack.iter() { v.print("\n") },
+        "E" => if let Some(ref errors) = state.current_ref().err_stack {
+          for v in errors.iter() { v.print("\n") }
+        },
+        "w" | "where" => Self::location(state, evaluator),
+        "f" | "family" => Self::family(state),
+        "h" | "help" => println!("{HELP}"),
+        _ => println!("unknown command: {command} (try 'help')")
+      }
+    }
+  }
+
+  fn location(state: &CognitionState, evaluator: Option<&Evaluator>) {
+    if let Some(ref parser) = state.parser {
+      let filename = parser.filename_ref().map_or("-", |s| s.as_str());
+      print!("at {filename}:{}:{}, ", parser.line(), parser.column());
+    }
+    println!("metastack depth {}, evaluation depth {}", state.stack.len(), evaluator.map_or(0, |e| e.depth()));
+  }
+
+  fn family(state: &CognitionState) {
+    for (i, wd) in state.family.iter().rev().enumerate() {
+      let mut words: Vec<&String> = match wd.vstack_ref().container.word_table {
+        Some(ref wt) => wt.keys().collect(),
+        None => Vec::new()
+      };
+      words.sort();
+      print!("{i}:");
+      for word in words { print!(" '{word}'") }
+      println!();
+    }
+  }
+}
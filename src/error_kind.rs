@@ -0,0 +1,98 @@
+use crate::*;
+
+macro_rules! error_kinds {
+  ($($kind:ident => $name:literal, $message:literal $(| $alias:literal)*;)*) => {
+    /// What went wrong in a VError. Builtins raise errors by kind, with its
+    /// message; errors made from a message alone, such as ones thrown by
+    /// scripts, look the kind up from it, and messages nothing here knows
+    /// about are ErrorKind::Other.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+    pub enum ErrorKind {
+      $($kind,)*
+      #[default]
+      Other
+    }
+
+    impl ErrorKind {
+      pub fn from_message(message: &str) -> Self {
+        match message {
+          $($message $(| $alias)* => Self::$kind,)*
+          _ => Self::Other
+        }
+      }
+      /// The message builtins raise this kind of error with
+      pub fn message(&self) -> Option<&'static str> {
+        match self {
+          $(Self::$kind => Some($message),)*
+          Self::Other => None
+        }
+      }
+      /// The name scripts see, as pushed by ekind
+      pub fn name(&self) -> &'static str {
+        match self {
+          $(Self::$kind => $name,)*
+          Self::Other => "other"
+        }
+      }
+    }
+
+    impl std::fmt::Display for ErrorKind {
+      fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.message().unwrap_or(self.name()))
+      }
+    }
+  }
+}
+
+error_kinds!{
+  TooFewArguments => "too-few-arguments", "TOO FEW ARGUMENTS";
+  BadArgumentType => "bad-argument-type", "BAD ARGUMENT TYPE";
+  OutOfBounds => "out-of-bounds", "OUT OF BOUNDS";
+  EmptyStack => "empty-stack", "EMPTY STACK";
+  NoErrors => "no-errors", "NO ERRORS";
+  UndefinedWord => "undefined-word", "UNDEFINED WORD";
+  BadComparator => "bad-comparator", "BAD COMPARATOR";
+  CrankTooDeep => "crank-too-deep", "CRANK TOO DEEP";
+  FuelExhausted => "fuel-exhausted", "FUEL EXHAUSTED";
+  RecursionTooDeep => "recursion-too-deep", "RECURSION TOO DEEP";
+  StackTooLarge => "stack-too-large", "STACK TOO LARGE";
+  Interrupted => "interrupted", "INTERRUPTED";
+  NotProfiling => "not-profiling", "NOT PROFILING";
+  PermissionDenied => "permission-denied", "PERMISSION DENIED";
+
+  MathBaseZero => "math-base-zero", "MATH BASE ZERO";
+  MathBaseOne => "math-base-one", "MATH BASE ONE";
+  MathBaseNonzero => "math-base-nonzero", "MATH BASE NONZERO";
+  MathDigitsUninitialized => "math-digits-uninitialized", "MATH DIGITS UNINITIALIZED";
+  MathDelimUninitialized => "math-delim-uninitialized", "MATH DELIM UNINITIALIZED";
+  MathMetaDelimUninitialized => "math-meta-delim-uninitialized", "MATH META DELIM UNINITIALIZED" | "MATH METADELIM UNINITIALIZED";
+  MathRadixUninitialized => "math-radix-uninitialized", "MATH RADIX UNINITIALIZED";
+  MathMetaRadixUninitialized => "math-meta-radix-uninitialized", "MATH METARADIX UNINITIALIZED";
+  MathNegcUninitialized => "math-negc-uninitialized", "MATH NEGC UNINITIALIZED";
+  MathDimensionMismatch => "math-dimension-mismatch", "MATH DIMENSION MISMATCH";
+  MathDomainError => "math-domain-error", "MATH DOMAIN ERROR";
+  UndefinedMathOp => "undefined-math-op", "UNDEFINED MATH OP";
+  UndefinedOperand => "undefined-operand", "UNDEFINED OPERAND";
+  DivisionByZero => "division-by-zero", "DIVISION BY ZERO";
+  InvalidNumberString => "invalid-number-string", "INVALID NUMBER STRING";
+
+  InvalidString => "invalid-string", "INVALID STRING";
+  InvalidCharBoundary => "invalid-char-boundary", "INVALID CHAR BOUNDARY";
+  InvalidCharValue => "invalid-char-value", "INVALID CHAR VALUE";
+
+  InvalidFilename => "invalid-filename", "INVALID FILENAME";
+  InvalidFile => "invalid-file", "INVALID FILE";
+  InsufficientFilePermissions => "insufficient-file-permissions", "INSUFFICIENT FILE PERMISSIONS";
+  NotSeekable => "not-seekable", "NOT SEEKABLE";
+  SeekFailed => "seek-failed", "SEEK FAILED";
+  StreamPositionFailed => "stream-position-failed", "STREAM POSITION FAILED";
+  ReadFailed => "read-failed", "READ FAILED";
+  NoParser => "no-parser", "NO PARSER";
+  NoFilename => "no-filename", "NO FILENAME";
+
+  InvalidFllib => "invalid-fllib", "INVALID FLLIB";
+  FllibExists => "fllib-exists", "FLLIB EXISTS";
+  InvalidSerdeFormat => "invalid-serde-format", "INVALID SERDE FORMAT";
+  SerializationFailed => "serialization-failed", "SERIALIZATION FAILED";
+  DeserializationFailed => "deserialization-failed", "DESERIALIZATION FAILED";
+}
@@ -10,10 +10,18 @@ pub mod pool;
 pub mod math;
 pub mod builtins;
 pub mod serde;
+pub mod debugger;
+pub mod profiler;
+pub mod error_kind;
+pub mod capabilities;
+pub mod snapshot;
 
 pub use crate::macros::*;
 pub use crate::math::*;
 pub use crate::pool::*;
+pub use crate::error_kind::*;
+pub use crate::capabilities::*;
+pub use crate::snapshot::*;
 
 pub use crate::serde::*;
 pub use ::serde::{Serialize, Deserialize, Serializer, Deserializer};
@@ -28,12 +36,13 @@ use std::default::Default;
 use std::fmt::Display;
 use std::io::{stdout, IsTerminal, Write};
 use std::sync::Arc;
+use std::sync::atomic::{self, AtomicBool};
 
 pub type CognitionFunction = fn(CognitionState, Option<&Value>) -> CognitionState;
 pub type AddWordsFn = unsafe extern fn(&mut CognitionState, &Library);
 
 pub type DeserializeFn<T> = fn(&mut dyn erased_serde::Deserializer, &mut CognitionState) -> erased_serde::Result<Box<T>>;
-pub type CognitionDeserializeResult = Result<CognitionState, (CognitionState, Box<dyn Display>)>;
+pub type CognitionDeserializeResult = Result<CognitionState, (CognitionState, ErrorKind, Box<dyn Display>)>;
 pub type CogStateDeserializeFn = fn(&str, bool, CognitionState) -> CognitionDeserializeResult;
 pub type CogLibsDeserializeFn = fn(&str, CognitionState) -> CognitionDeserializeResult;
 pub type CogStateSerializeFn = fn(&CognitionState, &mut dyn Write) -> Result<(), Box<dyn Display>>;
@@ -227,6 +236,9 @@ pub struct Container {
   pub cranks: Option<Cranks>,
   pub math: Option<Math>,
   pub word_table: Option<WordTable>,
+  pub on_error: Option<WordDef>, // evaluated with each error raised while this container is current
+  pub errors_pending: usize, // raised errors waiting for on_error
+  pub handling_errors: bool, // on_error is being evaluated, so errors it raises here are left
   pub faliases: Option<Faliases>,
   pub delims: Option<String>,
   pub ignored: Option<String>,
@@ -244,6 +256,9 @@ impl Default for Container {
       cranks: None,
       math: None,
       word_table: None,
+      on_error: None,
+      errors_pending: 0,
+      handling_errors: false,
       faliases: None,
       delims: None,
       ignored: None,
@@ -298,6 +313,8 @@ impl Container {
 #[derive(Serialize, Deserialize)]
 pub struct VWord {
   pub str_word: String,
+  #[serde(default, skip_serializing_if = "Option::is_none")]
+  pub loc: Option<Arc<WordLoc>>, // where the parser read this word, if CognitionState::locate_words
 }
 pub struct VStack {
   pub container: Container,
@@ -305,6 +322,13 @@ pub struct VStack {
 pub struct VMacro {
   pub macro_stack: Stack,
 }
+/// Position of a parsed word in its source, shared between copies of the word
+#[derive(Serialize, Deserialize)]
+pub struct WordLoc {
+  pub filename: Option<Arc<str>>,
+  pub line: usize,
+  pub column: usize,
+}
 #[derive(Serialize, Deserialize)]
 pub struct VErrorLoc {
   pub filename: String,
@@ -314,8 +338,12 @@ pub struct VErrorLoc {
 #[derive(Serialize, Deserialize)]
 pub struct VError {
   pub error: String,
+  #[serde(default)]
+  pub kind: ErrorKind,
   pub str_word: Option<String>,
-  pub loc: Option<VErrorLoc>
+  pub loc: Option<VErrorLoc>,
+  #[serde(default)]
+  pub backtrace: Vec<String>, // state.calls when the error was raised, innermost last
 }
 pub struct VFLLib {
   pub fllib: CognitionFunction,
@@ -330,11 +358,11 @@ pub struct VCustom {
 
 impl VWord {
   pub fn with_string(str_word: String) -> VWord {
-    VWord{ str_word }
+    VWord{ str_word, loc: None }
   }
   pub fn with_capacity(capacity: usize) -> VWord {
     let str_word = String::with_capacity(capacity);
-    VWord{ str_word }
+    VWord{ str_word, loc: None }
   }
 }
 impl VStack {
@@ -358,10 +386,10 @@ impl VMacro {
 }
 impl VError {
   pub fn with_strings(error: String, str_word: String) -> VError {
-    VError{ error, str_word: Some(str_word), loc: None }
+    VError{ kind: ErrorKind::from_message(&error), error, str_word: Some(str_word), loc: None, backtrace: Vec::new() }
   }
   pub fn with_error(error: String) -> VError {
-    VError{ error, str_word: None, loc: None }
+    VError{ kind: ErrorKind::from_message(&error), error, str_word: None, loc: None, backtrace: Vec::new() }
   }
   pub fn with_capacity(capacity: usize) -> VError {
     Self::with_error(String::with_capacity(capacity))
@@ -462,6 +490,12 @@ impl Value {
         } else {
           fwrite_check!(f, verror.error.as_bytes());
         }
+        for word in verror.backtrace.iter().rev() {
+          fwrite_check!(f, b" <- '");
+          if term { word.fprint_pretty(f); }
+          else { fwrite_check!(f, word.as_bytes()); }
+          fwrite_check!(f, b"'");
+        }
       },
       Self::FLLib(vfllib) => {
         match &vfllib.str_word {
@@ -549,6 +583,8 @@ pub struct ParserLoc {
 pub struct Parser {
   source: Option<String>,
   filename: Option<String>,
+  #[serde(skip)]
+  shared_filename: Option<Arc<str>>, // filename as held by word locations
   i: usize,
   c: Option<char>,
   line: usize,
@@ -561,32 +597,36 @@ impl Parser {
     Self::with_loc(source, ParserLoc{ filename, pos: None })
   }
   pub fn with_loc(source: Option<String>, loc: ParserLoc) -> Parser {
-    if source.is_none() { return Parser{ source: None, filename: None, i: 0, c: None, line: 1, column: 0, parse_delim: false } }
+    if source.is_none() { return Parser{ source: None, filename: None, shared_filename: None, i: 0, c: None, line: 1, column: 1, parse_delim: false } }
     let c = match source.as_ref().unwrap().get(..) { Some(st) => st.chars().next(), None => None };
-    let (line, column) = if let Some(pos) = loc.pos { (pos.0, pos.1) } else { (1, 0) };
-    Parser{ source, filename: loc.filename, i: 0, c, line, column, parse_delim: false }
+    let (line, column) = if let Some(pos) = loc.pos { (pos.0, pos.1) } else { (1, 1) };
+    Parser{ source, filename: loc.filename, shared_filename: None, i: 0, c, line, column, parse_delim: false }
   }
   pub fn next(&mut self) {
-    if self.c.is_none() || self.source.is_none() { return }
-    self.i += self.c.unwrap().len_utf8();
+    let Some(c) = self.c else { return };
+    if self.source.is_none() { return }
+    self.i += c.len_utf8();
     self.c = match self.source.as_ref().unwrap().get(self.i..) {
       Some(st) => st.chars().next(),
       None => None
     };
-    self.column += 1;
-    if self.c == Some('\n') {
+    // a newline ends its line, and columns count from one
+    if c == '\n' {
       self.line += 1;
-      self.column = 0;
+      self.column = 1;
+    } else {
+      self.column += 1;
     }
   }
 
   pub fn reset(&mut self, source: String, filename: Option<String>) {
-    self.column = 0;
+    self.column = 1;
     self.line = 1;
     self.c = match source.get(..) { Some(st) => st.chars().next(), None => None };
     self.i = 0;
     self.source = Some(source);
     self.filename = filename;
+    self.shared_filename = None;
     self.parse_delim = false;
   }
 
@@ -594,8 +634,10 @@ impl Parser {
     self.source.take()
   }
   pub fn filename(&mut self) -> Option<String> {
+    self.shared_filename = None;
     self.filename.take()
   }
+  pub fn filename_ref(&self) -> Option<&String> { self.filename.as_ref() }
   pub fn line(&self) -> usize { self.line }
   pub fn column(&self) -> usize { self.column }
 
@@ -609,9 +651,17 @@ impl Parser {
     skipped
   }
 
+  fn word_loc(&mut self) -> Arc<WordLoc> {
+    if self.shared_filename.is_none() {
+      self.shared_filename = self.filename.as_deref().map(Arc::from)
+    }
+    Arc::new(WordLoc{ filename: self.shared_filename.clone(), line: self.line, column: self.column })
+  }
+
   fn parse_word(&mut self, skipped: bool, state: &mut CognitionState) -> Option<Value> {
     let Some(c) = self.c else { return None };
     let mut v = state.pool.get_vword(DEFAULT_STRING_LENGTH);
+    if state.locate_words { v.loc = Some(self.word_loc()) }
     if !skipped && !self.parse_delim {
       v.str_word.push(c);
       self.next();
@@ -667,6 +717,32 @@ impl CognitionControl {
   pub fn is_none(&self) -> bool { if let Self::None = self { true } else { false } }
 }
 
+/// Optional bounds on evaluation. Exceeding one raises an error and unwinds
+/// like exit; see CognitionState::check_limits.
+#[derive(Clone, Copy, Default)]
+pub struct Limits {
+  pub fuel: Option<usize>, // evaluation steps left
+  pub depth: Option<usize>, // maximum evalstack nesting
+  pub stack: Option<usize>, // maximum values across the metastack, not counting nested ones
+}
+
+/// Hooks into evaluation, each called with the state as it is at that point.
+/// Install one as CognitionState::tracer.
+pub trait Tracer {
+  /// A value is about to be evaluated
+  fn value(&mut self, _state: &CognitionState, _v: &Value) {}
+  /// A word resolved to a definition in the family container this many
+  /// containers out from the innermost, or in the current container if None
+  fn lookup(&mut self, _state: &CognitionState, _word: &Value, _family: Option<usize>) {}
+  /// A definition starts evaluating, possibly in place of a finished one
+  fn enter(&mut self, _state: &CognitionState, _wd: &Value, _callword: Option<&Value>, _tail: bool) {}
+  fn exit(&mut self, _state: &CognitionState, _callword: Option<&Value>) {}
+  fn fllib(&mut self, _state: &CognitionState, _v: &Value) {}
+  fn evalf(&mut self, _state: &CognitionState, _wd: &Value) {}
+  fn crank(&mut self, _state: &CognitionState, _wd: &Value) {}
+  fn error(&mut self, _state: &CognitionState, _verror: &VError) {}
+}
+
 pub struct CognitionState {
   pub chroots: Vec<Stack>, // meta metastack
   pub stack: Stack, // metastack
@@ -675,6 +751,19 @@ pub struct CognitionState {
   pub control: CognitionControl,
   pub exited: bool,
   pub exit_code: Option<String>,
+  pub limits: Limits,
+  pub capabilities: Capabilities,
+  pub depth: usize, // evalstack nesting
+  pub limit_hit: bool,
+  pub interrupt: Arc<AtomicBool>, // set from a signal handler or another thread to cancel evaluation
+  pub interrupted: bool,
+  pub trying: usize, // try bodies being evaluated, which ethrow unwinds to
+  pub thrown: bool,
+  pub pending_errors: usize, // sum of the containers' errors_pending, checked after each evaluation
+  pub tracer: Option<Box<dyn Tracer>>,
+  pub profiler: Option<profiler::Profiler>,
+  pub calls: Vec<*const str>, // names of the definitions being evaluated, innermost last, borrowed from their callwords
+  pub locate_words: bool, // attach source locations to parsed words
   pub args: Stack, //
   pub fllibs: Option<ForeignLibraries>,
   pub builtins: Functions,
@@ -692,6 +781,19 @@ impl CognitionState {
       control: CognitionControl::None,
       exited: false,
       exit_code: None,
+      limits: Limits::default(),
+      capabilities: Capabilities::default(),
+      depth: 0,
+      limit_hit: false,
+      interrupt: Arc::new(AtomicBool::new(false)),
+      interrupted: false,
+      trying: 0,
+      thrown: false,
+      pending_errors: 0,
+      tracer: None,
+      profiler: None,
+      calls: Vec::with_capacity(DEFAULT_STACK_SIZE),
+      locate_words: false,
       args: Stack::new(),
       fllibs: None,
       builtins: Vec::with_capacity(BUILTINS_SIZE),
@@ -700,59 +802,183 @@ impl CognitionState {
     }
   }
 
-  pub fn verr_loc(&mut self) -> Option<VErrorLoc> {
-    if let Some(ref parser) = self.parser {
-      if let Some(ref filename) = parser.filename {
+  // where w was written if it carries a location, otherwise where the parser is
+  pub fn verr_loc(&mut self, w: Option<&Value>) -> Option<VErrorLoc> {
+    let word_loc = w.and_then(|w| if w.is_word() { w.vword_ref().loc.clone() } else { None });
+    let (mut loc, line, column) = match word_loc {
+      Some(ref word_loc) => {
+        let filename = word_loc.filename.as_ref()?;
+        let mut loc = self.pool.get_verror_loc(filename.len());
+        loc.filename.push_str(filename);
+        (loc, word_loc.line, word_loc.column)
+      },
+      None => {
+        let parser = self.parser.as_ref()?;
+        let filename = parser.filename.as_ref()?;
         let mut loc = self.pool.get_verror_loc(filename.len());
         loc.filename.push_str(filename);
-        if let Some(math) = self.current().math.take() {
-          if math.base() == 1 {
-            let zero = math.get_digits().get(0).expect("Math missing digits");
-            if self.parser.as_ref().unwrap().line == 0 { loc.line.push(zero.clone()) }
-            if self.parser.as_ref().unwrap().column == 0 { loc.column.push(zero.clone()) }
+        (loc, parser.line, parser.column)
+      }
+    };
+    if let Some(math) = self.current().math.take() {
+      if math.base() == 1 {
+        let zero = math.get_digits().get(0).expect("Math missing digits");
+        if line == 0 { loc.line.push(zero.clone()) }
+        if column == 0 { loc.column.push(zero.clone()) }
+      }
+      if math.base() > 1 {
+        if line <= isize::MAX as usize {
+          if let Ok(line) = math.itos(line as isize, self) {
+            loc.line.push_str(&line);
+            self.pool.add_string(line);
           }
-          if math.base() > 1 {
-            if self.parser.as_ref().unwrap().line <= isize::MAX as usize {
-              let line = self.parser.as_ref().unwrap().line as isize;
-              if let Ok(line) = math.itos(line, self) {
-                loc.line.push_str(&line);
-                self.pool.add_string(line);
-              }
-            }
-            if self.parser.as_ref().unwrap().column <= isize::MAX as usize {
-              let column = self.parser.as_ref().unwrap().column as isize;
-              if let Ok(column) = math.itos(column, self) {
-                loc.column.push_str(&column);
-                self.pool.add_string(column);
-              }
-            }
+        }
+        if column <= isize::MAX as usize {
+          if let Ok(column) = math.itos(column as isize, self) {
+            loc.column.push_str(&column);
+            self.pool.add_string(column);
           }
-          self.current().math = Some(math)
         }
-        return Some(loc)
       }
+      self.current().math = Some(math)
     }
-    None
+    Some(loc)
+  }
+
+  pub fn eval_error_mut(&mut self, kind: ErrorKind, w: Option<&Value>) {
+    self.raise_mut(kind, kind.message().unwrap_or(kind.name()), w)
+  }
+
+  // raises an error no ErrorKind has a message for, such as an fllib's own
+  pub fn eval_error_other_mut(&mut self, e: &str, w: Option<&Value>) {
+    self.raise_mut(ErrorKind::Other, e, w)
   }
 
-  pub fn eval_error_mut(&mut self, e: &'static str, w: Option<&Value>) {
+  pub(crate) fn raise_mut(&mut self, kind: ErrorKind, e: &str, w: Option<&Value>) {
     let mut verror = self.pool.get_verror(e.len());
     verror.error.push_str(e);
+    verror.kind = kind;
     verror.str_word = match w {
       None => None,
       Some(v) => Some(self.string_copy(&v.vword_ref().str_word)),
     };
-    verror.loc = self.verr_loc();
+    verror.loc = self.verr_loc(w);
+    for &name in self.calls.iter() {
+      // a callword outlives its evaluation, and call_exit takes its name off
+      // before the evaluation ends
+      let name = unsafe { &*name };
+      let mut copy = self.pool.get_string(name.len());
+      copy.push_str(name);
+      verror.backtrace.push(copy);
+    }
+    self.push_error(verror)
+  }
+
+  // pushes a raised error onto the current container's error stack, counting
+  // it for the container's on_error
+  pub(crate) fn push_error(&mut self, verror: Box<VError>) {
     if let None = self.current_ref().err_stack {
       let temp = self.pool.get_stack(1);
       self.current().err_stack = Some(temp);
     }
     let estack: &mut Stack = &mut self.current().err_stack.as_mut().unwrap();
     estack.push(Value::Error(verror));
+    // errors inside a try body are left for try to catch
+    let trying = self.trying > 0;
+    let cur = self.current();
+    if !cur.handling_errors && !trying && cur.on_error.is_some() {
+      cur.errors_pending += 1;
+      self.pending_errors += 1
+    }
+    self.trace(|t, s| {
+      if let Some(Value::Error(verror)) = s.current_ref().err_stack.as_ref().and_then(|e| e.last()) {
+        t.error(s, verror)
+      }
+    });
   }
 
-  pub fn eval_error(mut self, e: &'static str, w: Option<&Value>) -> Self {
-    self.eval_error_mut(e, w);
+  pub fn trace<F: FnOnce(&mut dyn Tracer, &Self)>(&mut self, f: F) {
+    if let Some(mut tracer) = self.tracer.take() {
+      f(tracer.as_mut(), self);
+      self.tracer = Some(tracer);
+    }
+  }
+
+  pub fn profile_enter(&mut self, name: &str) {
+    if let Some(ref mut profiler) = self.profiler { profiler.enter(name) }
+  }
+  pub fn profile_exit(&mut self) {
+    if let Some(ref mut profiler) = self.profiler { profiler.exit() }
+  }
+
+  // a definition called by this word starts or finishes evaluating
+  fn call_enter(&mut self, word: &Value) {
+    let name = word.vword_ref().str_word.as_str();
+    self.profile_enter(name);
+    self.calls.push(name);
+  }
+  fn call_exit(&mut self) {
+    self.calls.pop();
+    self.profile_exit();
+  }
+
+  pub fn errors_pending(&self) -> bool {
+    self.pending_errors > 0
+  }
+
+  // Handles the pending errors of each container on the metastack, innermost
+  // first, with the containers above it taken off so that it is current.
+  // Errors a handler raises in another container, as eraise does, are
+  // handled in turn.
+  pub fn handle_errors(mut self) -> Self {
+    while let Some(i) = self.stack.iter().rposition(|v| v.vstack_ref().container.errors_pending > 0) {
+      let mut above = self.stack.split_off(i + 1);
+      self = self.handle_current_errors();
+      self.stack.append(&mut above);
+      self.pool.add_stack(above);
+    }
+    // errors counted in containers that have since been dropped are gone
+    self.pending_errors = 0;
+    self
+  }
+
+  // Takes the current container's pending errors off its error stack, oldest
+  // first, and evaluates its on_error with each one. Errors the handler
+  // raises in the container itself are left on the error stack.
+  fn handle_current_errors(mut self) -> Self {
+    let pending = std::mem::take(&mut self.current().errors_pending);
+    self.pending_errors = self.pending_errors.saturating_sub(pending);
+    let Some(handler) = self.current_ref().on_error.clone() else { return self };
+    let Some(estack) = self.current().err_stack.as_mut() else { return self };
+    let mut errors = estack.split_off(estack.len().saturating_sub(pending));
+    let container: *const VStack = &**self.stack.last().expect("Cognition metastack was empty").vstack_ref();
+    self.current().handling_errors = true;
+    for e in errors.drain(..) {
+      if self.exited {
+        self.pool.add_val(e);
+        continue
+      }
+      let ve = builtins::errors::error_stack(&mut self, &e);
+      self.pool.add_val(e);
+      self.current().stack.push(ve);
+      self = self.evalstack(handler.clone(), None, false);
+    }
+    // the handler may have left the container
+    if let Some(container) = builtins::combinators::find_container(&mut self, container) {
+      container.handling_errors = false
+    }
+    self.pool.add_stack(errors);
+    self.pool.add_word_def(handler);
+    self
+  }
+
+  pub fn eval_error(mut self, kind: ErrorKind, w: Option<&Value>) -> Self {
+    self.eval_error_mut(kind, w);
+    self
+  }
+
+  pub fn eval_error_other(mut self, e: &str, w: Option<&Value>) -> Self {
+    self.eval_error_other_mut(e, w);
     self
   }
 
@@ -840,6 +1066,7 @@ impl CognitionState {
     new.dflag = old.dflag;
     new.iflag = old.iflag;
     new.sflag = old.sflag;
+    new.on_error = old.on_error.clone();
 
     if let Some(ref word_table) = old.word_table {
       new.word_table = Some(self.pool.get_word_table(word_table.capacity()));
@@ -854,6 +1081,7 @@ impl CognitionState {
       Value::Word(vword) => {
         let mut new_vword = self.pool.get_vword(vword.str_word.len());
         new_vword.str_word.push_str(&vword.str_word);
+        new_vword.loc = vword.loc.clone();
         Value::Word(new_vword)
       },
       Value::Stack(vstack) => {
@@ -871,6 +1099,7 @@ impl CognitionState {
       Value::Error(verror) => {
         let mut new_verror = self.pool.get_verror(verror.error.len());
         new_verror.error.push_str(&verror.error);
+        new_verror.kind = verror.kind;
         if let Some(ref word) = verror.str_word {
           new_verror.str_word = Some(self.string_copy(word));
         }
@@ -881,6 +1110,10 @@ impl CognitionState {
           new_loc.column.push_str(&loc.column);
           new_verror.loc = Some(new_loc);
         }
+        for word in verror.backtrace.iter() {
+          let word = self.string_copy(word);
+          new_verror.backtrace.push(word);
+        }
         Value::Error(new_verror)
       },
       Value::FLLib(vfllib) => {
@@ -990,11 +1223,12 @@ impl CognitionState {
     self.add_const_value(name, Value::Word(vword))
   }
 
-  pub unsafe fn load_fllib(&mut self, lib_name: &String, filename: &String) -> Option<&'static str> {
-    let Ok(lib) = libloading::Library::new(filename) else { return Some("INVALID FILENAME") };
+  pub unsafe fn load_fllib(&mut self, lib_name: &String, filename: &String) -> Option<ErrorKind> {
+    if !self.capabilities.has(Capability::Fllib) { return Some(ErrorKind::PermissionDenied) }
+    let Ok(lib) = libloading::Library::new(filename) else { return Some(ErrorKind::InvalidFilename) };
     let fllib_add_words = match lib.get::<libloading::Symbol<AddWordsFn>>(b"add_words\0") {
       Ok(f) => f.into_raw(),
-      Err(_) => return Some("INVALID FLLIB")
+      Err(_) => return Some(ErrorKind::InvalidFllib)
     };
     let lib_name = self.string_copy(lib_name);
     let lib_path = self.string_copy(filename);
@@ -1004,6 +1238,15 @@ impl CognitionState {
     None
   }
 
+  // whether the argument n values from the top is a word, which builtins taking
+  // either a stream or a filename open as a file, and cap is not allowed
+  pub fn filename_denied(&self, n: usize, cap: Capability) -> bool {
+    if self.capabilities.has(cap) { return false }
+    let stack = &self.current_ref().stack;
+    let Some(v) = stack.len().checked_sub(n + 1).map(|i| &stack[i]) else { return false };
+    (v.is_stack() || v.is_macro()) && v.value_stack_ref().first().is_some_and(|v| v.is_word())
+  }
+
   pub fn push_quoted(&mut self, v: Value) {
     let mut wrapper = self.pool.get_vstack(1);
     wrapper.container.stack.push(v);
@@ -1043,25 +1286,62 @@ impl CognitionState {
     true
   }
 
+  // Spends one step of fuel and checks the other limits and the interrupt
+  // flag, unwinding if any is exceeded or set
+  pub fn check_limits(&mut self, w: Option<&Value>) -> bool {
+    if self.interrupt.swap(false, atomic::Ordering::Relaxed) {
+      self.eval_error_mut(ErrorKind::Interrupted, w);
+      self.interrupted = true;
+      self.exited = true;
+      return false
+    }
+    let e = if self.limits.fuel == Some(0) { ErrorKind::FuelExhausted }
+    else if self.limits.depth.is_some_and(|d| self.depth > d) { ErrorKind::RecursionTooDeep }
+    else if self.limits.stack.is_some_and(|n| {
+      self.stack.iter().map(|v| v.value_stack_ref().len()).sum::<usize>() > n
+    }) { ErrorKind::StackTooLarge }
+    else {
+      if let Some(ref mut fuel) = self.limits.fuel { *fuel -= 1 }
+      return true
+    };
+    self.eval_error_mut(e, w);
+    self.limit_hit = true;
+    self.exited = true;
+    false
+  }
+
   pub fn evalstack(mut self, mut wd: WordDef, callword: Option<&Value>, crank_first: bool) -> Self {
+    self.depth += 1;
+    self.trace(|t, s| t.enter(s, &wd, callword, false));
     let mut eval = CognitionEval::setup(&mut self, &mut wd, crank_first);
     while !eval.is_empty() {
+      if !self.check_limits(callword) { break }
       let (state, recurse) = eval.eval_value(self, callword);
-      (self, eval) = eval.eval_recurse(state, recurse, callword);
+      let nested;
+      (self, eval, nested) = eval.eval_recurse(state, recurse);
+      if let Some((wdn, crank_first, kind)) = nested {
+        let callword = eval.nested_callword(&kind, callword);
+        let called = eval.call_nested(&mut self, &kind, callword);
+        self = self.evalstack(wdn, callword, crank_first);
+        if called { self.call_exit() }
+        eval.finish_nested(&mut self, kind);
+      }
       if self.exited { break }
       if self.control.is_return() {
         if eval.kill_return() { self.control.clear(); }
         break
       }
     }
+    self.trace(|t, s| t.exit(s, callword));
     eval.decommission(&mut self);
     self.pool.add_word_def(wd);
+    self.depth -= 1;
     self
   }
 
   pub fn get_evalf_val(&mut self, alias: Option<&Value>) -> Option<WordDef> {
     let Some(needseval) = self.current().stack.pop() else {
-      self.eval_error_mut("EMPTY STACK", alias);
+      self.eval_error_mut(ErrorKind::EmptyStack, alias);
       return None
     };
     let wd = self.pool.get_word_def(needseval);
@@ -1084,7 +1364,7 @@ impl CognitionState {
     let fixedindex: isize = cur.stack.len() as isize - 1 - cidx as isize;
     if fixedindex < 0 {
       cur.inc_crank();
-      self.eval_error_mut("CRANK TOO DEEP", w);
+      self.eval_error_mut(ErrorKind::CrankTooDeep, w);
       return None;
     }
     let needseval = cur.stack.remove(fixedindex as usize);
@@ -1175,6 +1455,13 @@ impl EvalStack {
   }
 }
 
+// What a frame does after a nested evaluation it started has finished
+enum EvalNested {
+  Evalf,
+  Crank,
+  Def
+}
+
 enum EvalRecurse {
   Def(WordDef),
   Evalf(WordDef),
@@ -1193,6 +1480,7 @@ struct CognitionEval {
   crank_first: bool,
   kill_return: bool,
   family_stack_pushes: usize,
+  called: bool, // whether this frame was reused for a definition, which is then on state.calls
 }
 
 impl CognitionEval {
@@ -1203,7 +1491,7 @@ impl CognitionEval {
     let family_stack_pushes = if is_macro { 0 } else { 1 };
     let local_family = state.pool.get_family();
     Self{ stack, first_v: true, local_family, legacy_family: None, is_macro,
-          callword_owned: None, crank_first, kill_return: false, family_stack_pushes }
+          callword_owned: None, crank_first, kill_return: false, family_stack_pushes, called: false }
   }
   fn recurse(mut self, mut wd: WordDef, crank_first: bool, is_def: bool, state: &mut CognitionState) -> Self {
     let stack = EvalStack::from(&mut wd, state);
@@ -1221,12 +1509,25 @@ impl CognitionEval {
       state.family.push(wd.clone());
       self.family_stack_pushes += 1;
     }
-    let callword_owned = if is_def { self.stack.get_next(state) } else { self.callword_owned };
+    // a definition called in tail position brings its own callword, so the
+    // one this frame held goes back to the pool
+    let callword_owned = if is_def {
+      if let Some(v) = self.callword_owned.take() { state.pool.add_val(v) }
+      self.stack.get_next(state)
+    } else { self.callword_owned };
     self.stack.decommission(state);
+    state.trace(|t, s| t.enter(s, &wd, callword_owned.as_ref(), true));
     state.pool.add_word_def(wd);
+    let mut called = self.called;
+    if is_def {
+      if called { state.call_exit() }
+      if let Some(ref v) = callword_owned { state.call_enter(v) }
+      called = callword_owned.is_some();
+    }
     let kill_return = is_def || self.kill_return;
     Self{ stack, first_v: true, local_family: self.local_family, legacy_family: self.legacy_family,
-          is_macro, callword_owned, crank_first, kill_return, family_stack_pushes: self.family_stack_pushes }
+          is_macro, callword_owned, crank_first, kill_return, family_stack_pushes: self.family_stack_pushes,
+          called }
   }
   pub fn decommission(mut self, state: &mut CognitionState) {
     while let Some(f) = self.local_family.pop() { state.family.push(f) }
@@ -1241,6 +1542,7 @@ impl CognitionEval {
     if let Some(callword) = self.callword_owned {
       state.pool.add_val(callword)
     }
+    if self.called { state.call_exit() }
     self.stack.decommission(state);
   }
 
@@ -1263,6 +1565,7 @@ impl CognitionEval {
       if let Some(ref wt) = state.current().word_table {
         if let Some(wd) = wt.get(&v.vword_ref().str_word) {
           let new_word_def = wd.clone();
+          state.trace(|t, s| t.lookup(s, v, None));
           return (state, EvalRecurse::Def(new_word_def))
         }
       }
@@ -1301,6 +1604,7 @@ impl CognitionEval {
           if let Some(wd) = wt.get(&v.vword_ref().str_word) {
             let new_word_def = wd.clone();
             state.family.push(family_stack);
+            state.trace(|t, s| t.lookup(s, v, Some(self.local_family.len())));
             return (state, EvalRecurse::Def(new_word_def))
           }
         }
@@ -1327,7 +1631,13 @@ impl CognitionEval {
     let fllib = v.vfllib_ref().fllib.clone();
     if state.is_high_tide() || self.force_eval() {
       if self.cranking() { state.current().inc_crank() }
+      state.trace(|t, s| t.fllib(s, v));
+      if state.profiler.is_some() {
+        let name = v.vfllib_ref().str_word.as_deref().unwrap_or("fllib");
+        state.profile_enter(&format!("{name}()"))
+      }
       state = fllib(state, self.callword(callword));
+      state.profile_exit();
       if state.control.is_eval() {
         state.control.clear();
         if let Some(wd) = state.get_evalf_val(self.callword(callword)) {
@@ -1377,8 +1687,9 @@ impl CognitionEval {
     (state, EvalRecurse::None)
   }
 
-  pub fn eval_value(&mut self, state: CognitionState, callword: Option<&Value>) -> (CognitionState, EvalRecurse) {
+  pub fn eval_value(&mut self, mut state: CognitionState, callword: Option<&Value>) -> (CognitionState, EvalRecurse) {
     let Some(v) = self.stack.peek() else { return (state, EvalRecurse::None) };
+    state.trace(|t, s| t.value(s, v));
     match v {
       Value::Word(_) => self.eval_word(state, callword),
       Value::Error(_) => panic!("VError on stack"),
@@ -1387,48 +1698,220 @@ impl CognitionEval {
       _ => self.eval_push_to_stack(state, callword)
     }
   }
-  pub fn eval_recurse(mut self, mut state: CognitionState, recurse: EvalRecurse, callword: Option<&Value>) -> (CognitionState, Self) {
-    (state, self) = match recurse {
+  // Values in tail position reuse this frame. Otherwise the nested
+  // evaluation is returned to be run by the caller, which then calls
+  // finish_nested.
+  pub fn eval_recurse(mut self, mut state: CognitionState, recurse: EvalRecurse)
+                      -> (CognitionState, Self, Option<(WordDef, bool, EvalNested)>) {
+    let nested = match recurse {
       EvalRecurse::Evalf(wdn) => {
+        state.trace(|t, s| t.evalf(s, &wdn));
         self.first_v = false;
         if self.is_empty() {
-          let new_eval = self.recurse(wdn, false, false, &mut state);
-          (state, new_eval)
-        } else {
-          (state.evalstack(wdn, self.callword(callword), false), self)
-        }
+          self = self.recurse(wdn, false, false, &mut state);
+          None
+        } else { Some((wdn, false, EvalNested::Evalf)) }
       },
       EvalRecurse::Crank(wdn) => {
+        state.trace(|t, s| t.crank(s, &wdn));
         self.first_v = false;
         if self.is_empty() {
-          let new_eval = self.recurse(wdn, true, false, &mut state);
-          (state, new_eval)
-        } else {
-          (state.evalstack(wdn, self.callword(callword), true), self)
-        }
+          self = self.recurse(wdn, true, false, &mut state);
+          None
+        } else { Some((wdn, true, EvalNested::Crank)) }
       },
       EvalRecurse::Def(wdn) => {
+        let cranking = self.cranking();
         if self.stack.last() {
-          let cranking = self.cranking();
-          let new_eval = self.recurse(wdn, cranking, true, &mut state);
-          (state, new_eval)
-        } else {
-          let w = self.stack.peek();
-          state = state.evalstack(wdn, w, self.cranking());
-          state.control.clear();
-          while let Some(f) = self.local_family.pop() { state.family.push(f) }
-          self.stack.advance(&mut state);
-          self.first_v = false;
-          (state, self)
-        }
+          self = self.recurse(wdn, cranking, true, &mut state);
+          None
+        } else { Some((wdn, cranking, EvalNested::Def)) }
       },
       EvalRecurse::None => {
         self.first_v = false;
-        (state, self)
+        None
       },
-      EvalRecurse::Ghost => (state, self)
+      EvalRecurse::Ghost => None
     };
+    if nested.is_none() {
+      while let Some(f) = self.local_family.pop() { state.family.push(f) }
+      // with the family back in place, errors can be handled here
+      if state.errors_pending() { state = state.handle_errors() }
+    }
+    (state, self, nested)
+  }
+
+  fn nested_callword<'a>(&'a self, nested: &EvalNested, callword: Option<&'a Value>) -> Option<&'a Value> {
+    match nested {
+      EvalNested::Def => self.stack.peek(),
+      _ => self.callword(callword)
+    }
+  }
+
+  // records a nested definition on state.calls, returning whether it did
+  fn call_nested(&self, state: &mut CognitionState, nested: &EvalNested, callword: Option<&Value>) -> bool {
+    let (EvalNested::Def, Some(v)) = (nested, callword) else { return false };
+    state.call_enter(v);
+    true
+  }
+
+  fn finish_nested(&mut self, state: &mut CognitionState, nested: EvalNested) {
+    if let EvalNested::Def = nested {
+      state.control.clear();
+      self.stack.advance(state);
+      self.first_v = false;
+    }
     while let Some(f) = self.local_family.pop() { state.family.push(f) }
-    (state, self)
+  }
+}
+
+struct EvalFrame {
+  eval: CognitionEval,
+  wd: WordDef,
+  callword: Option<Value>,
+  nested: Option<EvalNested>,
+  called: bool, // whether the nested evaluation is a definition on state.calls
+}
+
+/// Resumable counterpart to CognitionState::evalstack. Nested definitions,
+/// cranks and evalf are kept as frames here rather than on the native stack,
+/// so evaluation can be paused between any two values and picked up again
+/// later. Builtins that evaluate internally still run to completion within a
+/// step.
+pub struct Evaluator {
+  frames: Vec<EvalFrame>,
+}
+
+impl Evaluator {
+  pub fn new(state: &mut CognitionState, wd: WordDef, callword: Option<&Value>, crank_first: bool) -> Self {
+    let callword = callword.map(|v| state.value_copy(v));
+    let mut evaluator = Self{ frames: Vec::with_capacity(DEFAULT_STACK_SIZE) };
+    evaluator.enter(state, wd, callword, crank_first);
+    evaluator.settle(state);
+    evaluator
+  }
+
+  /// Counterpart to CognitionState::eval for a value read at the top level,
+  /// or None if the value doesn't start an evaluation
+  pub fn from_value(state: &mut CognitionState, v: Value) -> Option<Self> {
+    let cur = state.current_ref();
+    if cur.isfalias(&v) {
+      let evalf = cur.cranks.as_ref().and_then(|c| c.first()).is_none_or(|c| c.base != 1 && c.modulo != 1);
+      let wd = if evalf { state.get_evalf_val(Some(&v)) } else { None };
+      state.pool.add_val(v);
+      return wd.map(|wd| Self::new(state, wd, None, false))
+    }
+    state.push_quoted(v);
+    let wd = state.get_crank_val(None)?;
+    Some(Self::new(state, wd, None, true))
+  }
+
+  pub fn is_done(&self) -> bool { self.frames.is_empty() }
+  pub fn depth(&self) -> usize { self.frames.len() }
+  /// The value the next step evaluates
+  pub fn peek(&self) -> Option<&Value> { self.frames.last().and_then(|f| f.eval.stack.peek()) }
+
+  /// Evaluates the next value
+  pub fn step(&mut self, mut state: CognitionState) -> CognitionState {
+    let Some(mut frame) = self.frames.pop() else { return state };
+    if !state.check_limits(frame.callword.as_ref()) {
+      self.frames.push(frame);
+      self.leave(&mut state);
+      return state
+    }
+    let (s, recurse) = frame.eval.eval_value(state, frame.callword.as_ref());
+    let (s, eval, nested) = frame.eval.eval_recurse(s, recurse);
+    state = s;
+    frame.eval = eval;
+    match nested {
+      Some((wdn, crank_first, kind)) => {
+        let callword = frame.eval.nested_callword(&kind, frame.callword.as_ref());
+        frame.called = frame.eval.call_nested(&mut state, &kind, callword);
+        let callword = callword.map(|v| state.value_copy(v));
+        frame.nested = Some(kind);
+        self.frames.push(frame);
+        self.enter(&mut state, wdn, callword, crank_first);
+      },
+      None => {
+        let ends = Self::ends(&mut state, &frame.eval);
+        self.frames.push(frame);
+        if ends { self.leave(&mut state) }
+      }
+    }
+    self.settle(&mut state);
+    state
+  }
+
+  /// Evaluates up to n values, stopping early if evaluation finishes
+  pub fn steps(&mut self, mut state: CognitionState, n: usize) -> CognitionState {
+    for _ in 0..n {
+      if self.is_done() { break }
+      state = self.step(state);
+    }
+    state
+  }
+
+  /// Evaluates values until the deadline passes or evaluation finishes
+  pub fn until(&mut self, mut state: CognitionState, deadline: std::time::Instant) -> CognitionState {
+    while !self.is_done() && std::time::Instant::now() < deadline {
+      state = self.step(state);
+    }
+    state
+  }
+
+  /// Evaluates everything that is left
+  pub fn finish(mut self, mut state: CognitionState) -> CognitionState {
+    while !self.is_done() { state = self.step(state) }
+    state
+  }
+
+  /// Abandons the evaluation, leaving the state as if it had stopped here
+  pub fn cancel(mut self, state: &mut CognitionState) {
+    while let Some(frame) = self.frames.pop() { Self::drop_frame(frame, state) }
+  }
+
+  fn enter(&mut self, state: &mut CognitionState, mut wd: WordDef, callword: Option<Value>, crank_first: bool) {
+    state.depth += 1;
+    state.trace(|t, s| t.enter(s, &wd, callword.as_ref(), false));
+    let eval = CognitionEval::setup(state, &mut wd, crank_first);
+    self.frames.push(EvalFrame{ eval, wd, callword, nested: None, called: false });
+  }
+
+  fn drop_frame(frame: EvalFrame, state: &mut CognitionState) {
+    state.trace(|t, s| t.exit(s, frame.callword.as_ref()));
+    if frame.called { state.call_exit() }
+    frame.eval.decommission(state);
+    state.pool.add_word_def(frame.wd);
+    if let Some(v) = frame.callword { state.pool.add_val(v) }
+    state.depth -= 1;
+  }
+
+  // whether a frame stops after its last value, as in evalstack
+  fn ends(state: &mut CognitionState, eval: &CognitionEval) -> bool {
+    if state.exited { return true }
+    if state.control.is_return() {
+      if eval.kill_return() { state.control.clear() }
+      return true
+    }
+    false
+  }
+
+  // drops the top frame, and any below it that stop as a result
+  fn leave(&mut self, state: &mut CognitionState) {
+    while let Some(frame) = self.frames.pop() {
+      Self::drop_frame(frame, state);
+      let Some(parent) = self.frames.last_mut() else { break };
+      if parent.called {
+        state.call_exit();
+        parent.called = false;
+      }
+      if let Some(kind) = parent.nested.take() { parent.eval.finish_nested(state, kind) }
+      if !Self::ends(state, &parent.eval) { break }
+    }
+  }
+
+  // drops frames with nothing left to evaluate
+  fn settle(&mut self, state: &mut CognitionState) {
+    while self.frames.last().is_some_and(|f| f.eval.is_empty()) { self.leave(state) }
   }
 }
@@ -19,6 +19,7 @@ pub const DEFAULT_FALIASES_SIZE: usize = 24;
 pub const DEFAULT_OP_SIZE: usize = 576;
 pub const DEFAULT_OPS_TABLE_SIZE: usize = 24;
 pub const DEFAULT_BASE: usize = 24;
+pub const DEFAULT_INT_LIMBS: usize = 8;
 pub const BUILTINS_SIZE: usize = 192;
 
 #[macro_export]
@@ -136,7 +137,7 @@ macro_rules! data_formats_entry {
       let mut deserializer = $deserializer_from_str(f);
       match crate::serde::deserialize_cognition_state_from_state(&mut deserializer, &mut state, i) {
         Ok(_) => Ok(state),
-        Err(e) => Err((state, Box::new(e)))
+        Err(e) => Err((state, crate::ErrorKind::DeserializationFailed, Box::new(e)))
       }
     })
   }};
@@ -144,9 +145,9 @@ macro_rules! data_formats_entry {
     (|fllibs, mut state| {
       let mut deserializer = $deserializer_from_str(fllibs);
       match crate::serde::serde_load_fllibs(&mut deserializer, &mut state) {
-        Err(e) => Err((state, Box::new(e))),
+        Err(e) => Err((state, crate::ErrorKind::DeserializationFailed, Box::new(e))),
         Ok(opt) => match opt {
-          Some(e) => Err((state, Box::new(e))),
+          Some(kind) => Err((state, kind, Box::new(kind))),
           None => Ok(state)
         }
       }
@@ -307,16 +308,45 @@ macro_rules! option_deserialize_fn {
   }
 }
 
-pub const BUILTIN_CUSTOM_DESERIALIZERS: [(&str, crate::DeserializeFn<dyn crate::Custom>); 7] = [
+pub const BUILTIN_CUSTOM_DESERIALIZERS: [(&str, crate::DeserializeFn<dyn crate::Custom>); 8] = [
   ("cognition::Void", void_deserialize_fn!{}),
   ("cognition::builtins::io::ReadWriteCustom", option_deserialize_fn!{crate::builtins::io::ReadWriteCustom}),
   ("cognition::builtins::io::FileCustom", option_deserialize_fn!{crate::builtins::io::FileCustom}),
   ("cognition::builtins::io::ReadCustom", option_deserialize_fn!{crate::builtins::io::ReadCustom}),
   ("cognition::builtins::io::WriteCustom", option_deserialize_fn!{crate::builtins::io::WriteCustom}),
   ("cognition::builtins::io::BufReadCustom", option_deserialize_fn!{crate::builtins::io::BufReadCustom}),
-  ("cognition::builtins::io::BufWriteCustom", option_deserialize_fn!{crate::builtins::io::BufWriteCustom})
+  ("cognition::builtins::io::BufWriteCustom", option_deserialize_fn!{crate::builtins::io::BufWriteCustom}),
+  ("cognition::builtins::serde::SnapshotCustom", void_deserialize_fn!{})
 ];
 
+#[macro_export]
+macro_rules! require {
+  ($state:ident,$w:ident,$($cap:ident),+) => {
+    if $(!$state.capabilities.has($crate::Capability::$cap))||+ {
+      return $state.eval_error($crate::ErrorKind::PermissionDenied, $w)
+    }
+  }
+}
+
+// borrows the nearest math with a nonzero base, or gives it back, runs $fail
+// to put back what the builtin popped and raises MATH BASE ZERO
+#[macro_export]
+macro_rules! get_math {
+  ($state:ident,$w:ident,$fail:block) => {
+    match $state.get_math() {
+      Some(math) if math.math().base() != 0 => math,
+      math => {
+        if let Some(math) = math { $state.set_math(math) }
+        $fail;
+        return $state.eval_error($crate::ErrorKind::MathBaseZero, $w)
+      }
+    }
+  };
+  ($state:ident,$w:ident) => {
+    get_math!($state, $w, {})
+  }
+}
+
 #[macro_export]
 macro_rules! bad_value_err {
   () => { panic!("Bad value on stack") };
@@ -431,7 +461,7 @@ macro_rules! ensure_foreign_library {
       fllibs = Some(ForeignLibraries::new());
     }
     if let Some(fd) = fllibs.as_mut().unwrap().get_mut(&$lib.lib_name) {
-      $state.eval_error_mut("FLLIB EXISTS", None);
+      $state.eval_error_mut($crate::ErrorKind::FllibExists, None);
       $state.fllibs = fllibs;
       return
     } else {
@@ -801,30 +831,30 @@ macro_rules! get_char {
     let Some(v) = cur.stack.last() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     if v.value_stack_ref().len() != 1 {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_v = &v.value_stack_ref()[0];
     if !word_v.is_word() {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let s = &word_v.vword_ref().str_word;
     let mut iter = s.chars();
     let Some($c) = iter.next() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     if iter.next().is_some() {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let v = cur.stack.pop().unwrap();
     $state.pool.add_val(v);
@@ -844,18 +874,18 @@ macro_rules! get_char_option {
     let Some(v) = cur.stack.last() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     if v.value_stack_ref().len() != 1 {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_v = &v.value_stack_ref()[0];
     if !word_v.is_word() {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let s = &word_v.vword_ref().str_word;
     let mut iter = s.chars();
@@ -864,7 +894,7 @@ macro_rules! get_char_option {
     if tmp.is_some() && iter.next().is_some() {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let v = cur.stack.pop().unwrap();
     $state.pool.add_val(v);
@@ -883,18 +913,18 @@ macro_rules! get_word {
     let Some(v) = $state.current().stack.last() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     if v.value_stack_ref().len() != 1 {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_v = &v.value_stack_ref()[0];
     if !word_v.is_word() {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
   };
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block) => {{
@@ -916,7 +946,7 @@ macro_rules! get_2_words {
     if stack.len() < 2 {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     }
     let v2 = stack.pop().unwrap();
     let v1 = stack.last().unwrap();
@@ -924,7 +954,7 @@ macro_rules! get_2_words {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word2_v = &v2.value_stack_ref()[0];
     let word1_v = &v1.value_stack_ref()[0];
@@ -932,7 +962,7 @@ macro_rules! get_2_words {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     (stack.pop().unwrap(), v2)
   }};
@@ -950,17 +980,17 @@ macro_rules! get_custom {
     let Some(v) = $state.current_ref().stack.last() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     if v.value_stack_ref().len() != 1 {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     if !v.value_stack_ref().first().unwrap().is_custom() {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
   }};
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block) => {{
@@ -978,38 +1008,38 @@ macro_rules! get_custom {
 // note: do not use usize (use isize instead)
 #[macro_export]
 macro_rules! get_unsigned {
-  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:literal) => {{
+  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:ident) => {{
     let Some(v) = $state.current().stack.pop() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     let stack = v.value_stack_ref();
     if stack.len() != 1 {
       $state.current().stack.push(v);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_val = &stack[0];
     let $crate::Value::Word(vword) = word_val else {
       $state.current().stack.push(v);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     let Some(math) = $state.get_math() else {
       $state.current().stack.push(v);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     };
     if math.math().base() == 0 {
       $state.set_math(math);
       $state.current().stack.push(v);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     }
     let i = match math.math().stoi(&vword.str_word) {
       Ok(i) => if i > <$type>::MAX as isize || i < 0 {
@@ -1017,7 +1047,7 @@ macro_rules! get_unsigned {
         $state.current().stack.push(v);
         let $state_pat = $state;
         $fail;
-        return $state_token.eval_error($err, $w)
+        return $state_token.eval_error($crate::ErrorKind::$err, $w)
       } else { i as $type },
       Err(e) => {
         $state.set_math(math);
@@ -1032,7 +1062,7 @@ macro_rules! get_unsigned {
     i
   }};
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE) => {
-    get_unsigned!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, "OUT OF BOUNDS")
+    get_unsigned!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, OutOfBounds)
   };
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty) => {{
     let i = get_unsigned!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE);
@@ -1053,36 +1083,36 @@ macro_rules! get_unsigned {
 
 #[macro_export]
 macro_rules! get_int {
-  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:literal) => {{
+  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:ident) => {{
     let Some(v) = $state.current().stack.pop() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     let stack = v.value_stack_ref();
     if stack.len() != 1 {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_val = &stack[0];
     let $crate::Value::Word(vword) = word_val else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     let Some(math) = $state.get_math() else {
       $state.current().stack.push(v);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     };
     if math.math().base() == 0 {
       $state.set_math(math);
       $state.current().stack.push(v);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     }
     let i = match math.math().stoi(&vword.str_word) {
       Ok(i) => if i > <$type>::MAX as isize || i < <$type>::MIN as isize {
@@ -1090,7 +1120,7 @@ macro_rules! get_int {
         $state.current().stack.push(v);
         let $state_pat = $state;
         $fail;
-        return $state_token.eval_error($err, $w)
+        return $state_token.eval_error($crate::ErrorKind::$err, $w)
       } else { i as $type },
       Err(e) => {
         $state.set_math(math);
@@ -1105,7 +1135,7 @@ macro_rules! get_int {
     i
   }};
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE) => {
-    get_int!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, "OUT OF BOUNDS")
+    get_int!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, OutOfBounds)
   };
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty) => {{
     let i = get_int!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE);
@@ -1126,18 +1156,18 @@ macro_rules! get_int {
 
 #[macro_export]
 macro_rules! get_2_unsigned {
-  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:literal) => {{
+  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:ident) => {{
     let stack = &mut $state.current().stack;
     let Some(v2) = stack.pop() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w)
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w)
     };
     let Some(v1) = stack.pop() else {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w);
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w);
     };
     let stack1 = v1.value_stack_ref();
     if stack1.len() != 1 {
@@ -1145,7 +1175,7 @@ macro_rules! get_2_unsigned {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_val1 = &stack1[0];
     let $crate::Value::Word(vword1) = word_val1 else {
@@ -1153,7 +1183,7 @@ macro_rules! get_2_unsigned {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     let stack2 = v2.value_stack_ref();
     if stack2.len() != 1 {
@@ -1161,7 +1191,7 @@ macro_rules! get_2_unsigned {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_val2 = &stack2[0];
     let $crate::Value::Word(vword2) = word_val2 else {
@@ -1169,14 +1199,14 @@ macro_rules! get_2_unsigned {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     let Some(math) = $state.get_math() else {
       $state.current().stack.push(v1);
       $state.current().stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     };
     if math.math().base() == 0 {
       $state.set_math(math);
@@ -1184,7 +1214,7 @@ macro_rules! get_2_unsigned {
       $state.current().stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     }
     let i1 = match math.math().stoi(&vword1.str_word) {
       Ok(i) => if i > <$type>::MAX as isize || i < 0 {
@@ -1193,7 +1223,7 @@ macro_rules! get_2_unsigned {
         $state.current().stack.push(v2);
         let $state_pat = $state;
         $fail;
-        return $state_token.eval_error($err, $w)
+        return $state_token.eval_error($crate::ErrorKind::$err, $w)
       } else { i as $type },
       Err(e) => {
         $state.set_math(math);
@@ -1211,7 +1241,7 @@ macro_rules! get_2_unsigned {
         $state.current().stack.push(v2);
         let $state_pat = $state;
         $fail;
-        return $state_token.eval_error($err, $w)
+        return $state_token.eval_error($crate::ErrorKind::$err, $w)
       } else { i as $type },
       Err(e) => {
         $state.set_math(math);
@@ -1228,7 +1258,7 @@ macro_rules! get_2_unsigned {
     (i1, i2)
   }};
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE) => {
-    get_2_unsigned!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, "OUT OF BOUNDS")
+    get_2_unsigned!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, OutOfBounds)
   };
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty) => {{
     let (i1, i2) = get_2_unsigned!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE);
@@ -1251,17 +1281,17 @@ macro_rules! get_2_unsigned {
 
 #[macro_export]
 macro_rules! get_2_ints {
-  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:literal) => {{
+  ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE,$err:ident) => {{
     let stack = &mut $state.current().stack;
     let Some(v2) = stack.pop() else {
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w) };
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w) };
     let Some(v1) = stack.pop() else {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("TOO FEW ARGUMENTS", $w);
+      return $state_token.eval_error($crate::ErrorKind::TooFewArguments, $w);
     };
     let stack1 = v1.value_stack_ref();
     if stack1.len() != 1 {
@@ -1269,7 +1299,7 @@ macro_rules! get_2_ints {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_val1 = &stack1[0];
     let $crate::Value::Word(vword1) = word_val1 else {
@@ -1277,7 +1307,7 @@ macro_rules! get_2_ints {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     let stack2 = v2.value_stack_ref();
     if stack2.len() != 1 {
@@ -1285,7 +1315,7 @@ macro_rules! get_2_ints {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     }
     let word_val2 = &stack2[0];
     let $crate::Value::Word(vword2) = word_val2 else {
@@ -1293,14 +1323,14 @@ macro_rules! get_2_ints {
       stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("BAD ARGUMENT TYPE", $w)
+      return $state_token.eval_error($crate::ErrorKind::BadArgumentType, $w)
     };
     let Some(math) = $state.get_math() else {
       $state.current().stack.push(v1);
       $state.current().stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     };
     if math.math().base() == 0 {
       $state.set_math(math);
@@ -1308,7 +1338,7 @@ macro_rules! get_2_ints {
       $state.current().stack.push(v2);
       let $state_pat = $state;
       $fail;
-      return $state_token.eval_error("MATH BASE ZERO", $w)
+      return $state_token.eval_error($crate::ErrorKind::MathBaseZero, $w)
     }
     let i1 = match math.math().stoi(&vword1.str_word) {
       Ok(i) => if i > <$type>::MAX as isize || i < <$type>::MIN as isize {
@@ -1317,7 +1347,7 @@ macro_rules! get_2_ints {
         $state.current().stack.push(v2);
         let $state_pat = $state;
         $fail;
-        return $state_token.eval_error($err, $w)
+        return $state_token.eval_error($crate::ErrorKind::$err, $w)
       } else { i as $type },
       Err(e) => {
         $state.set_math(math);
@@ -1335,7 +1365,7 @@ macro_rules! get_2_ints {
         $state.current().stack.push(v2);
         let $state_pat = $state;
         $fail;
-        return $state_token.eval_error($err, $w)
+        return $state_token.eval_error($crate::ErrorKind::$err, $w)
       } else { i as $type },
       Err(e) => {
         $state.set_math(math);
@@ -1352,7 +1382,7 @@ macro_rules! get_2_ints {
     (i1, i2)
   }};
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty,ACTIVE) => {
-    get_2_ints!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, "OUT OF BOUNDS")
+    get_2_ints!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE, OutOfBounds)
   };
   ($state:ident,$w:ident,$state_token:tt,$state_pat:pat,$fail:block,$type:ty) => {{
     let (i1, i2) = get_2_ints!($state, $w, $state_token, $state_pat, $fail, $type, ACTIVE);
@@ -4,7 +4,7 @@
 use std::process::ExitCode;
 use std::env;
 use std::fs::{self, File};
-use std::io::stdin;
+use std::io::{stdin, BufWriter, Write};
 
 use cognition::*;
 
@@ -51,10 +51,12 @@ fn main() -> ExitCode {
     }
   } else { None };
 
+  let capabilities = if opts.sandbox { opts.allow.unwrap_or_else(Capabilities::none) } else { Capabilities::all() };
+
   // Initialize state
   let mut state = match opts.load {
     Some(ref loadfile) => {
-      match load(loadfile, opts.format.as_ref(), opts.fllibs.as_ref(), opts.suppress_fllibs) {
+      match load(loadfile, opts.format.as_ref(), opts.fllibs.as_ref(), opts.suppress_fllibs, capabilities) {
         Ok(state) => state,
         Err(e) => return e
       }
@@ -62,11 +64,12 @@ fn main() -> ExitCode {
     None => {
       let metastack = Stack::with_capacity(DEFAULT_STACK_SIZE);
       let mut state = CognitionState::new(metastack);
+      state.capabilities = capabilities;
       let mut vstack = Box::new(VStack::with_capacity(DEFAULT_STACK_SIZE));
       vstack.container.faliases = Container::default_faliases();
       state.stack.push(Value::Stack(vstack));
       for arg in args[(opts.fileidx + sources)..].iter() {
-        state.args.push(Value::Word(Box::new(VWord{ str_word: arg.clone() })));
+        state.args.push(Value::Word(Box::new(VWord::with_string(arg.clone()))));
       }
       builtins::add_builtins(&mut state);
       state
@@ -76,6 +79,38 @@ fn main() -> ExitCode {
   if state.parser.is_none() {
     state.parser = Some(Parser::new(None, None));
   }
+  let mut debugger = if opts.debug { Some(debugger::Debugger::new(&mut state)) } else { None };
+  if let Some(ref tracefile) = opts.trace {
+    match File::create(tracefile) {
+      Ok(f) => state.tracer = Some(Box::new(FileTracer{ file: BufWriter::new(f) })),
+      Err(e) => {
+        println!("{}: could not open trace file: {}: {e}", binary_name(), tracefile);
+        return ExitCode::from(4)
+      }
+    }
+  }
+  let mut profile_files = Vec::with_capacity(2);
+  for (file, collapsed) in [(&opts.profile, false), (&opts.flamegraph, true)] {
+    let Some(file) = file else { continue };
+    match File::create(file) {
+      Ok(f) => profile_files.push((BufWriter::new(f), collapsed)),
+      Err(e) => {
+        println!("{}: could not open profile file: {}: {e}", binary_name(), file);
+        return ExitCode::from(4)
+      }
+    }
+  }
+  if !profile_files.is_empty() { state.profiler = Some(profiler::Profiler::new()) }
+  state.locate_words = !opts.no_locations;
+  state.limits = Limits{ fuel: opts.fuel, depth: opts.max_depth, stack: opts.max_stack };
+
+  // The first SIGINT interrupts the current evaluation. A second one before
+  // the evaluator has seen the first terminates as usual.
+  let sigint = signal_hook::consts::SIGINT;
+  if let Err(e) = signal_hook::flag::register_conditional_shutdown(sigint, 130, state.interrupt.clone())
+    .and_then(|_| signal_hook::flag::register(sigint, state.interrupt.clone())) {
+    println!("{}: could not handle interrupts: {e}", binary_name());
+  }
 
   let mut idx = 0;
   'inputs: loop {
@@ -84,6 +119,8 @@ fn main() -> ExitCode {
     let file = &args[opts.fileidx + idx];
     let (source, filename, stdin) = if *file == "-" {
       let Some(Ok(s)) = stdin().lines().next() else { break 'inputs };
+      // a SIGINT while waiting for the line had nothing to interrupt
+      state.interrupt.store(false, std::sync::atomic::Ordering::Relaxed);
       (s, None, true)
     } else {
       let mut filename = None;
@@ -118,10 +155,19 @@ fn main() -> ExitCode {
       match w {
         Some(v) => {
           if let Some(f) = &mut logfile { v.fprint(f, "\n", false) }
-          state = state.eval(v, None)
+          state = match debugger {
+            Some(ref mut debugger) => debugger.eval(state, v),
+            None => state.eval(v, None)
+          }
         },
         None => break,
       }
+      if state.interrupted {
+        state.interrupted = false;
+        state.exited = false;
+        state.control.clear();
+        continue
+      }
       if state.control.is_return() {
         if stdin { idx += 1 }
         break
@@ -133,6 +179,15 @@ fn main() -> ExitCode {
 
   let end = opts.end.unwrap_or_else(|| End::with(true));
   if !opts.quiet { print_end(&state, end); }
+  if let Some(mut profiler) = state.profiler.take() {
+    profiler.finish();
+    for (mut f, collapsed) in profile_files {
+      let result = if collapsed { profiler.collapsed(&mut f) } else { profiler.report(&mut f, &state.pool) };
+      if let Err(e) = result.and_then(|_| f.flush()) {
+        println!("{}: could not write profile: {e}", binary_name());
+      }
+    }
+  }
   if let Some(ref save_fn) = save_fn {
     return cogsave(&state, opts.save.as_ref().unwrap(), *save_fn)
   }
@@ -146,20 +201,89 @@ fn main() -> ExitCode {
   ExitCode::SUCCESS
 }
 
+struct FileTracer {
+  file: BufWriter<File>
+}
+
+impl FileTracer {
+  // one line per event, indented by evaluation depth
+  fn event(&mut self, state: &CognitionState, event: &str, v: Option<&Value>, detail: &str) {
+    let f = &mut self.file;
+    let _ = write!(f, "{:1$}{event}", "", state.depth * 2);
+    if let Some(v) = v {
+      let _ = write!(f, " ");
+      v.fprint(f, "", false);
+    }
+    let _ = write!(f, "{detail}");
+    if let Some(ref parser) = state.parser {
+      let filename = parser.filename_ref().map_or("-", |s| s.as_str());
+      let _ = write!(f, " @ {filename}:{}:{}", parser.line(), parser.column());
+    }
+    let _ = writeln!(f);
+  }
+}
+
+impl Tracer for FileTracer {
+  fn value(&mut self, state: &CognitionState, v: &Value) {
+    let crank = match state.current_ref().cranks.as_ref().and_then(|c| c.first()) {
+      Some(c) => format!(" crank {}/{}", c.modulo, c.base),
+      None => " crank 0".to_string()
+    };
+    self.event(state, "value", Some(v), &crank)
+  }
+  fn lookup(&mut self, state: &CognitionState, word: &Value, family: Option<usize>) {
+    match family {
+      Some(i) => self.event(state, "lookup", Some(word), &format!(" in family {i}")),
+      None => self.event(state, "lookup", Some(word), " in current")
+    }
+  }
+  fn enter(&mut self, state: &CognitionState, wd: &Value, callword: Option<&Value>, tail: bool) {
+    let mut def = b" := ".to_vec();
+    wd.fprint(&mut def, "", false);
+    self.event(state, if tail { "tail" } else { "enter" }, callword, &String::from_utf8_lossy(&def))
+  }
+  fn exit(&mut self, state: &CognitionState, callword: Option<&Value>) {
+    self.event(state, "exit", callword, "")
+  }
+  fn fllib(&mut self, state: &CognitionState, v: &Value) {
+    self.event(state, "fllib", Some(v), "")
+  }
+  fn evalf(&mut self, state: &CognitionState, wd: &Value) {
+    self.event(state, "evalf", Some(wd), "")
+  }
+  fn crank(&mut self, state: &CognitionState, wd: &Value) {
+    self.event(state, "crank", Some(wd), "")
+  }
+  fn error(&mut self, state: &CognitionState, verror: &VError) {
+    let word = verror.str_word.as_deref().unwrap_or("(none)");
+    self.event(state, "error", None, &format!(" {word}: {}", verror.error))
+  }
+}
+
 struct Config {
   help: bool,
+  allow: Option<Capabilities>,
+  debug: bool,
   coglib: Option<String>,
   end: Option<End>,
   format: Option<String>,
   list_formats: bool,
   fllibs: Option<String>,
+  flamegraph: Option<String>,
+  fuel: Option<usize>,
+  max_depth: Option<usize>,
+  max_stack: Option<usize>,
   suppress_fllibs: bool,
   logfile: Option<String>,
   load: Option<String>,
+  no_locations: bool,
+  profile: Option<String>,
   quiet: bool,
   sources: i32,
+  sandbox: bool,
   save: Option<String>,
   save_format: Option<String>,
+  trace: Option<String>,
   version: bool,
   usage: bool,
   fileidx: usize
@@ -169,18 +293,28 @@ impl Config {
   pub fn default() -> Self {
     Self {
       help: false,
+      allow: None,
+      debug: false,
       coglib: None,
       end: None,
       format: None,
       list_formats: false,
       fllibs: None,
+      flamegraph: None,
+      fuel: None,
+      max_depth: None,
+      max_stack: None,
       suppress_fllibs: false,
       logfile: None,
       load: None,
+      no_locations: false,
+      profile: None,
       quiet: false,
       sources: -1,
+      sandbox: false,
       save: None,
       save_format: None,
+      trace: None,
       version: false,
       usage: false,
       fileidx: 0
@@ -258,6 +392,34 @@ fn parse_end(args: &Vec<String>, argc: usize, i: usize, end: bool) -> Result<(us
   Ok((i + 1, Some(new_end)))
 }
 
+fn parse_limit(args: &[String], argc: usize, i: usize, limit: Option<usize>, name: &str) -> Result<(usize, Option<usize>), ExitCode> {
+  if limit.is_some() { return Err(usage_help(1)) }
+  else if i + 1 == argc { return Err(usage_help(3)) }
+  match args[i + 1].parse::<usize>() {
+    Ok(int) => Ok((i + 1, Some(int))),
+    Err(_) => {
+      println!("{}: {name}: invalid argument", binary_name());
+      Err(try_help(3))
+    }
+  }
+}
+
+fn parse_allow(args: &[String], argc: usize, i: usize, allow: Option<Capabilities>) -> Result<(usize, Option<Capabilities>), ExitCode> {
+  if allow.is_some() { return Err(usage_help(1)) }
+  else if i + 1 == argc { return Err(usage_help(3)) }
+  let mut caps = Capabilities::none();
+  for name in args[i + 1].split(',') {
+    match Capability::from_name(name) {
+      Some(cap) => caps = caps.allow(cap),
+      None => {
+        println!("{}: allow: unknown capability: {name}", binary_name());
+        return Err(try_help(3))
+      }
+    }
+  }
+  Ok((i + 1, Some(caps)))
+}
+
 macro_rules! define_config_parsers {
   ($set_bool:tt,$set_str:tt,$args:ident,$argc:ident) => {
     let $set_bool = |boolval| if boolval { Err(usage_help(1)) } else { Ok(true) };
@@ -336,9 +498,12 @@ fn parse_configs(args: &Vec<String>, argc: usize) -> Result<Config, ExitCode> {
     let slice = args[i].as_str();
     match slice {
       "--help"            => config.help             = set_bool(config.help)?,
+      "--debug"           => config.debug            = set_bool(config.debug)?,
       "--list-formats"    => config.list_formats     = set_bool(config.list_formats)?,
+      "--no-locations"    => config.no_locations     = set_bool(config.no_locations)?,
       "--suppress-fllibs" => config.suppress_fllibs  = set_bool(config.suppress_fllibs)?,
       "--quiet"           => config.quiet            = set_bool(config.quiet)?,
+      "--sandbox"         => config.sandbox          = set_bool(config.sandbox)?,
       "--usage"           => config.usage            = set_bool(config.usage)?,
       "--version"         => config.version          = set_bool(config.version)?,
 
@@ -349,8 +514,15 @@ fn parse_configs(args: &Vec<String>, argc: usize) -> Result<Config, ExitCode> {
       "--load"            => (i, config.load)        = set_str(i, &config.load)?,
       "--save"            => (i, config.save)        = set_str(i, &config.save)?,
       "--save-format"     => (i, config.save_format) = set_str(i, &config.save_format)?,
+      "--trace"           => (i, config.trace)       = set_str(i, &config.trace)?,
+      "--profile"         => (i, config.profile)     = set_str(i, &config.profile)?,
+      "--flamegraph"      => (i, config.flamegraph)  = set_str(i, &config.flamegraph)?,
       "--end"             => (i, config.end)         = parse_end(args, argc, i, config.end.is_some())?,
       "--sources"         => (i, config.sources)     = parse_sources(args, argc, i, config.sources)?,
+      "--fuel"            => (i, config.fuel)        = parse_limit(args, argc, i, config.fuel, "fuel")?,
+      "--max-depth"       => (i, config.max_depth)   = parse_limit(args, argc, i, config.max_depth, "max-depth")?,
+      "--max-stack"       => (i, config.max_stack)   = parse_limit(args, argc, i, config.max_stack, "max-stack")?,
+      "--allow"           => (i, config.allow)       = parse_allow(args, argc, i, config.allow)?,
 
       _ => if parse_other(args, argc, &mut i, &mut config)? { break }
     }
@@ -362,6 +534,8 @@ fn parse_configs(args: &Vec<String>, argc: usize) -> Result<Config, ExitCode> {
   }
   if config.load.is_some() && config.list_formats { return Err(usage_help(1)) }
   if config.save.is_none() && config.save_format.is_some() { return Err(usage_help(1)) }
+  if config.debug && config.trace.is_some() { return Err(usage_help(1)) }
+  if config.allow.is_some() && !config.sandbox { return Err(usage_help(1)) }
   if config.sources < 0 { config.sources = 1 }
 
   Ok(config)
@@ -369,7 +543,7 @@ fn parse_configs(args: &Vec<String>, argc: usize) -> Result<Config, ExitCode> {
 
 fn binary_name() -> String { if let Some(n) = env::args().next() { n } else { "crank".to_string() } }
 
-const OPTIONS: &str = "[-hquv] [-e [sefpcmFPCE]] [-l FILE] [-L FILE [-f FORMAT] [-F FILE] [--suppress-fllibs] -S FILE [--save-format FORMAT] | --list-formats]";
+const OPTIONS: &str = "[-hquv] [-e [sefpcmFPCE]] [--debug | --trace FILE] [--profile FILE] [--flamegraph FILE] [--fuel N] [--max-depth N] [--max-stack N] [--no-locations] [--sandbox [--allow CAPS]] [-l FILE] [-L FILE [-f FORMAT] [-F FILE] [--suppress-fllibs] -S FILE [--save-format FORMAT] | --list-formats]";
 
 fn usage() -> ExitCode {
   println!("Usage: {} {OPTIONS} [-s N] [file...] [arg...]", binary_name());
@@ -392,23 +566,36 @@ fn help() -> ExitCode {
   println!("");
   println!("Options:");
   println!(" -h, --help                print this help message");
+  println!("     --allow CAPS          with '--sandbox', allow the comma-separated capabilities CAPS:");
+  println!("                             fs-read, fs-write, fllib, process and env");
+  println!("     --debug               step through evaluation interactively (see 'help' at the prompt)");
   println!(" -c, --coglib-dir DIR      use DIR as a secondary source directory");
   println!(" -e, --end [sefpcmFPCE]    select information to display at program end");
   println!(" -f, --format FORMAT       use FORMAT as the load format (see '--load')");
   println!("                             FORMAT can optionally be a comma-separated pair: '-f|--format LOAD_FORMAT,FLLIBS_FORMAT'");
   println!("                             to specify a different format for an optional fllib description file (see '--fllibs')");
+  println!("     --flamegraph FILE     write profiled call stacks to FILE in collapsed stack format");
+  println!("     --fuel N              stop evaluating after N steps");
   println!("     --list-formats        print a list of supported load formats");
   println!(" -F, --fllibs FILE         supplement cognition load file with an fllib description file");
   println!("                             (only used in combination with '--load')");
   println!("     --suppress-fllibs     suppress automatic interpretation of fllibs with '--load'");
   println!(" -l, --log-file FILE       enable token logging to FILE");
+  println!("     --max-depth N         stop evaluating when nested more than N evaluations deep");
+  println!("     --max-stack N         stop evaluating when the metastack holds more than N values");
+  println!("                             (values nested inside them are not counted)");
+  println!("     --no-locations        don't record where words were parsed (errors then point at the parser position)");
   println!(" -L, --load FILE           load cognition state from FILE, attempting to infer format from file extension");
   println!("                             for a list of supported formats and extensions, see '--list-formats'");
+  println!("     --profile FILE        write call counts and timings for each word to FILE at exit");
   println!(" -q, --quiet               don't show state information at program end");
+  println!("     --sandbox             deny scripts access to files, fllibs, processes and the environment");
+  println!("                             except as allowed by '--allow'");
   println!(" -s, --sources N           specify N source files to be composed (default is N=1)");
   println!("                             not compatible with '-'");
   println!(" -S, --save FILE           save cognition state to FILE on program exit");
   println!("     --save-format FORMAT  explicitly set save format");
+  println!("     --trace FILE          write a trace of evaluation events to FILE");
   println!(" -u, --usage               print usage information");
   println!(" -v, --version             print version information");
   ExitCode::SUCCESS
@@ -441,13 +628,13 @@ fn load_result(result: CognitionDeserializeResult) -> Result<CognitionState, Exi
   match result {
     Ok(state) => Ok(state),
     Err(e) => {
-      println!("{}: load: {}", binary_name(), e.1);
+      println!("{}: load: {}", binary_name(), e.2);
       Err(ExitCode::from(5))
     }
   }
 }
 
-fn load(loadfile: &String, format: Option<&String>, fllibs: Option<&String>, suppress_fllibs: bool) -> Result<CognitionState, ExitCode> {
+fn load(loadfile: &String, format: Option<&String>, fllibs: Option<&String>, suppress_fllibs: bool, capabilities: Capabilities) -> Result<CognitionState, ExitCode> {
   let formats = match format {
     Some(fmt) => {
       let v: Vec<&str> = fmt.split(',').collect();
@@ -460,6 +647,7 @@ fn load(loadfile: &String, format: Option<&String>, fllibs: Option<&String>, sup
     None => None,
   };
   let mut state = cognition::serde::cogstate_init();
+  state.capabilities = capabilities;
   let mut ignore_fllibs = false;
 
   if let Some(fllibs) = fllibs {
@@ -580,6 +768,7 @@ fn print_end(state: &CognitionState, e: End) {
     println!("");
     println!("Math:");
     println!("base: {}", math.map_or(0, |m| m.base()));
+    println!("precision: {}", math.map_or(0, |m| m.precision()));
     print!("digits: ");
     if math.map_or(0, |m| m.get_digits().len()) > 0 {
       for d in math.unwrap().get_digits() {
@@ -1,11 +1,21 @@
 use crate::*;
+use std::cmp::Ordering;
 use ::serde::{Serialize, Deserialize};
 
-use std::str::Chars;
-//use std::iter::Rev;
-//use std::slice::IterMut;
+pub mod int;
+pub mod fixed;
+pub mod chebyshev;
+pub mod compound;
+
+pub use int::Int;
+pub use fixed::Fixed;
+pub use chebyshev::Chebyshev;
+pub use compound::Compound;
 
 pub const BASE_MAX: i32 = 0x110000 - (0xE000 - 0xD800);
+pub const UNARY_LIMB_BASE: i32 = 1 << 15;
+/// Largest number of integer digits exp, shl and pow may produce
+pub const DIGITS_MAX: f64 = 65536.0;
 
 pub type Digits = Vec<Digit>;
 pub type UnaryOp = HashMap<Digit, Digit>;
@@ -43,6 +53,28 @@ impl Operand {
   pub fn is_quaternary(&self) -> bool { if let Self::Quaternary(_, _, _, _) = self { true } else { false } }
   pub fn is_arbitrary(&self) -> bool { if let Self::Arbitrary(_) = self { true } else { false } }
 
+  pub fn from_digits(v: &[Digit]) -> Operand {
+    match v {
+      [a] => Self::Unary(a.clone()),
+      [a, b] => Self::Binary(a.clone(), b.clone()),
+      [a, b, c] => Self::Ternary(a.clone(), b.clone(), c.clone()),
+      [a, b, c, d] => Self::Quaternary(a.clone(), b.clone(), c.clone(), d.clone()),
+      _ => Self::Arbitrary(v.to_vec()),
+    }
+  }
+  pub fn len(&self) -> usize {
+    match self {
+      Self::Unary(_) => 1,
+      Self::Binary(_, _) => 2,
+      Self::Ternary(_, _, _) => 3,
+      Self::Quaternary(_, _, _, _) => 4,
+      Self::Arbitrary(v) => v.len(),
+    }
+  }
+  pub fn is_empty(&self) -> bool {
+    if let Self::Arbitrary(v) = self { v.is_empty() } else { false }
+  }
+
   // pub fn first(&self) -> char {
   //   match self {
   //     Self::Unary(u) => u.clone(),
@@ -118,33 +150,6 @@ impl Operand {
   // }
 }
 
-// macro_rules! push_next {
-//   ($c:ident,$iter:ident,$tmp:ident,$negc:expr,$radix:expr,$delim:expr) => {
-//     if $c == $negc {
-//       let Some(c) = $iter.next() else { return Some("INVALID NUMBER STRING") };
-//       $tmp.push(Digit{ digit: c, neg: true }); (true, false)
-//     }
-//     else if $c == $radix { (false, true) }
-//     else if $c == $delim { (false, false) }
-//     else { $tmp.push(Digit{ digit: $c, neg: false }); (true, false) }
-//   };
-// }
-// macro_rules! push_next_int {
-//   ($self:ident,$c:ident,$iter:ident,$tmp:ident,$negc:expr,$radix:expr,$delim:expr) => {
-//     if $c == $negc {
-//       let Some(c) = $iter.next() else { return Err("INVALID NUMBER STRING") };
-//       let Some(i) = $self.d_idx.get(&c) else { return Err("INVALID NUMBER STRING") };
-//       $tmp.push(-(*i)); (true, false)
-//     }
-//     else if $c == $radix { (false, true) }
-//     else if $c == $delim { (false, false) }
-//     else {
-//       let Some(i) = $self.d_idx.get(&$c) else { return Err("INVALID NUMBER STRING") };
-//       $tmp.push(*i); (true, false)
-//     }
-//   };
-// }
-
 pub struct Math {
   pub base: i32,
   pub digits: Vec<char>,
@@ -158,6 +163,9 @@ pub struct Math {
   pub delim: Option<char>,
   pub meta_radix: Option<char>,
   pub meta_delim: Option<char>,
+
+  pub precision: usize,
+  pub chebyshev: Chebyshev,
 }
 
 impl Math {
@@ -172,7 +180,9 @@ impl Math {
       radix: None,
       delim: None,
       meta_radix: None,
-      meta_delim: None
+      meta_delim: None,
+      precision: 0,
+      chebyshev: Chebyshev::new()
     }
   }
 
@@ -187,7 +197,9 @@ impl Math {
       radix: None,
       delim: None,
       meta_radix: None,
-      meta_delim: None
+      meta_delim: None,
+      precision: 0,
+      chebyshev: Chebyshev::new()
     }
   }
 
@@ -207,6 +219,8 @@ impl Math {
     self.delim = None;
     self.meta_radix = None;
     self.meta_delim = None;
+    self.precision = 0;
+    self.chebyshev.clean(p);
   }
 
   // Assumes that the new math is clean
@@ -263,6 +277,8 @@ impl Math {
     math.delim = self.delim.clone();
     math.meta_radix = self.meta_radix.clone();
     math.meta_delim = self.meta_delim.clone();
+    math.precision = self.precision;
+    self.chebyshev.copy_into(&mut math.chebyshev, &mut state.pool);
   }
 
   pub fn set_digits(&mut self, s: &String) {
@@ -294,43 +310,57 @@ impl Math {
   pub fn get_meta_radix (&self) -> Option<char> { self.meta_radix }
   pub fn get_meta_delim (&self) -> Option<char> { self.meta_delim }
 
-  pub fn unset_negc (&mut self) -> Option<&'static str> {
-    if self.base != 0 { return Some("MATH BASE NONZERO") }
+  pub fn unset_negc (&mut self) -> Option<ErrorKind> {
+    if self.base != 0 { return Some(ErrorKind::MathBaseNonzero) }
     self.negc = None; None
   }
-  pub fn unset_radix(&mut self) -> Option<&'static str> {
-    if self.base != 0 { return Some("MATH BASE NONZERO") }
+  pub fn unset_radix(&mut self) -> Option<ErrorKind> {
+    if self.base != 0 { return Some(ErrorKind::MathBaseNonzero) }
     self.radix = None; None
   }
-  pub fn unset_delim(&mut self) -> Option<&'static str> {
-    if self.base != 0 { return Some("MATH BASE NONZERO") }
+  pub fn unset_delim(&mut self) -> Option<ErrorKind> {
+    if self.base != 0 { return Some(ErrorKind::MathBaseNonzero) }
     self.delim = None; None
   }
-  pub fn unset_meta_radix(&mut self) -> Option<&'static str> {
-    if self.base != 0 { return Some("MATH BASE NONZERO") }
+  pub fn unset_meta_radix(&mut self) -> Option<ErrorKind> {
+    if self.base != 0 { return Some(ErrorKind::MathBaseNonzero) }
     self.meta_radix = None; None
   }
-  pub fn unset_meta_delim(&mut self) -> Option<&'static str> {
-    if self.base != 0 { return Some("MATH BASE NONZERO") }
+  pub fn unset_meta_delim(&mut self) -> Option<ErrorKind> {
+    if self.base != 0 { return Some(ErrorKind::MathBaseNonzero) }
     self.meta_delim = None; None
   }
 
-  pub fn set_base(&mut self, base: i32) -> Option<&'static str> {
-    if self.digits.len() < (base / 2 + 1) as usize { return Some("MATH DIGITS UNINITIALIZED") }
-    if self.negc.is_none() { return Some("MATH NEGC UNINITIALIZED") }
-    if self.radix.is_none() { return Some("MATH RADIX UNINITIALIZED") }
-    if self.delim.is_none() { return Some("MATH DELIM UNINITIALIZED") }
-    if self.meta_radix.is_none() { return Some("MATH METARADIX UNINITIALIZED") }
-    if self.meta_delim.is_none() { return Some("MATH METADELIM UNINITIALIZED") }
+  pub fn set_base(&mut self, base: i32) -> Option<ErrorKind> {
+    if self.digits.len() < (base / 2 + 1) as usize { return Some(ErrorKind::MathDigitsUninitialized) }
+    if self.negc.is_none() { return Some(ErrorKind::MathNegcUninitialized) }
+    if self.radix.is_none() { return Some(ErrorKind::MathRadixUninitialized) }
+    if self.delim.is_none() { return Some(ErrorKind::MathDelimUninitialized) }
+    if self.meta_radix.is_none() { return Some(ErrorKind::MathMetaRadixUninitialized) }
+    if self.meta_delim.is_none() { return Some(ErrorKind::MathMetaDelimUninitialized) }
     self.base = base;
     self.mul.drain();
     self.init_mul();
-    // TODO: init chebyshev polynomial coefficients
+    self.init_chebyshev();
     None
   }
 
+  /// Builds the Chebyshev coefficient tables for the current base
+  pub fn init_chebyshev(&mut self) {
+    let mut pool = Pool::new();
+    if self.base > 1 {
+      self.chebyshev.init(Chebyshev::default_scale(self.base), self.base, &mut pool)
+    } else {
+      self.chebyshev.clean(&mut pool)
+    }
+  }
+
   pub fn base(&self) -> i32 { self.base }
 
+  /// Number of fractional digits kept by inexact operations
+  pub fn set_precision(&mut self, precision: usize) { self.precision = precision }
+  pub fn precision(&self) -> usize { self.precision }
+
   fn init_mul(&mut self) {
     if self.base == 0 { return }
     let radius = self.base / 2 + 1;
@@ -359,9 +389,9 @@ impl Math {
     // }
   }
 
-  // pub fn len(&self, s: &str) -> Result<usize, &'static str> {
-  //   let Some(ref negc) = self.negc else { return Err("MATH NEGC UNINITIALIZED") };
-  //   let Some(ref radix) = self.radix else { return Err("MATH RADIX UNINITIALIZED") };
+  // pub fn len(&self, s: &str) -> Result<usize, ErrorKind> {
+  //   let Some(ref negc) = self.negc else { return Err(ErrorKind::MathNegcUninitialized) };
+  //   let Some(ref radix) = self.radix else { return Err(ErrorKind::MathRadixUninitialized) };
   //   let mut l: usize = 0;
   //   let mut s_iter = s.chars();
   //   loop {
@@ -373,53 +403,208 @@ impl Math {
   //   }
   // }
 
+  /// Radix of the limbs of the Ints produced by this Math. Tally (base one)
+  /// numbers are held in an arbitrary internal radix.
+  pub fn limb_base(&self) -> i32 {
+    if self.base > 1 { self.base } else { UNARY_LIMB_BASE }
+  }
+
   /// Converts a string to a signed integer
-  pub fn stoi(&self, s: &str) -> Result<isize, &'static str> {
-    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err("MATH NEGC UNINITIALIZED") };
-    let mut iter = s.chars();
-    let Some(mut c) = iter.next() else { return Ok(0) };
+  pub fn stoi(&self, s: &str) -> Result<isize, ErrorKind> {
+    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err(ErrorKind::MathNegcUninitialized) };
+    if self.base == 1 { return self.tally(s).map(|(p, n)| p as isize - n as isize) }
+    let mut iter = s.chars().peekable();
     let mut agg: isize = 0;
-    loop {
-      let Some(c_new) = iter.next() else {
-        let Some(i) = self.d_idx.get(&c) else { return Err("INVALID NUMBER STRING") };
-        agg = agg * self.base as isize + *i as isize;
-        break
+    while let Some(c) = iter.next() {
+      let Some(i) = self.d_idx.get(&c) else { return Err(ErrorKind::InvalidNumberString) };
+      let i = if iter.next_if_eq(&negc).is_some() { -(*i as isize) } else { *i as isize };
+      let Some(a) = agg.checked_mul(self.base as isize) else { return Err(ErrorKind::OutOfBounds) };
+      let Some(a) = a.checked_add(i) else { return Err(ErrorKind::OutOfBounds) };
+      agg = a;
+    }
+    Ok(agg)
+  }
+  /// Converts a signed integer to a string
+  pub fn itos(&self, i: isize, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 0 { return Err(ErrorKind::MathBaseZero) };
+    let int = Int::from_isize(i, self.limb_base(), &mut state.pool);
+    let result = self.inttos(&int, state);
+    int.clean(&mut state.pool);
+    result
+  }
+
+  /// Counts the positive and negative tally marks of a base one number string
+  fn tally(&self, s: &str) -> Result<(usize, usize), ErrorKind> {
+    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err(ErrorKind::MathNegcUninitialized) };
+    let mark = self.digits[0];
+    let (mut p, mut n) = (0, 0);
+    let mut iter = s.chars().peekable();
+    while let Some(c) = iter.next() {
+      if c != mark { return Err(ErrorKind::InvalidNumberString) }
+      if iter.next_if_eq(&negc).is_some() { n += 1 } else { p += 1 }
+    }
+    Ok((p, n))
+  }
+
+  /// Converts a string to an arbitrary precision integer
+  pub fn stoint(&self, s: &str, state: &mut CognitionState) -> Result<Int, ErrorKind> {
+    let fixed = self.stofixed(s, state)?;
+    if fixed.scale != 0 {
+      fixed.clean(&mut state.pool);
+      return Err(ErrorKind::InvalidNumberString)
+    }
+    Ok(fixed.int)
+  }
+
+  /// Converts a string, possibly containing the radix character, to a fixed
+  /// point number
+  pub fn stofixed(&self, s: &str, state: &mut CognitionState) -> Result<Fixed, ErrorKind> {
+    if self.base == 0 { return Err(ErrorKind::MathBaseZero) }
+    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err(ErrorKind::MathNegcUninitialized) };
+    if self.base == 1 {
+      let (p, n) = self.tally(s)?;
+      return Ok(Fixed::from_int(Int::from_isize(p as isize - n as isize, self.limb_base(), &mut state.pool)))
+    }
+    // most significant digit first
+    let mut ints = state.pool.get_ints(s.len());
+    let mut point = None;
+    let mut iter = s.chars().peekable();
+    while let Some(c) = iter.next() {
+      if Some(c) == self.radix && point.is_none() {
+        point = Some(ints.len());
+        continue
+      }
+      let Some(i) = self.d_idx.get(&c) else {
+        state.pool.add_ints(ints);
+        return Err(ErrorKind::InvalidNumberString)
       };
-      let Some(i) = self.d_idx.get(&c) else { return Err("INVALID NUMBER STRING") };
-      if c_new == negc {
-        agg = agg * self.base as isize - *i as isize;
-        let Some(c_new) = iter.next() else { break };
-        c = c_new;
+      ints.push(if iter.next_if_eq(&negc).is_some() { -*i } else { *i });
+    }
+    if point.is_some() && ints.is_empty() {
+      state.pool.add_ints(ints);
+      return Err(ErrorKind::InvalidNumberString)
+    }
+    // balanced digits are split into a positive and a negative magnitude
+    let mut pos = Int::new(ints.len() + 1, &mut state.pool);
+    let mut neg = Int::new(ints.len() + 1, &mut state.pool);
+    let (mut pcarry, mut ncarry) = (0, 0);
+    for i in ints.iter().rev() {
+      let (p, n) = if *i < 0 { (pcarry, -*i + ncarry) } else { (*i + pcarry, ncarry) };
+      pos.limbs.push(p % self.base);
+      neg.limbs.push(n % self.base);
+      (pcarry, ncarry) = (p / self.base, n / self.base);
+    }
+    pos.limbs.push(pcarry);
+    neg.limbs.push(ncarry);
+    pos.trim();
+    neg.trim();
+    let mut fixed = Fixed{
+      int: pos.difference(&neg, self.base, &mut state.pool),
+      scale: point.map_or(0, |p| ints.len() - p)
+    };
+    fixed.trim();
+    pos.clean(&mut state.pool);
+    neg.clean(&mut state.pool);
+    state.pool.add_ints(ints);
+    Ok(fixed)
+  }
+
+  /// Whether the string is a compound numeral, containing either delimiter
+  pub fn is_compound(&self, s: &str) -> bool {
+    self.delim.is_some_and(|c| s.contains(c)) || self.meta_delim.is_some_and(|c| s.contains(c))
+  }
+
+  /// Converts a string to a compound numeral, splitting it into components
+  /// at the meta delimiter and the components into parts at the
+  /// Cayley-Dickson delimiter
+  pub fn stocompound(&self, s: &str, state: &mut CognitionState) -> Result<Compound, ErrorKind> {
+    let mut compound = Compound{ components: Vec::new() };
+    for c in s.split(|c| Some(c) == self.meta_delim) {
+      let mut parts = Vec::new();
+      for p in c.split(|c| Some(c) == self.delim) {
+        match self.stofixed(p, state) {
+          Ok(f) => parts.push(f),
+          Err(e) => {
+            for f in parts { f.clean(&mut state.pool) }
+            compound.clean(&mut state.pool);
+            return Err(e)
+          }
+        }
       }
-      else {
-        agg = agg * self.base as isize + *i as isize;
-        c = c_new;
+      compound.components.push(parts);
+    }
+    Ok(compound)
+  }
+
+  pub fn compoundtos(&self, compound: &Compound, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let mut s = state.pool.get_string(compound.components.len() * 2);
+    for (i, c) in compound.components.iter().enumerate() {
+      if i != 0 { s.push(self.meta_delim.ok_or(ErrorKind::MathMetaDelimUninitialized)?) }
+      for (j, f) in c.iter().enumerate() {
+        if j != 0 { s.push(self.delim.ok_or(ErrorKind::MathDelimUninitialized)?) }
+        let part = self.fixedtos(f, state)?;
+        s.push_str(&part);
+        state.pool.add_string(part);
       }
     }
-    Ok(agg)
+    Ok(s)
   }
-  /// Converts a signed integer to a string
-  pub fn itos(&self, mut i: isize, state: &mut CognitionState) -> Result<String, &'static str> {
-    if self.base == 0 { return Err("MATH BASE ZERO") };
-    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err("MATH NEGC UNINITIALIZED") };
-    if i == 0 { return Ok(state.pool.get_string(0)) }
-    if self.base == 1 { return Err("MATH BASE ONE") };
+
+  /// Converts an arbitrary precision integer to a string
+  pub fn inttos(&self, int: &Int, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    self.format(int, 0, state)
+  }
+
+  /// Converts a fixed point number to a string, using the radix character
+  /// if it has a fractional part
+  pub fn fixedtos(&self, fixed: &Fixed, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    self.format(&fixed.int, fixed.scale, state)
+  }
+
+  fn format(&self, int: &Int, scale: usize, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 0 { return Err(ErrorKind::MathBaseZero) }
+    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err(ErrorKind::MathNegcUninitialized) };
+    if self.base == 1 {
+      let Some(i) = int.to_isize(self.limb_base()) else { return Err(ErrorKind::OutOfBounds) };
+      let mark = self.digits[0];
+      let mut result = state.pool.get_string(i.unsigned_abs() * (mark.len_utf8() + negc.len_utf8()));
+      for _ in 0..i.unsigned_abs() {
+        result.push(mark);
+        if i < 0 { result.push(negc) }
+      }
+      return Ok(result)
+    }
+    let radix = if scale == 0 { None } else if let Some(c) = self.radix { Some(c) } else { return Err(ErrorKind::MathRadixUninitialized) };
     let radius = self.base / 2 + 1;
-    let mut digits = state.pool.get_digits(DEFAULT_STACK_SIZE);
-    let mut neg = i < 0;
-    let mut neg_count = 0;
-    while i != 0 {
-      let mut digit: i32 = (i % self.base as isize) as i32;
-      if digit < 0 { digit += self.base }
-      if digit >= radius || (digit == radius - 1 && !neg) {
-        neg = true; digit = self.base - digit; neg_count += 1
+    let even = self.base % 2 == 0;
+    let mut digits = state.pool.get_digits(int.limbs.len().max(scale) + 1);
+    let mut neg = false;
+    let mut carry = 0;
+    let mut limbs = int.limbs.iter();
+    loop {
+      let t = match limbs.next() {
+        Some(l) => *l + carry,
+        None if carry != 0 => carry,
+        None => break
+      };
+      let (mut digit, mut c) = (t % self.base, t / self.base);
+      let mut dneg = false;
+      // in even bases, a digit of value base/2 is negative unless the last nonzero digit was
+      if digit >= radius || (even && digit == radius - 1 && !neg) {
+        neg = true; dneg = true; digit = self.base - digit; c += 1
       } else if digit > 0 { neg = false }
-      i += { if neg { digit } else { -digit } } as isize;
-      i /= self.base as isize;
-      digits.push(Digit{ digit: self.digits[digit as usize], neg });
+      carry = c;
+      digits.push(Digit{ digit: self.digits[digit as usize], neg: digit != 0 && dneg != int.neg });
     }
-    let mut result = state.pool.get_string(digits.len() + neg_count);
+    while digits.len() < scale {
+      digits.push(Digit{ digit: self.digits[0], neg: false })
+    }
+    let neg_count = digits.iter().filter(|d| d.neg).count();
+    let mut result = state.pool.get_string(digits.len() + neg_count + 1);
     while let Some(d) = digits.pop() {
+      if let Some(r) = radix {
+        if digits.len() + 1 == scale { result.push(r) }
+      }
       result.push(d.digit);
       if d.neg { result.push(negc) }
     }
@@ -427,6 +612,341 @@ impl Math {
     Ok(result)
   }
 
+  /// Scale of the results of inexact operations on operands of the given scales
+  fn result_scale(&self, s1: usize, s2: usize) -> usize {
+    if self.base == 1 { 0 } else { self.precision.max(s1).max(s2) }
+  }
+
+  fn binary_int_op<F>(&self, s1: &str, s2: &str, state: &mut CognitionState, f: F) -> Result<String, ErrorKind>
+  where F: FnOnce(&Int, &Int, &mut Pool) -> Result<Int, ErrorKind>
+  {
+    let i1 = self.stoint(s1, state)?;
+    let i2 = match self.stoint(s2, state) {
+      Ok(i) => i,
+      Err(e) => { i1.clean(&mut state.pool); return Err(e) }
+    };
+    let result = f(&i1, &i2, &mut state.pool);
+    i1.clean(&mut state.pool);
+    i2.clean(&mut state.pool);
+    let int = result?;
+    let result = self.inttos(&int, state);
+    int.clean(&mut state.pool);
+    result
+  }
+
+  fn unary_int_op<F>(&self, s: &str, state: &mut CognitionState, f: F) -> Result<String, ErrorKind>
+  where F: FnOnce(&Int, &mut Pool) -> Result<Int, ErrorKind>
+  {
+    let i = self.stoint(s, state)?;
+    let result = f(&i, &mut state.pool);
+    i.clean(&mut state.pool);
+    let int = result?;
+    let result = self.inttos(&int, state);
+    int.clean(&mut state.pool);
+    result
+  }
+
+  fn binary_compound_op<F>(&self, s1: &str, s2: &str, state: &mut CognitionState, f: F) -> Result<String, ErrorKind>
+  where F: FnOnce(&Compound, &Compound, &mut Pool) -> Result<Compound, ErrorKind>
+  {
+    let c1 = self.stocompound(s1, state)?;
+    let c2 = match self.stocompound(s2, state) {
+      Ok(c) => c,
+      Err(e) => { c1.clean(&mut state.pool); return Err(e) }
+    };
+    let result = f(&c1, &c2, &mut state.pool);
+    c1.clean(&mut state.pool);
+    c2.clean(&mut state.pool);
+    let compound = result?;
+    let result = self.compoundtos(&compound, state);
+    compound.clean(&mut state.pool);
+    result
+  }
+
+  fn binary_fixed_op<F>(&self, s1: &str, s2: &str, state: &mut CognitionState, f: F) -> Result<String, ErrorKind>
+  where F: FnOnce(&Fixed, &Fixed, &mut Pool) -> Result<Fixed, ErrorKind>
+  {
+    let f1 = self.stofixed(s1, state)?;
+    let f2 = match self.stofixed(s2, state) {
+      Ok(f) => f,
+      Err(e) => { f1.clean(&mut state.pool); return Err(e) }
+    };
+    let result = f(&f1, &f2, &mut state.pool);
+    f1.clean(&mut state.pool);
+    f2.clean(&mut state.pool);
+    let fixed = result?;
+    let result = self.fixedtos(&fixed, state);
+    fixed.clean(&mut state.pool);
+    result
+  }
+
+  pub fn compare(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<Ordering, ErrorKind> {
+    let f1 = self.stofixed(s1, state)?;
+    let f2 = match self.stofixed(s2, state) {
+      Ok(f) => f,
+      Err(e) => { f1.clean(&mut state.pool); return Err(e) }
+    };
+    let ordering = f1.compare(&f2, &mut state.pool);
+    f1.clean(&mut state.pool);
+    f2.clean(&mut state.pool);
+    Ok(ordering)
+  }
+
+  /// Whether two numbers are equal. Compound numerals compare component-wise.
+  pub fn equal(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<bool, ErrorKind> {
+    if !self.is_compound(s1) && !self.is_compound(s2) {
+      return self.compare(s1, s2, state).map(|o| o == Ordering::Equal)
+    }
+    let c1 = self.stocompound(s1, state)?;
+    let c2 = match self.stocompound(s2, state) {
+      Ok(c) => c,
+      Err(e) => { c1.clean(&mut state.pool); return Err(e) }
+    };
+    let equal = c1.equal(&c2, &mut state.pool);
+    c1.clean(&mut state.pool);
+    c2.clean(&mut state.pool);
+    Ok(equal)
+  }
+
+  pub fn sum(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    if self.is_compound(s1) || self.is_compound(s2) {
+      return self.binary_compound_op(s1, s2, state, |c1, c2, pool| c1.sum(c2, radix, pool))
+    }
+    self.binary_fixed_op(s1, s2, state, |f1, f2, pool| Ok(f1.sum(f2, radix, pool)))
+  }
+  pub fn difference(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    if self.is_compound(s1) || self.is_compound(s2) {
+      return self.binary_compound_op(s1, s2, state, |c1, c2, pool| c1.difference(c2, radix, pool))
+    }
+    self.binary_fixed_op(s1, s2, state, |f1, f2, pool| Ok(f1.difference(f2, radix, pool)))
+  }
+  /// Product, truncated toward zero to the precision if inexact. Compound
+  /// numerals multiply component-wise, with each pair of components
+  /// multiplied as Cayley-Dickson numbers.
+  pub fn product(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    if self.is_compound(s1) || self.is_compound(s2) {
+      return self.binary_compound_op(s1, s2, state, |c1, c2, pool| {
+        let mut c = c1.product(c2, radix, pool)?;
+        c.truncate(self.result_scale(c1.max_scale(), c2.max_scale()));
+        Ok(c)
+      })
+    }
+    self.binary_fixed_op(s1, s2, state, |f1, f2, pool| {
+      Ok(f1.product(f2, self.result_scale(f1.scale, f2.scale), radix, pool))
+    })
+  }
+  /// Quotient, truncated toward zero to the precision or to the scale of
+  /// the operands, whichever is larger. With precision zero, integers
+  /// divide as integers.
+  pub fn quotient(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_fixed_op(s1, s2, state, |f1, f2, pool| {
+      f1.quotient(f2, self.result_scale(f1.scale, f2.scale), radix, pool).ok_or(ErrorKind::DivisionByZero)
+    })
+  }
+  /// Integer remainder, taking the sign of the dividend
+  pub fn remainder(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| {
+      let Some((q, r)) = i1.divmod(i2, radix, pool) else { return Err(ErrorKind::DivisionByZero) };
+      q.clean(pool);
+      Ok(r)
+    })
+  }
+  /// Re-expresses a number string in the numeral system of another Math.
+  /// Fractions are exact if the target radix can represent them, and are
+  /// otherwise truncated toward zero to the target precision.
+  pub fn convert(&self, s: &str, to: &Math, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if to.base == 0 { return Err(ErrorKind::MathBaseZero) }
+    let (from_radix, to_radix) = (self.limb_base(), to.limb_base());
+    let f = self.stofixed(s, state)?;
+    let exact = if to.base > 1 { exact_scale(&f, from_radix, to_radix, &mut state.pool) } else { None };
+    let fixed = f.to_radix(from_radix, to_radix, exact.unwrap_or(to.result_scale(0, 0)), &mut state.pool);
+    f.clean(&mut state.pool);
+    let result = to.fixedtos(&fixed, state);
+    fixed.clean(&mut state.pool);
+    result
+  }
+
+  /// Integer modulo, taking the sign of the divisor
+  pub fn modulo(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| {
+      let Some((q, r)) = i1.floor_divmod(i2, radix, pool) else { return Err(ErrorKind::DivisionByZero) };
+      q.clean(pool);
+      Ok(r)
+    })
+  }
+  /// Floored integer quotient and modulo, consistent with modulo
+  pub fn divmod(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<(String, String), ErrorKind> {
+    let radix = self.limb_base();
+    let i1 = self.stoint(s1, state)?;
+    let i2 = match self.stoint(s2, state) {
+      Ok(i) => i,
+      Err(e) => { i1.clean(&mut state.pool); return Err(e) }
+    };
+    let result = i1.floor_divmod(&i2, radix, &mut state.pool);
+    i1.clean(&mut state.pool);
+    i2.clean(&mut state.pool);
+    let Some((q, r)) = result else { return Err(ErrorKind::DivisionByZero) };
+    let result = self.inttos(&q, state).and_then(|sq| match self.inttos(&r, state) {
+      Ok(sr) => Ok((sq, sr)),
+      Err(e) => { state.pool.add_string(sq); Err(e) }
+    });
+    q.clean(&mut state.pool);
+    r.clean(&mut state.pool);
+    result
+  }
+  /// Nonnegative greatest common divisor of two integers
+  pub fn gcd(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| Ok(i1.gcd(i2, radix, pool)))
+  }
+  /// Nonnegative least common multiple of two integers, zero if either is zero
+  pub fn lcm(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| {
+      if i1.is_zero() || i2.is_zero() { return Ok(Int::new(0, pool)) }
+      let g = i1.gcd(i2, radix, pool);
+      let (q, r) = i1.divmod(&g, radix, pool).unwrap();
+      let mut l = q.product(i2, radix, pool);
+      l.neg = false;
+      g.clean(pool);
+      q.clean(pool);
+      r.clean(pool);
+      Ok(l)
+    })
+  }
+  /// Integer power of a fixed point number, truncated like the quotient
+  pub fn power(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_fixed_op(s1, s2, state, |f1, f2, pool| {
+      if f2.scale != 0 { return Err(ErrorKind::InvalidNumberString) }
+      let Some(exp) = f2.int.to_isize(radix) else { return Err(ErrorKind::OutOfBounds) };
+      if !f1.is_zero() {
+        // the integer limbs are raised to |exp|, and a negative exp divides
+        // that into radix^(scale * |exp|)
+        let int_digits = f1.ln_estimate(radix) / (radix as f64).ln() + f1.scale as f64;
+        let digits = if exp < 0 { int_digits + f1.scale as f64 } else { int_digits };
+        if digits * exp.unsigned_abs() as f64 > DIGITS_MAX { return Err(ErrorKind::OutOfBounds) }
+      }
+      f1.power(exp, self.result_scale(f1.scale, 0), radix, pool).ok_or(ErrorKind::DivisionByZero)
+    })
+  }
+
+  fn unary_fixed_op<F>(&self, s: &str, state: &mut CognitionState, f: F) -> Result<String, ErrorKind>
+  where F: FnOnce(&Fixed, usize, &mut Pool) -> Result<Fixed, ErrorKind>
+  {
+    let f1 = self.stofixed(s, state)?;
+    let result = f(&f1, self.result_scale(f1.scale, 0), &mut state.pool);
+    f1.clean(&mut state.pool);
+    let fixed = result?;
+    let result = self.fixedtos(&fixed, state);
+    fixed.clean(&mut state.pool);
+    result
+  }
+
+  pub fn band(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| Ok(i1.bitwise(i2, radix, pool, |a, b| a & b)))
+  }
+  pub fn bor(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| Ok(i1.bitwise(i2, radix, pool, |a, b| a | b)))
+  }
+  pub fn bxor(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| Ok(i1.bitwise(i2, radix, pool, |a, b| a ^ b)))
+  }
+  pub fn bnot(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.unary_int_op(s, state, |i, pool| Ok(i.bitnot(radix, pool)))
+  }
+  /// Arithmetic left shift. A negative count shifts right.
+  pub fn shl(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| {
+      let Some(n) = i2.to_isize(radix) else { return Err(ErrorKind::OutOfBounds) };
+      if n < 0 { Ok(i1.shr(n.unsigned_abs(), radix, pool)) }
+      else { shift_left(i1, n as usize, radix, pool) }
+    })
+  }
+  /// Arithmetic right shift, rounding toward negative infinity. A negative
+  /// count shifts left.
+  pub fn shr(&self, s1: &str, s2: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.binary_int_op(s1, s2, state, |i1, i2, pool| {
+      let Some(n) = i2.to_isize(radix) else { return Err(ErrorKind::OutOfBounds) };
+      if n < 0 { shift_left(i1, n.unsigned_abs(), radix, pool) }
+      else { Ok(i1.shr(n as usize, radix, pool)) }
+    })
+  }
+  /// Number of one bits in the magnitude
+  pub fn popcount(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.unary_int_op(s, state, |i, pool| {
+      let count = i.popcount(radix, pool);
+      Ok(Int::from_isize(count as isize, radix, pool))
+    })
+  }
+  pub fn abs(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    self.unary_fixed_op(s, state, |f, _, pool| {
+      let mut fixed = f.copy(pool);
+      fixed.int.neg = false;
+      Ok(fixed)
+    })
+  }
+  /// One, zero or negative one according to the sign
+  pub fn sign(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.unary_fixed_op(s, state, |f, _, pool| {
+      let sign = if f.is_zero() { 0 } else if f.int.neg { -1 } else { 1 };
+      Ok(Fixed::from_isize(sign, radix, pool))
+    })
+  }
+  /// Square root, truncated toward zero like the quotient
+  pub fn sqrt(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let radix = self.limb_base();
+    self.unary_fixed_op(s, state, |f, scale, pool| {
+      if f.int.neg { return Err(ErrorKind::MathDomainError) }
+      Ok(f.sqrt(scale, radix, pool))
+    })
+  }
+  pub fn exp(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 1 { return Err(ErrorKind::MathBaseOne) }
+    self.unary_fixed_op(s, state, |f, scale, pool| self.chebyshev.exp(f, scale, self.base, pool))
+  }
+  pub fn ln(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 1 { return Err(ErrorKind::MathBaseOne) }
+    self.unary_fixed_op(s, state, |f, scale, pool| {
+      if f.int.neg || f.is_zero() { return Err(ErrorKind::MathDomainError) }
+      Ok(self.chebyshev.ln(f, scale, self.base, pool))
+    })
+  }
+  pub fn sin(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 1 { return Err(ErrorKind::MathBaseOne) }
+    self.unary_fixed_op(s, state, |f, scale, pool| {
+      let (s, c) = self.chebyshev.sin_cos(f, scale, self.base, pool);
+      c.clean(pool);
+      Ok(s)
+    })
+  }
+  pub fn cos(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 1 { return Err(ErrorKind::MathBaseOne) }
+    self.unary_fixed_op(s, state, |f, scale, pool| {
+      let (s, c) = self.chebyshev.sin_cos(f, scale, self.base, pool);
+      s.clean(pool);
+      Ok(c)
+    })
+  }
+  pub fn atan(&self, s: &str, state: &mut CognitionState) -> Result<String, ErrorKind> {
+    if self.base == 1 { return Err(ErrorKind::MathBaseOne) }
+    self.unary_fixed_op(s, state, |f, scale, pool| Ok(self.chebyshev.atan(f, scale, self.base, pool)))
+  }
+
   fn into_digits(&self, s: &str, v: &mut Vec<Digit>) {
     let negc = self.negc.expect("negc uninitialized");
     let mut iter = s.chars();
@@ -476,533 +996,177 @@ impl Math {
     state.pool.add_string(new_s);
   }
 
-
-
-  // fn get_next_digit(&self, c1: &mut char, iter: &mut Rev<Chars>, negc: char) -> Result<(i32, bool), &'static str> {
-  //   let c1_old = *c1;
-  //   let Some(i) = self.d_idx.get(&c1_old) else { return Err("INVALID NUMBER STRING") };
-  //   let (c_val, alive) = if let Some(c) = iter.next() {
-  //     if c == negc {
-  //       if let Some(c) = iter.next() {
-  //         *c1 = c;
-  //         (-(*i), true)
-  //       } else {
-  //         (-(*i), false)
-  //       }
-  //     } else {
-  //       *c1 = c;
-  //       (*i, true)
-  //     }
-  //   } else {
-  //     (*i, false)
-  //   };
-  //   Ok((c_val, alive))
-  // }
-
-  // fn add_digits(&self, c1_val: i32, c2_val: i32, mut carry: i32, mut neg: bool, r_iter: &mut Rev<IterMut<Digit>>) -> (i32, bool) {
-  //   let radius = &self.base / 2 + 1;
-  //   let mut sum = c1_val + c2_val + carry;
-  //   if sum < -radius {
-  //     neg = false;
-  //     carry = -1;
-  //     sum += self.base;
-  //   } else if sum == -radius {
-  //     sum = radius;
-  //     if neg { neg = false; carry = -1 }
-  //     else   { neg = true;  carry =  0 }
-  //   } else if sum < 0 {
-  //     sum = -sum;
-  //     neg = true;
-  //   } else if sum > 0 && sum < radius {
-  //     neg = false;
-  //   } else if sum == radius {
-  //     if neg { neg = false; carry = 0 }
-  //     else   { neg = true;  carry = 1 }
-  //   } else { // sum > radius
-  //     neg = true;
-  //     carry = 1;
-  //     sum = self.base - sum;
-  //   }
-  //   *r_iter.next().unwrap() = Digit{ digit: self.digits[sum as usize], neg };
-  //   (carry, neg)
-  // }
-
-  // /// f2 is always longer than f1 and includes a radix point
-  // /// i2 if always longer than i1
-  // fn add(&self, mut neg: bool,
-  //        i1: &str, i2: &str,
-  //        f1: &str, f2: &str,
-  //        f_len_diff: usize,
-  //        float: bool, mut r: Digits
-  // ) -> Result<Digits, &'static str> {
-
-  //   let Some(negc) = self.negc else { return Err("NEGC UNINITIALIZED") };
-  //   let Some(radix) = self.radix else { return Err("RADIX UNINITIALIZED") };
-
-  //   let mut r_iter = r.iter_mut().rev();
-  //   let mut carry: i32 = 0;
-  //   if float {
-  //     let mut f1_iter = f1.chars().rev();
-  //     let mut f2_iter = f2.chars().rev();
-  //     if let Some(mut c2) = f2_iter.next() {
-  //       // tail end of f2
-  //       for _ in 0..f_len_diff {
-  //         let (c2_val, _) = match self.get_next_digit(&mut c2, &mut f2_iter, negc) {
-  //           Ok((a, b)) => (a, b),
-  //           Err(e) => return Err(e),
-  //         };
-  //         (carry, neg) = self.add_digits(0, c2_val, carry, neg, &mut r_iter);
-  //       }
-  //       // add f1 and f2
-  //       if let Some(mut c1) = f1_iter.next() {
-  //         loop {
-  //           let (c1_val, alive) = match self.get_next_digit(&mut c1, &mut f1_iter, negc) {
-  //             Ok((a, b)) => (a, b),
-  //             Err(e) => return Err(e),
-  //           };
-  //           let (c2_val, _) = match self.get_next_digit(&mut c2, &mut f2_iter, negc) {
-  //             Ok((a, b)) => (a, b),
-  //             Err(e) => return Err(e),
-  //           };
-  //           (carry, neg) = self.add_digits(c1_val, c2_val, carry, neg, &mut r_iter);
-  //           if !alive { break }
-  //         }
-  //       }
-  //     }
-  //     *r_iter.next().unwrap() = Digit{ digit: radix, neg: false };
-  //   }
-
-  //   let mut i1_iter = i1.chars().rev();
-  //   let mut i2_iter = i2.chars().rev();
-
-  //   if let Some(mut c2) = i2_iter.next() {
-  //     if let Some(mut c1) = i1_iter.next() {
-  //       loop {
-  //         let (c1_val, alive) = match self.get_next_digit(&mut c1, &mut i1_iter, negc) {
-  //           Ok((a, b)) => (a, b),
-  //           Err(e) => return Err(e),
-  //         };
-  //         let (c2_val, _) = match self.get_next_digit(&mut c2, &mut i2_iter, negc) {
-  //           Ok((a, b)) => (a, b),
-  //           Err(e) => return Err(e),
-  //         };
-  //         (carry, neg) = self.add_digits(c1_val, c2_val, carry, neg, &mut r_iter);
-  //         if !alive { break }
-  //       }
-  //     }
-  //     loop {
-  //       let (c2_val, alive) = match self.get_next_digit(&mut c2, &mut i2_iter, negc) {
-  //         Ok((a, b)) => (a, b),
-  //         Err(e) => return Err(e),
-  //       };
-  //       (carry, neg) = self.add_digits(0, c2_val, carry, neg, &mut r_iter);
-  //       if !alive { break }
-  //     }
-  //   }
-
-  //   Ok(r)
-  // }
-
-  // pub fn sum(&self, s1: &String, s2: &String, pool: &mut Pool) -> Result<String, &'static str> {
-  //   if self.base == 0 { return Err("UNINITIALIZED NUMBER BASE") }
-
-  //   let result = pool.get_string(s1.len().max(s2.len()) * 4);
-  //   let mut s1_iter = s1.char_indices();
-  //   let mut s2_iter = s2.char_indices();
-
-  //   let mut f_len_diff: usize = 0;
-  //   let mut p1last: usize = 0;
-  //   let mut p2last: usize = 0;
-
-
-  //   let ((i1, i1_ok), (i2, i2_ok)) = loop {
-  //     let mut c1 = s1_iter.next();
-  //     let mut c2 = s2_iter.next();
-  //     if c1.is_none() {
-  //       break ((&s1[p1last..s1.len()], false), loop {
-  //         if c2.is_none() { break (&s2[p2last..s2.len()], false) }
-  //         if Some(c2.unwrap().1) == self.delim {
-  //           let p2 = p2last;
-  //           p2last = c2.unwrap().0 + self.delim.unwrap().len_utf8();
-  //           break (&s2[p2..c2.unwrap().0], true)
-  //         }
-  //         c2 = s2_iter.next();
-  //         f_len_diff += 1;
-  //       })
-  //     } else if Some(c1.unwrap().1) == self.delim {
-  //       let p1 = p1last;
-  //       p1last = c1.unwrap().0 + self.delim.unwrap().len_utf8();
-  //       break ((&s1[p1..c1.unwrap().0], true), loop {
-  //         if c2.is_none() { break (&s2[p2last..s2.len()], false) }
-  //         if Some(c2.unwrap().1) == self.delim {
-  //           let p2 = p2last;
-  //           p2last = c2.unwrap().0 + self.delim.unwrap().len_utf8();
-  //           break (&s2[p2..c2.unwrap().0], true)
-  //         }
-  //         c2 = s2_iter.next();
-  //         f_len_diff += 1;
-  //       })
-  //     }
-
-  //     if c2.is_none() {
-  //       break ((&s1[p1last..s1.len()], false), loop {
-  //         if c1.is_none() { break (&s2[p2last..s2.len()], false) }
-  //         if Some(c1.unwrap().1) == self.delim {
-  //           let p2 = p2last;
-  //           p2last = c1.unwrap().0 + self.delim.unwrap().len_utf8();
-  //           break (&s2[p2..c1.unwrap().0], true)
-  //         }
-  //         c1 = s2_iter.next();
-  //         f_len_diff += 1;
-  //       })
-  //     } else if Some(c2.unwrap().1) == self.delim {
-  //       let p1 = p1last;
-  //       p1last = c2.unwrap().0 + self.delim.unwrap().len_utf8();
-  //       break ((&s1[p1..c2.unwrap().0], true), loop {
-  //         if c1.is_none() { break (&s2[p2last..s2.len()], false) }
-  //         if Some(c1.unwrap().1) == self.delim {
-  //           let p2 = p2last;
-  //           p2last = c1.unwrap().0 + self.delim.unwrap().len_utf8();
-  //           break (&s2[p2..c1.unwrap().0], true)
-  //         }
-  //         c1 = s2_iter.next();
-  //         f_len_diff += 1;
-  //       })
-  //     }
-  //   };
-
-
-
-
-  //   // /// f2 is always longer than f1 and includes a radix point
-  //   // /// i2 if always longer than i1
-  //   // fn add(&self, mut neg: bool,
-  //   //        i1: &str, i2: &str,
-  //   //        f1: &str, f2: &str,
-  //   //        f_len_diff: usize,
-  //   //        float: bool, mut r: Digits
-  //   // ) -> Result<Digits, &'static str>
-
-
-
-
-  //   Err("not finished yet")
-  // }
-
-
-  // fn pair_into_next_digits(&self, s1_iter: &mut Chars, s2_iter: &mut Chars, tmp1_digits: &mut Digits, tmp2_digits: &mut Digits) -> Option<&'static str> {
-  //   let Some(ref negc) = self.negc else { return Some("MATH NEGC UNINITIALIZED") };
-  //   let Some(ref radix) = self.radix else { return Some("MATH RADIX UNINITIALIZED") };
-  //   let Some(ref delim) = self.delim else { return Some("MATH DELIM UNINITIALIZED") };
-
-  //   let (mut i_len1, mut i_len2, mut f_len1, mut f_len2) = (0,0,0,0);
-
-  //   let (mut alive1, mut alive2) = (true, true);
-  //   let (mut frac1, mut frac2) = (false, false);
-
-  //   let get_next = | alive1: bool, alive2: bool, iter1: &mut Chars, iter2: &mut Chars, len1: &mut usize, len2: &mut usize |
-  //   (if alive1 { *len1 += 1; iter1.next() } else { None },
-  //    if alive2 { *len2 += 1; iter2.next() } else { None });
-
-  //   loop {
-  //     let (c1opt, c2opt) = get_next(alive1, alive2, s1_iter, s2_iter, &mut i_len1, &mut i_len2);
-  //     if let Some(c1) = c1opt { (alive1, frac1) = push_next!(c1, s1_iter, tmp1_digits, *negc, *radix, *delim); }
-  //     if let Some(c2) = c2opt { (alive2, frac2) = push_next!(c2, s2_iter, tmp2_digits, *negc, *radix, *delim); }
-  //     if (c1opt, c2opt) == (None, None) { break }
-  //   }
-  //   if frac1 { tmp1_digits.push(Digit{ digit: *radix, neg: false }) }
-  //   if frac2 { tmp2_digits.push(Digit{ digit: *radix, neg: false }) }
-  //   (alive1, alive2) = (frac1, frac2);
-  //   loop {
-  //     let (c1opt, c2opt) = get_next(alive1, alive2, s1_iter, s2_iter, &mut f_len1, &mut f_len2);
-  //     if let Some(c1) = c1opt {
-  //       (alive1, frac1) = push_next!(c1, s1_iter, tmp1_digits, *negc, *radix, *delim);
-  //       if frac1 { return Some("INVALID NUMBER STRING") }
-  //     }
-  //     if let Some(c2) = c2opt {
-  //       (alive2, frac2) = push_next!(c2, s2_iter, tmp2_digits, *negc, *radix, *delim);
-  //       if frac2 { return Some("INVALID NUMBER STRING") }
-  //     }
-  //     if (c1opt, c2opt) == (None, None) { break None }
-  //   }
-  // }
-
-  fn add_digits(&self, c1_val: i32, c2_val: i32, mut carry: i32, mut neg: bool, r: &mut Digits) -> (i32, bool) {
-    let radius = &self.base / 2 + 1;
-    let mut sum = c1_val + c2_val + carry;
-    if sum < -radius {
-      neg = false;
-      carry = -1;
-      sum += self.base;
-    } else if sum == -radius {
-      sum = radius;
-      if neg { neg = false; carry = -1 }
-      else   { neg = true;  carry =  0 }
-    } else if sum < 0 {
-      sum = -sum;
-      neg = true;
-    } else if sum > 0 && sum < radius {
-      neg = false;
-    } else if sum == radius {
-      if neg { neg = false; carry = 0 }
-      else   { neg = true;  carry = 1 }
-    } else { // sum > radius
-      neg = true;
-      carry = 1;
-      sum = self.base - sum;
-    }
-    r.push(Digit{ digit: self.digits[sum as usize], neg });
-    (carry, neg)
-  }
-
-  // /// f2 is always longer than f1
-  // fn add(&self, mut neg: bool, d1: Digits, d2: Digits, f_len_diff: usize, float: bool, r: &mut Digits) -> Option<&'static str> {
-  //   let Some(radix) = self.radix else { return Some("MATH RADIX UNINITIALIZED") };
-
-  //   let mut r_iter = r.iter_mut().rev();
-  //   let mut carry: i32 = 0;
-  //   let mut d1_iter = d1.iter().rev();
-  //   let mut d2_iter = d2.iter().rev();
-  //   if float {
-  //     // tail end of d2
-  //     for _ in 0..f_len_diff {
-  //       let Some(ref c2) = d2_iter.next()       else { return Some("INVALID NUMBER STRING") };
-  //       let Some(i) = self.d_idx.get(&c2.digit) else { return Some("INVALID NUMBER STRING") };
-  //       let c2val = if c2.neg { -(*i) } else { *i };
-  //       (carry, neg) = self.add_digits(0, c2val, carry, neg, &mut r_iter);
-  //     }
-  //     // add f1 and f2
-  //     loop {
-  //       let Some(ref c1) = d1_iter.next()       else { return Some("INVALID NUMBER STRING") };
-  //       if c1.digit == radix                         { d2_iter.next(); break }
-  //       let Some(i) = self.d_idx.get(&c1.digit) else { return Some("INVALID NUMBER STRING") };
-  //       let c1val = if c1.neg { -(*i) } else { *i };
-  //       let Some(ref c2) = d2_iter.next()       else { return Some("INVALID NUMBER STRING") };
-  //       let Some(i) = self.d_idx.get(&c2.digit) else { return Some("INVALID NUMBER STRING") };
-  //       let c2val = if c2.neg { -(*i) } else { *i };
-  //       (carry, neg) = self.add_digits(c1val, c2val, carry, neg, &mut r_iter);
-  //     }
-  //     *r_iter.next().unwrap() = Digit{ digit: radix, neg: false };
-  //   }
-  //   match loop {
-  //     let Some(ref c1) = d1_iter.next()       else { break 2 };
-  //     let Some(i) = self.d_idx.get(&c1.digit) else { return Some("INVALID NUMBER STRING") };
-  //     let c1val = if c1.neg { -(*i) } else { *i };
-  //     let Some(ref c2) = d2_iter.next()       else { break 1 };
-  //     let Some(i) = self.d_idx.get(&c2.digit) else { return Some("INVALID NUMBER STRING") };
-  //     let c2val = if c2.neg { -(*i) } else { *i };
-  //     (carry, neg) = self.add_digits(c1val, c2val, carry, neg, &mut r_iter);
-  //   } {
-  //     1 => loop {
-  //       let Some(ref c1) = d1_iter.next()       else { break };
-  //       let Some(i) = self.d_idx.get(&c1.digit) else { return Some("INVALID NUMBER STRING") };
-  //       let c1val = if c1.neg { -(*i) } else { *i };
-  //       (carry, neg) = self.add_digits(c1val, 0, carry, neg, &mut r_iter);
-  //     },
-  //     _ => loop {
-  //       let Some(ref c2) = d2_iter.next()       else { break };
-  //       let Some(i) = self.d_idx.get(&c2.digit) else { return Some("INVALID NUMBER STRING") };
-  //       let c2val = if c2.neg { -(*i) } else { *i };
-  //       (carry, neg) = self.add_digits(0, c2val, carry, neg, &mut r_iter);
-  //     },
-  //   }
-
-  //   self.add_digits(0, 0, carry, neg, &mut r_iter);
-  //   None
-  // }
-
-  // add radix point retainment
-  fn add(&self, mut neg: Option<bool>, d1: &mut Vec<i32>, d2: &mut Vec<i32>, f_len1: usize, f_len2: usize, r: &mut Digits, radix: char) {
-    for (i1, i2) in d1.iter().zip(d2.iter()) {
-      if neg.is_some() { break }
-      if i1 + i2 < 0 { neg = Some(true) }
-      if i1 + i2 > 0 { neg = Some(false) }
-    }
-    let mut neg = if let Some(v) = neg { v } else { false };
-    let mut carry: i32 = 0;
-    let mut d1_iter = d1.iter().rev();
-    let mut d2_iter = d2.iter().rev();
-    if f_len2 > 0 {
-      // tail end of d2
-      for _ in f_len1..f_len2 {
-        let i = d2_iter.next().expect("Bad Math::add() call");
-        (carry, neg) = self.add_digits(0, *i, carry, neg, r);
-      }
-      // add f1 and f2
-      for _ in 0..f_len1 {
-        let i1 = d1_iter.next().expect("Bad Math::add() call");
-        let i2 = d2_iter.next().expect("Bad Math::add() call");
-        (carry, neg) = self.add_digits(*i1, *i2, carry, neg, r);
-      }
-      r.push(Digit{ digit: radix, neg: false });
-    }
-    match loop {
-      let Some(i1) = d1_iter.next() else { break 2 };
-      let Some(i2) = d2_iter.next() else { break 1 };
-      (carry, neg) = self.add_digits(*i1, *i2, carry, neg, r);
-    } {
-      1 => loop {
-        let Some(i) = d1_iter.next() else { break };
-        (carry, neg) = self.add_digits(*i, 0, carry, neg, r);
-      },
-      _ => loop {
-        let Some(i) = d2_iter.next() else { break };
-        (carry, neg) = self.add_digits(0, *i, carry, neg, r);
-      }
-    }
-    self.add_digits(0, 0, carry, neg, r);
+  fn zero_digit(&self) -> Digit {
+    Digit{ digit: self.digits[0], neg: false }
   }
 
-  // redesigning based on reading ahead
-  #[allow(unused_variables)]
-  #[allow(unused_mut)]
-  pub fn sum(&self, s1: &String, s2: &String, state: &mut CognitionState) -> Result<String, &'static str> {
-    if self.base == 0 { return Err("UNINITIALIZED NUMBER BASE") }
-
-    let negc = if let Some(ref c) = self.negc { c.clone() } else { return Err("MATH NEGC UNINITIALIZED") };
-    let radix = if let Some(ref c) = self.radix { c.clone() } else { return Err("MATH RADIX UNINITIALIZED") };
-    let delim = if let Some(ref c) = self.delim { c.clone() } else { return Err("MATH DELIM UNINITIALIZED") };
-
-    if s1.len() == 0 { return Ok(state.string_copy(s2)) }
-    if s2.len() == 0 { return Ok(state.string_copy(s1)) }
-
-    let mut result = state.pool.get_string(s1.len().max(s2.len()) * 4);
+  /// Parses a single digit, optionally followed by negc. The empty string
+  /// is the zero digit.
+  pub fn stodigit(&self, s: &str) -> Result<Digit, ErrorKind> {
+    let Some(negc) = self.negc else { return Err(ErrorKind::MathNegcUninitialized) };
+    if self.digits.is_empty() { return Err(ErrorKind::MathDigitsUninitialized) }
+    let mut iter = s.chars();
+    let Some(digit) = iter.next() else { return Ok(self.zero_digit()) };
+    let neg = match iter.next() {
+      None => false,
+      Some(c) if c == negc && iter.next().is_none() => true,
+      _ => return Err(ErrorKind::InvalidNumberString)
+    };
+    Ok(Digit{ digit, neg: neg && digit != self.digits[0] })
+  }
 
-    let (mut tmp1_ints, mut tmp2_ints) = (state.pool.get_ints(s1.len()), state.pool.get_ints(s2.len()));
-    let (mut s1_iter, mut s2_iter) = (s1.chars(), s2.chars());
+  /// Number of arguments taken by an operation in the ops table
+  pub fn op_arity(&self, name: &str) -> Option<usize> {
+    Some(match self.ops_table.get(name)? {
+      Op::Unary(_) | Op::Str(_) => 1,
+      Op::Binary(_) => 2,
+      Op::Custom(c) => c.keys().next().map_or(1, |k| k.len() - 1),
+    })
+  }
 
-    loop {
-      let (mut i_len1, mut i_len2, mut f_len1, mut f_len2) = (0,0,0,0);
-
-      let (mut alive1, mut alive2) = (true, true);
-      let (mut frac1, mut frac2) = (false, false);
-
-      let get_next = | alive1: bool, alive2: bool, iter1: &mut Chars, iter2: &mut Chars, len1: &mut usize, len2: &mut usize |
-      (if alive1 { *len1 += 1; iter1.next() } else { None },
-       if alive2 { *len2 += 1; iter2.next() } else { None });
-
-
-      // macro_rules! push_next_int {
-      //   ($self:ident,$c:ident,$iter:ident,$tmp:ident,$negc:expr,$radix:expr,$delim:expr) => {
-      //     if $c == $negc {
-      //       let Some(c) = $iter.next() else { return Err("INVALID NUMBER STRING") };
-      //       let Some(i) = $self.d_idx.get(&c) else { return Err("INVALID NUMBER STRING") };
-      //       $tmp.push(-(*i)); (true, false)
-      //     }
-      //     else if $c == $radix { (false, true) }
-      //     else if $c == $delim { (false, false) }
-      //     else {
-      //       let Some(i) = $self.d_idx.get(&$c) else { return Err("INVALID NUMBER STRING") };
-      //       $tmp.push(*i); (true, false)
-      //     }
-      //   };
-      // }
-
-      let mut neg = None;
-
-      if let Some(mut c) = s1_iter.next() {
-        loop {
-          if c == radix { break }
-          if c == delim { break }
-          let Some(c_new) = s1_iter.next() else {
-            let Some(i) = self.d_idx.get(&c) else {
-              state.pool.add_ints(tmp1_ints);
-              state.pool.add_ints(tmp2_ints);
-              return Err("INVALID NUMBER STRING")
-            };
-            tmp1_ints.push(*i);
-            break
+  /// Applies an operation in the ops table to number strings. Unary and
+  /// string operations map each digit or the whole string. Binary operations
+  /// map a pair of aligned digits to a result and a carry, and fold the
+  /// carries into the next position through the same table. Custom
+  /// operations map the incoming carry followed by a digit of each argument
+  /// to a result and an outgoing carry.
+  pub fn apply_op(&self, name: &str, args: &[&str], state: &mut CognitionState) -> Result<String, ErrorKind> {
+    let Some(op) = self.ops_table.get(name) else { return Err(ErrorKind::UndefinedMathOp) };
+    if let Op::Str(table) = op {
+      let Some(s) = table.get(args[0]) else { return Err(ErrorKind::UndefinedOperand) };
+      return Ok(state.string_copy(s))
+    }
+    // least significant digit first
+    let mut ds: Vec<Digits> = Vec::with_capacity(args.len());
+    for arg in args {
+      let mut d = state.pool.get_digits(arg.len());
+      self.into_digits(arg, &mut d);
+      d.reverse();
+      ds.push(d);
+    }
+    let len = ds.iter().map(|d| d.len()).max().unwrap_or(0);
+    let zero = self.zero_digit();
+    let digit = |d: &Digits, i: usize| d.get(i).unwrap_or(&zero).clone();
+    let mut out = state.pool.get_digits(len + 1);
+    let result = match op {
+      Op::Unary(table) => ds[0].iter().try_for_each(|d| {
+        out.push(table.get(d).ok_or(ErrorKind::UndefinedOperand)?.clone());
+        Ok(())
+      }),
+      Op::Binary(table) => {
+        let mut carries = state.pool.get_digits(len);
+        let mut next = state.pool.get_digits(len);
+        let mut i = 0;
+        let result = loop {
+          if i >= len && carries.is_empty() { break Ok(()) }
+          if i > len + table.len() { break Err(ErrorKind::OutOfBounds) }
+          let folded = if i < len {
+            fold_carries(table, &zero, digit(&ds[0], i), &[digit(&ds[1], i)], &mut next)
+              .and_then(|acc| fold_carries(table, &zero, acc, &carries, &mut next))
+          } else {
+            fold_carries(table, &zero, carries[0].clone(), &carries[1..], &mut next)
           };
-          if c_new == negc {
-            let Some(i) = self.d_idx.get(&c) else {
-              state.pool.add_ints(tmp1_ints);
-              state.pool.add_ints(tmp2_ints);
-              return Err("INVALID NUMBER STRING")
-            };
-            tmp1_ints.push(-(*i));
-            let Some(c_new) = s1_iter.next() else { break };
-            c = c_new;
-            continue;
+          match folded {
+            Ok(d) => out.push(d),
+            Err(e) => break Err(e)
           }
-          let Some(i) = self.d_idx.get(&c) else {
-            state.pool.add_ints(tmp1_ints);
-            state.pool.add_ints(tmp2_ints);
-            return Err("INVALID NUMBER STRING")
+          std::mem::swap(&mut carries, &mut next);
+          next.clear();
+          i += 1;
+        };
+        state.pool.add_digits(carries);
+        state.pool.add_digits(next);
+        result
+      },
+      Op::Custom(table) => {
+        let mut key = state.pool.get_digits(ds.len() + 1);
+        let mut carry = zero.clone();
+        let mut i = 0;
+        let result = loop {
+          if i >= len && carry == zero { break Ok(()) }
+          if i > len + table.len() { break Err(ErrorKind::OutOfBounds) }
+          key.clear();
+          key.push(carry.clone());
+          key.extend(ds.iter().map(|d| digit(d, i)));
+          let (r, c) = match table.get(&Operand::from_digits(&key)) {
+            Some(Operand::Unary(r)) => (r.clone(), zero.clone()),
+            Some(Operand::Binary(r, c)) => (r.clone(), c.clone()),
+            Some(_) => break Err(ErrorKind::BadArgumentType),
+            None => break Err(ErrorKind::UndefinedOperand)
           };
-          tmp1_ints.push(*i);
-          c = c_new;
-        }
-      }
-
-      // loop {
-      //   let (c1opt_new, c2opt_new) = get_next(alive1, alive2, &mut s1_iter, &mut s2_iter, &mut i_len1, &mut i_len2);
-      //   if let Some(c1) = c1opt {
-      //     (alive1, frac1) = push_next_int!(self, c1, s1_iter, tmp1_ints, *negc, *radix, *delim);
-      //   }
-      //   if let Some(c2) = c2opt { (alive2, frac2) = push_next_int!(self, c2, s2_iter, tmp2_ints, *negc, *radix, *delim); }
-      //   if alive1 && alive2 && neg.is_none() {
-      //     let sum = tmp1_ints.last().unwrap() + tmp2_ints.last().unwrap();
-      //     if sum < 0 { neg = Some(true) } else if sum > 0 { neg = Some(false) }
-      //   }
-      //   if (c1opt, c2opt) == (None, None) { break }
-      // }
-      // if i_len1 > i_len2      { neg = Some(*tmp1_ints.first().unwrap() < 0) }
-      // else if i_len1 < i_len2 { neg = Some(*tmp2_ints.first().unwrap() < 0) }
-      // (alive1, alive2) = (frac1, frac2);
-
-      // loop {
-      //   let (c1opt, c2opt) = get_next(alive1, alive2, &mut s1_iter, &mut s2_iter, &mut f_len1, &mut f_len2);
-      //   if let Some(c1) = c1opt {
-      //     (alive1, frac1) = push_next_int!(self, c1, s1_iter, tmp1_ints, *negc, *radix, *delim);
-      //     if frac1 { return Err("INVALID NUMBER STRING") }
-      //   }
-      //   if let Some(c2) = c2opt {
-      //     (alive2, frac2) = push_next_int!(self, c2, s2_iter, tmp2_ints, *negc, *radix, *delim);
-      //     if frac2 { return Err("INVALID NUMBER STRING") }
-      //   }
-      //   if alive1 && alive2 && neg.is_none() {
-      //     let sum = tmp1_ints.last().unwrap() + tmp2_ints.last().unwrap();
-      //     if sum < 0 { neg = Some(true) } else if sum > 0 { neg = Some(false) }
-      //   }
-      //   if (c1opt, c2opt) == (None, None) { break }
-      // }
-
-      let n_o_d = tmp1_ints.len().max(tmp2_ints.len()) + 2;
-      let mut tmp_digits = state.pool.get_digits(n_o_d);
-      for _ in 0..n_o_d { tmp_digits.push(Digit{ digit: self.digits[0], neg: false }) }
-      if f_len1 > f_len2 { self.add(neg, &mut tmp2_ints, &mut tmp1_ints, f_len2, f_len1, &mut tmp_digits, radix) }
-      else               { self.add(neg, &mut tmp1_ints, &mut tmp2_ints, f_len1, f_len2, &mut tmp_digits, radix) }
-
-      while let Some(d) = tmp_digits.pop() {
-        if d.digit == self.digits[0] { continue }
-        if d.neg { result.push(negc) }
-        result.push(d.digit);
-      }
-      result.push(delim);
-      state.pool.add_digits(tmp_digits);
-
-      break
+          out.push(r);
+          carry = c;
+          i += 1;
+        };
+        state.pool.add_digits(key);
+        result
+      },
+      Op::Str(_) => unreachable!()
+    };
+    for d in ds { state.pool.add_digits(d) }
+    if let Err(e) = result {
+      state.pool.add_digits(out);
+      return Err(e)
     }
-    result.pop();
-    Ok(result)
+    while out.last() == Some(&zero) { out.pop(); }
+    out.reverse();
+    let mut s = state.pool.get_string(out.len() * 2);
+    self.digits_to_string(&out, &mut s);
+    state.pool.add_digits(out);
+    Ok(s)
   }
+}
 
-  // pub fn product(&self, s1: &String, s2: &String, state: &mut CognitionState) -> Result<String, &'static str> {
-//     if self.base == 0 { return Err("UNINITIALIZED NUMBER BASE") }
-
-//     let Some(negc) = self.negc else { return Err("MATH NEGC UNINITIALIZED") };
-//     let Some(radix) = self.radix else { return Err("MATH RADIX UNINITIALIZED") };
-//     let Some(delim) = self.delim else { return Err("MATH DELIM UNINITIALIZED") };
-
-//     if s1.len() == 0 { return Ok(state.string_copy(s2)) }
-//     if s2.len() == 0 { return Ok(state.string_copy(s1)) }
-
-//     let mut result = state.pool.get_string(s1.len().max(s2.len()) * 4);
-
-//     let (mut tmp1_ints, mut tmp2_ints) = (state.pool.get_ints(s1.len()), state.pool.get_ints(s2.len()));
-//     let (mut s1_iter, mut s2_iter) = (s1.chars(), s2.chars());
+/// i * 2^n, unless that has more than DIGITS_MAX digits
+fn shift_left(i: &Int, n: usize, radix: i32, pool: &mut Pool) -> Result<Int, ErrorKind> {
+  let digits = i.limbs.len() as f64 + n as f64 * std::f64::consts::LN_2 / (radix as f64).ln();
+  if !i.is_zero() && digits > DIGITS_MAX { return Err(ErrorKind::OutOfBounds) }
+  Ok(i.shl(n, radix, pool))
+}
 
-// //    self.pair_into_next_digits(&mut s1_iter, &mut s2_iter, tmp1_digits, tmp2_digits)
+/// Smallest scale in radix `to` that holds the fixed point number exactly,
+/// if there is one
+fn exact_scale(f: &Fixed, from: i32, to: i32, pool: &mut Pool) -> Option<usize> {
+  if f.scale == 0 { return Some(0) }
+  let multiplicity = |mut n: i32, p: i32| {
+    let mut m = 0;
+    while n % p == 0 { n /= p; m += 1 }
+    m
+  };
+  let (mut n, mut p, mut k) = (from, 2, 0);
+  while n > 1 {
+    if p * p > n { p = n }
+    if n % p == 0 {
+      // factors of p in the denominator that the numerator does not cancel
+      let mut m = f.scale * multiplicity(n, p);
+      let mut int = f.int.copy(pool);
+      let d = Int::from_isize(p as isize, from, pool);
+      while m != 0 {
+        let (q, r) = int.divmod(&d, from, pool).unwrap();
+        let zero = r.is_zero();
+        r.clean(pool);
+        std::mem::replace(&mut int, q).clean(pool);
+        if !zero { break }
+        m -= 1;
+      }
+      int.clean(pool);
+      d.clean(pool);
+      let b = multiplicity(to, p);
+      if m != 0 && b == 0 { return None }
+      if b != 0 { k = k.max(m.div_ceil(b)) }
+      while n % p == 0 { n /= p }
+    }
+    p += 1;
+  }
+  Some(k)
+}
 
-//   }
+/// Folds each digit into acc through a binary table, collecting the carries
+fn fold_carries(table: &BinaryOp, zero: &Digit, mut acc: Digit, digits: &[Digit], carries: &mut Digits) -> Result<Digit, ErrorKind> {
+  for d in digits {
+    let Some((r, c)) = table.get(&(acc, d.clone())) else { return Err(ErrorKind::UndefinedOperand) };
+    if c != zero { carries.push(c.clone()) }
+    acc = r.clone();
+  }
+  Ok(acc)
 }
@@ -0,0 +1,343 @@
+use crate::*;
+
+use std::cmp::Ordering;
+
+/// Bits of precision of the tables built by Math::set_base
+pub const CHEBYSHEV_BITS: f64 = 128.0;
+
+/// Chebyshev series coefficients on [-1, 1] for exp, cos, sin and atan, held
+/// as fixed point numbers in the limb radix of a Math. Each series is
+/// f(x) = sum a_k T_k(x), and is summed with Clenshaw's recurrence. The
+/// other transcendental functions are reduced to these.
+#[derive(Default)]
+pub struct Chebyshev {
+  pub scale: usize,
+  pub exp: Vec<Fixed>,
+  pub cos: Vec<Fixed>,
+  pub sin: Vec<Fixed>,
+  pub atan: Vec<Fixed>,
+}
+
+/// Number of extra limbs carried through intermediate results
+fn guard(radix: i32) -> usize {
+  (32.0 / (radix as f64).log2()).ceil() as usize + 1
+}
+
+/// Number of limbs in the integer part of |f|
+fn digits(f: f64, radix: i32) -> usize {
+  if f.abs() < 1.0 { return 0 }
+  (f.abs().ln() / (radix as f64).ln()).ceil() as usize + 1
+}
+
+fn zero(pool: &mut Pool) -> Fixed {
+  Fixed::from_int(Int::new(0, pool))
+}
+
+fn replace(f: &mut Fixed, new: Fixed, pool: &mut Pool) {
+  std::mem::replace(f, new).clean(pool)
+}
+
+fn double(f: &Fixed, radix: i32, pool: &mut Pool) -> Fixed {
+  f.sum(f, radix, pool)
+}
+
+fn clean_all(v: &mut Vec<Fixed>, pool: &mut Pool) {
+  while let Some(f) = v.pop() { f.clean(pool) }
+}
+
+fn copy_all(v: &[Fixed], pool: &mut Pool) -> Vec<Fixed> {
+  v.iter().map(|f| f.copy(pool)).collect()
+}
+
+/// Sums a Chebyshev series at x with Clenshaw's recurrence
+fn clenshaw(a: &[Fixed], x: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> Fixed {
+  let two_x = double(x, radix, pool);
+  let mut b1 = zero(pool);
+  let mut b2 = zero(pool);
+  for c in a.iter().skip(1).rev() {
+    // b_k = a_k + 2x b_(k+1) - b_(k+2)
+    let t = two_x.product(&b1, scale, radix, pool);
+    let u = t.sum(c, radix, pool);
+    let b = u.difference(&b2, radix, pool);
+    t.clean(pool);
+    u.clean(pool);
+    let b = std::mem::replace(&mut b1, b);
+    replace(&mut b2, b, pool);
+  }
+  // f(x) = a_0 + x b_1 - b_2
+  let t = x.product(&b1, scale, radix, pool);
+  let u = match a.first() {
+    Some(a0) => t.sum(a0, radix, pool),
+    None => t.copy(pool)
+  };
+  let f = u.difference(&b2, radix, pool);
+  for g in [two_x, b1, b2, t, u] { g.clean(pool) }
+  f
+}
+
+impl Chebyshev {
+  pub fn new() -> Chebyshev {
+    Chebyshev{ scale: 0, exp: Vec::new(), cos: Vec::new(), sin: Vec::new(), atan: Vec::new() }
+  }
+
+  pub fn clean(&mut self, pool: &mut Pool) {
+    self.scale = 0;
+    clean_all(&mut self.exp, pool);
+    clean_all(&mut self.cos, pool);
+    clean_all(&mut self.sin, pool);
+    clean_all(&mut self.atan, pool);
+  }
+
+  pub fn copy_into(&self, other: &mut Chebyshev, pool: &mut Pool) {
+    other.scale = self.scale;
+    other.exp = copy_all(&self.exp, pool);
+    other.cos = copy_all(&self.cos, pool);
+    other.sin = copy_all(&self.sin, pool);
+    other.atan = copy_all(&self.atan, pool);
+  }
+
+  /// Number of limbs the tables built by Math::set_base are accurate to
+  pub fn default_scale(radix: i32) -> usize {
+    (CHEBYSHEV_BITS / (radix as f64).log2()).ceil() as usize + guard(radix)
+  }
+
+  /// Computes the coefficients to the given scale. The coefficients of exp,
+  /// cos and sin are modified Bessel and Bessel functions of the first kind
+  /// at 1; those of atan are powers of sqrt(2) - 1.
+  pub fn init(&mut self, scale: usize, radix: i32, pool: &mut Pool) {
+    self.clean(pool);
+    self.scale = scale;
+    // lead = 1 / (2^n n!)
+    let mut lead = Fixed::from_isize(1, radix, pool);
+    let mut n: isize = 0;
+    while !lead.is_zero() {
+      // I_n(1) and J_n(1) are sums of lead / (4^m m! (n+1)...(n+m))
+      let mut i_n = lead.copy(pool);
+      let mut j_n = lead.copy(pool);
+      let mut term = lead.copy(pool);
+      let mut m: isize = 1;
+      loop {
+        let d = Fixed::from_isize(4 * m * (m + n), radix, pool);
+        let t = term.quotient(&d, scale, radix, pool).unwrap();
+        d.clean(pool);
+        replace(&mut term, t, pool);
+        if term.is_zero() { break }
+        let i = i_n.sum(&term, radix, pool);
+        replace(&mut i_n, i, pool);
+        let j = if m % 2 == 0 { j_n.sum(&term, radix, pool) } else { j_n.difference(&term, radix, pool) };
+        replace(&mut j_n, j, pool);
+        m += 1;
+      }
+      term.clean(pool);
+      if n != 0 {
+        let i = double(&i_n, radix, pool);
+        replace(&mut i_n, i, pool);
+        let j = double(&j_n, radix, pool);
+        replace(&mut j_n, j, pool);
+      }
+      // cos takes the even terms and sin the odd, with alternating signs
+      if n % 4 >= 2 { j_n.negate() }
+      self.exp.push(i_n);
+      if n % 2 == 0 {
+        self.cos.push(j_n);
+        self.sin.push(zero(pool));
+      } else {
+        self.cos.push(zero(pool));
+        self.sin.push(j_n);
+      }
+      n += 1;
+      let d = Fixed::from_isize(2 * n, radix, pool);
+      let l = lead.quotient(&d, scale, radix, pool).unwrap();
+      d.clean(pool);
+      replace(&mut lead, l, pool);
+    }
+    lead.clean(pool);
+    // atan(x) = sum 2 (-1)^k v^(2k+1) / (2k+1) T_(2k+1)(x), v = sqrt(2) - 1
+    let two = Fixed::from_isize(2, radix, pool);
+    let one = Fixed::from_isize(1, radix, pool);
+    let root = two.sqrt(scale, radix, pool);
+    let v = root.difference(&one, radix, pool);
+    let v2 = v.product(&v, scale, radix, pool);
+    let mut power = double(&v, radix, pool);
+    let mut k: isize = 0;
+    while !power.is_zero() {
+      let d = Fixed::from_isize(2 * k + 1, radix, pool);
+      let mut a = power.quotient(&d, scale, radix, pool).unwrap();
+      d.clean(pool);
+      if k % 2 == 1 { a.negate() }
+      self.atan.push(zero(pool));
+      self.atan.push(a);
+      let p = power.product(&v2, scale, radix, pool);
+      replace(&mut power, p, pool);
+      k += 1;
+    }
+    for f in [two, one, root, v, v2, power] { f.clean(pool) }
+  }
+
+  /// Calls f with a table accurate to at least the given scale, building a
+  /// temporary one if this one is not
+  fn with_table<T, F>(&self, scale: usize, radix: i32, pool: &mut Pool, f: F) -> T
+  where F: FnOnce(&Chebyshev, &mut Pool) -> T
+  {
+    if scale + guard(radix) <= self.scale { return f(self, pool) }
+    let mut table = Chebyshev::new();
+    table.init(scale + guard(radix), radix, pool);
+    let result = f(&table, pool);
+    table.clean(pool);
+    result
+  }
+
+  /// pi to the given scale, summing the atan series at one
+  fn pi(&self, scale: usize, radix: i32, pool: &mut Pool) -> Fixed {
+    let one = Fixed::from_isize(1, radix, pool);
+    let quarter = clenshaw(&self.atan, &one, scale, radix, pool);
+    let half = double(&quarter, radix, pool);
+    let pi = double(&half, radix, pool);
+    for f in [one, quarter, half] { f.clean(pool) }
+    pi
+  }
+
+  /// exp(x), rounded to the given scale
+  pub fn exp(&self, x: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> Result<Fixed, ErrorKind> {
+    let ln_radix = (radix as f64).ln();
+    let ln_x = if x.is_zero() { f64::NEG_INFINITY } else { x.ln_estimate(radix) };
+    if x.int.neg && ln_x.exp() > (scale + 1) as f64 * ln_radix { return Ok(zero(pool)) }
+    if !x.int.neg && ln_x.exp() > DIGITS_MAX * ln_radix { return Err(ErrorKind::OutOfBounds) }
+    // exp(x) = exp(x / 2^r)^(2^r) with |x / 2^r| <= 1
+    let r = if ln_x > 0.0 { (ln_x / std::f64::consts::LN_2).ceil() as usize } else { 0 };
+    let growth = if x.int.neg { 0.0 } else { ln_x.exp() };
+    let ws = scale + guard(radix) + ((growth + r as f64 * std::f64::consts::LN_2) / ln_radix).ceil() as usize;
+    let mut v = self.with_table(ws, radix, pool, |table, pool| {
+      let two = Fixed::from_isize(2, radix, pool);
+      let d = two.power(r as isize, 0, radix, pool).unwrap();
+      let y = x.quotient(&d, ws, radix, pool).unwrap();
+      let mut v = clenshaw(&table.exp, &y, ws, radix, pool);
+      for _ in 0..r {
+        let s = v.product(&v, ws, radix, pool);
+        replace(&mut v, s, pool);
+      }
+      for f in [two, d, y] { f.clean(pool) }
+      v
+    });
+    v.round(scale, radix);
+    v.trim();
+    Ok(v)
+  }
+
+  /// Returns (sin(x), cos(x)), rounded to the given scale
+  pub fn sin_cos(&self, x: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> (Fixed, Fixed) {
+    let ws = scale + guard(radix);
+    // 2 pi needs as many more limbs as x has integer limbs
+    let (m, e) = x.approx(radix);
+    let wp = ws + (digits(m, radix) as isize + e).max(0) as usize;
+    let (mut s, mut c) = self.with_table(wp, radix, pool, |table, pool| {
+      let pi = table.pi(wp, radix, pool);
+      let two_pi = double(&pi, radix, pool);
+      // reduce x to [-pi, pi]
+      let n = x.quotient(&two_pi, 0, radix, pool).unwrap();
+      let t = n.product(&two_pi, wp, radix, pool);
+      let mut y = x.difference(&t, radix, pool);
+      let neg_pi = { let mut p = pi.copy(pool); p.negate(); p };
+      if y.compare(&pi, pool) == Ordering::Greater {
+        let r = y.difference(&two_pi, radix, pool);
+        replace(&mut y, r, pool)
+      } else if y.compare(&neg_pi, pool) == Ordering::Less {
+        let r = y.sum(&two_pi, radix, pool);
+        replace(&mut y, r, pool)
+      }
+      // evaluate at y / 4 and double the angle twice
+      let four = Fixed::from_isize(4, radix, pool);
+      let z = y.quotient(&four, ws, radix, pool).unwrap();
+      let mut s = clenshaw(&table.sin, &z, ws, radix, pool);
+      let mut c = clenshaw(&table.cos, &z, ws, radix, pool);
+      for _ in 0..2 {
+        let sc = s.product(&c, ws, radix, pool);
+        let ss = s.product(&s, ws, radix, pool);
+        let cc = c.product(&c, ws, radix, pool);
+        replace(&mut s, double(&sc, radix, pool), pool);
+        replace(&mut c, cc.difference(&ss, radix, pool), pool);
+        for f in [sc, ss, cc] { f.clean(pool) }
+      }
+      for f in [pi, two_pi, n, t, y, neg_pi, four, z] { f.clean(pool) }
+      (s, c)
+    });
+    s.round(scale, radix);
+    s.trim();
+    c.round(scale, radix);
+    c.trim();
+    (s, c)
+  }
+
+  /// atan(x), rounded to the given scale
+  pub fn atan(&self, x: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> Fixed {
+    let ws = scale + guard(radix);
+    let mut v = self.with_table(ws, radix, pool, |table, pool| {
+      let one = Fixed::from_isize(1, radix, pool);
+      let v = if x.compare_mag(&one, pool) != Ordering::Greater {
+        clenshaw(&table.atan, x, ws, radix, pool)
+      } else {
+        // atan(x) = +-pi/2 - atan(1/x)
+        let inv = one.quotient(x, ws, radix, pool).unwrap();
+        let a = clenshaw(&table.atan, &inv, ws, radix, pool);
+        let pi = table.pi(ws, radix, pool);
+        let two = Fixed::from_isize(2, radix, pool);
+        let mut half_pi = pi.quotient(&two, ws, radix, pool).unwrap();
+        if x.int.neg { half_pi.negate() }
+        let v = half_pi.difference(&a, radix, pool);
+        for f in [inv, a, pi, two, half_pi] { f.clean(pool) }
+        v
+      };
+      one.clean(pool);
+      v
+    });
+    v.round(scale, radix);
+    v.trim();
+    v
+  }
+
+  /// ln(y) for y near one, by Newton's method on exp starting from the
+  /// floating point estimate z
+  fn ln_newton(&self, y: &Fixed, z: f64, ws: usize, radix: i32, pool: &mut Pool) -> Fixed {
+    let one = Fixed::from_isize(1, radix, pool);
+    let mut z = Fixed::from_f64(z, radix, pool);
+    // each step doubles the roughly 50 correct bits of the estimate
+    let bits = ws as f64 * (radix as f64).log2();
+    let steps = (bits / 40.0).log2().ceil().max(0.0) as usize + 1;
+    for _ in 0..steps {
+      // z += y exp(-z) - 1
+      let mut neg_z = z.copy(pool);
+      neg_z.negate();
+      let e = clenshaw(&self.exp, &neg_z, ws, radix, pool);
+      let ye = y.product(&e, ws, radix, pool);
+      let d = ye.difference(&one, radix, pool);
+      let r = z.sum(&d, radix, pool);
+      replace(&mut z, r, pool);
+      for f in [neg_z, e, ye, d] { f.clean(pool) }
+    }
+    one.clean(pool);
+    z
+  }
+
+  /// ln(x), rounded to the given scale. Assumes x is positive.
+  pub fn ln(&self, x: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> Fixed {
+    // ln(x) = ln(x / 2^k) + k ln(2) with x / 2^k near one
+    let ln_x = x.ln_estimate(radix);
+    let k = (ln_x / std::f64::consts::LN_2).round() as isize;
+    let ws = scale + guard(radix) + digits(k as f64, radix);
+    let mut v = self.with_table(ws, radix, pool, |table, pool| {
+      let two = Fixed::from_isize(2, radix, pool);
+      let d = two.power(k.abs(), 0, radix, pool).unwrap();
+      let y = if k >= 0 { x.quotient(&d, ws, radix, pool).unwrap() } else { x.product(&d, ws, radix, pool) };
+      let ln_y = table.ln_newton(&y, ln_x - k as f64 * std::f64::consts::LN_2, ws, radix, pool);
+      let ln_2 = table.ln_newton(&two, std::f64::consts::LN_2, ws, radix, pool);
+      let k = Fixed::from_isize(k, radix, pool);
+      let t = k.product(&ln_2, ws, radix, pool);
+      let v = ln_y.sum(&t, radix, pool);
+      for f in [two, d, y, ln_y, ln_2, k, t] { f.clean(pool) }
+      v
+    });
+    v.round(scale, radix);
+    v.trim();
+    v
+  }
+}
@@ -0,0 +1,133 @@
+use crate::*;
+
+use std::cmp::Ordering;
+
+/// Compound numeral. The components are separated by the meta delimiter,
+/// and each is a Cayley-Dickson number whose parts are separated by the
+/// Cayley-Dickson delimiter: 3,4 is 3+4i, 1,2,3,4 is the quaternion
+/// 1+2i+3j+4k, and 1;2,3 is a vector of the real 1 and the complex 2+3i. A
+/// plain number has one component of one part.
+pub struct Compound {
+  pub components: Vec<Vec<Fixed>>,
+}
+
+impl Compound {
+  pub fn clean(self, pool: &mut Pool) {
+    for c in self.components { clean_all(c, pool) }
+  }
+
+  pub fn max_scale(&self) -> usize {
+    self.components.iter().flatten().map(|f| f.scale).max().unwrap_or(0)
+  }
+
+  /// Truncates every part toward zero to at most scale fractional limbs
+  pub fn truncate(&mut self, scale: usize) {
+    for f in self.components.iter_mut().flatten() {
+      f.truncate(scale);
+      f.trim();
+    }
+  }
+
+  pub fn sum(&self, other: &Compound, radix: i32, pool: &mut Pool) -> Result<Compound, ErrorKind> {
+    self.componentwise(other, pool, |a, b, pool| {
+      parts_op(a, b, pool, |x, y, pool| x.sum(y, radix, pool))
+    })
+  }
+
+  pub fn difference(&self, other: &Compound, radix: i32, pool: &mut Pool) -> Result<Compound, ErrorKind> {
+    self.componentwise(other, pool, |a, b, pool| {
+      parts_op(a, b, pool, |x, y, pool| x.difference(y, radix, pool))
+    })
+  }
+
+  /// Exact product, multiplying each pair of components as Cayley-Dickson
+  /// numbers
+  pub fn product(&self, other: &Compound, radix: i32, pool: &mut Pool) -> Result<Compound, ErrorKind> {
+    self.componentwise(other, pool, |a, b, pool| {
+      let n = a.len().max(b.len()).next_power_of_two();
+      let (a, b) = (padded(a, n, pool), padded(b, n, pool));
+      let p = cd_product(&a, &b, radix, pool);
+      clean_all(a, pool);
+      clean_all(b, pool);
+      p
+    })
+  }
+
+  /// Components must agree in number, and parts that are missing from one
+  /// side compare equal to zero
+  pub fn equal(&self, other: &Compound, pool: &mut Pool) -> bool {
+    if self.components.len() != other.components.len() { return false }
+    self.components.iter().zip(other.components.iter()).all(|(a, b)| {
+      (0..a.len().max(b.len())).all(|i| match (a.get(i), b.get(i)) {
+        (Some(x), Some(y)) => x.compare(y, pool) == Ordering::Equal,
+        (Some(x), None) | (None, Some(x)) => x.is_zero(),
+        (None, None) => true
+      })
+    })
+  }
+
+  /// Applies f to each pair of components. A single component is paired
+  /// with every component of the other side.
+  fn componentwise<F>(&self, other: &Compound, pool: &mut Pool, f: F) -> Result<Compound, ErrorKind>
+  where F: Fn(&[Fixed], &[Fixed], &mut Pool) -> Vec<Fixed>
+  {
+    let (n1, n2) = (self.components.len(), other.components.len());
+    if n1 != n2 && n1 != 1 && n2 != 1 { return Err(ErrorKind::MathDimensionMismatch) }
+    let components = (0..n1.max(n2)).map(|i| {
+      f(&self.components[i.min(n1 - 1)], &other.components[i.min(n2 - 1)], pool)
+    }).collect();
+    Ok(Compound{ components })
+  }
+}
+
+fn clean_all(v: Vec<Fixed>, pool: &mut Pool) {
+  for f in v { f.clean(pool) }
+}
+
+fn zero(pool: &mut Pool) -> Fixed {
+  Fixed::from_int(Int::new(0, pool))
+}
+
+/// Copies the parts, padding with zeros to n parts
+fn padded(a: &[Fixed], n: usize, pool: &mut Pool) -> Vec<Fixed> {
+  (0..n).map(|i| match a.get(i) {
+    Some(f) => f.copy(pool),
+    None => zero(pool)
+  }).collect()
+}
+
+/// Applies f to each pair of parts, treating missing parts as zero
+fn parts_op<F>(a: &[Fixed], b: &[Fixed], pool: &mut Pool, f: F) -> Vec<Fixed>
+where F: Fn(&Fixed, &Fixed, &mut Pool) -> Fixed
+{
+  let z = zero(pool);
+  let parts = (0..a.len().max(b.len())).map(|i| {
+    f(a.get(i).unwrap_or(&z), b.get(i).unwrap_or(&z), pool)
+  }).collect();
+  z.clean(pool);
+  parts
+}
+
+fn conjugate(a: &[Fixed], pool: &mut Pool) -> Vec<Fixed> {
+  a.iter().enumerate().map(|(i, f)| {
+    let mut f = f.copy(pool);
+    if i != 0 { f.negate() }
+    f
+  }).collect()
+}
+
+/// (a, b)(c, d) = (ac - d*b, da + bc*), where the halves of each operand are
+/// Cayley-Dickson numbers of half the size. Assumes both operands have the
+/// same power of two length.
+fn cd_product(a: &[Fixed], b: &[Fixed], radix: i32, pool: &mut Pool) -> Vec<Fixed> {
+  if a.len() == 1 { return vec![a[0].product(&b[0], a[0].scale + b[0].scale, radix, pool)] }
+  let h = a.len() / 2;
+  let ((a1, a2), (b1, b2)) = (a.split_at(h), b.split_at(h));
+  let (b1c, b2c) = (conjugate(b1, pool), conjugate(b2, pool));
+  let (p1, p2) = (cd_product(a1, b1, radix, pool), cd_product(&b2c, a2, radix, pool));
+  let (p3, p4) = (cd_product(b2, a1, radix, pool), cd_product(a2, &b1c, radix, pool));
+  let mut parts = parts_op(&p1, &p2, pool, |x, y, pool| x.difference(y, radix, pool));
+  parts.extend(parts_op(&p3, &p4, pool, |x, y, pool| x.sum(y, radix, pool)));
+  for v in [b1c, b2c, p1, p2, p3, p4] { clean_all(v, pool) }
+  parts
+}
@@ -0,0 +1,225 @@
+use crate::*;
+
+use std::cmp::Ordering;
+
+/// Fixed point number with the value int / radix^scale, where the radix is
+/// the limb radix of int. Operations that cannot be exact are truncated
+/// toward zero at the scale requested by the caller.
+pub struct Fixed {
+  pub int: Int,
+  pub scale: usize,
+}
+
+impl Fixed {
+  pub fn from_int(int: Int) -> Fixed {
+    Fixed{ int, scale: 0 }
+  }
+
+  pub fn from_isize(i: isize, radix: i32, pool: &mut Pool) -> Fixed {
+    Fixed::from_int(Int::from_isize(i, radix, pool))
+  }
+
+  /// Nearest fixed point number to f with as many fractional limbs as fit
+  /// in the mantissa of an f64
+  pub fn from_f64(f: f64, radix: i32, pool: &mut Pool) -> Fixed {
+    let scale = ((50.0 / (radix as f64).log2()) as usize).max(1);
+    let i = (f * (radix as f64).powi(scale as i32)).round() as isize;
+    let mut fixed = Fixed{ int: Int::from_isize(i, radix, pool), scale };
+    fixed.trim();
+    fixed
+  }
+
+  /// Approximates the value as m * radix^e, where m is built from the
+  /// leading limbs
+  pub fn approx(&self, radix: i32) -> (f64, isize) {
+    let n = (64.0 / (radix as f64).log2()).ceil() as usize + 1;
+    let len = self.int.limbs.len();
+    let mut m = 0.0;
+    for l in self.int.limbs.iter().rev().take(n) {
+      m = m * radix as f64 + *l as f64;
+    }
+    if self.int.neg { m = -m }
+    (m, len.saturating_sub(n) as isize - self.scale as isize)
+  }
+
+  /// Natural logarithm of the magnitude, estimated in floating point
+  pub fn ln_estimate(&self, radix: i32) -> f64 {
+    let (m, e) = self.approx(radix);
+    m.abs().ln() + e as f64 * (radix as f64).ln()
+  }
+
+  pub fn copy(&self, pool: &mut Pool) -> Fixed {
+    Fixed{ int: self.int.copy(pool), scale: self.scale }
+  }
+
+  pub fn clean(self, pool: &mut Pool) {
+    self.int.clean(pool)
+  }
+
+  pub fn is_zero(&self) -> bool { self.int.is_zero() }
+
+  pub fn negate(&mut self) { self.int.negate() }
+
+  /// Removes trailing fractional zero limbs
+  pub fn trim(&mut self) {
+    self.int.trim();
+    let zeros = self.int.limbs.iter().take(self.scale).take_while(|l| **l == 0).count();
+    self.int.limbs.drain(0..zeros);
+    self.scale -= zeros;
+    if self.int.is_zero() { self.scale = 0 }
+  }
+
+  /// Truncates toward zero to at most scale fractional limbs
+  pub fn truncate(&mut self, scale: usize) {
+    if self.scale <= scale { return }
+    let n = (self.scale - scale).min(self.int.limbs.len());
+    self.int.limbs.drain(0..n);
+    self.scale = scale;
+    self.int.trim();
+  }
+
+  /// Rounds to at most scale fractional limbs, with halves away from zero
+  pub fn round(&mut self, scale: usize, radix: i32) {
+    if self.scale <= scale { return }
+    let n = self.scale - scale;
+    // the dropped limbs are at least half of radix^n if doubling them carries
+    let mut carry = 0;
+    for i in 0..n {
+      carry = (2 * self.int.limbs.get(i).unwrap_or(&0) + carry) / radix;
+    }
+    let neg = self.int.neg;
+    self.int.limbs.drain(0..n.min(self.int.limbs.len()));
+    self.scale = scale;
+    if carry != 0 {
+      let mut i = 0;
+      loop {
+        if i == self.int.limbs.len() { self.int.limbs.push(1); break }
+        self.int.limbs[i] += 1;
+        if self.int.limbs[i] != radix { break }
+        self.int.limbs[i] = 0;
+        i += 1;
+      }
+      self.int.neg = neg;
+    }
+    self.int.trim();
+  }
+
+  /// Copies the integer part multiplied by radix^(scale - self.scale),
+  /// truncating toward zero if scale is less than self.scale
+  fn scaled(&self, scale: usize, pool: &mut Pool) -> Int {
+    let mut int = Int::new(self.int.limbs.len() + scale.saturating_sub(self.scale), pool);
+    int.neg = self.int.neg;
+    if scale >= self.scale {
+      if !self.int.is_zero() {
+        int.limbs.resize(scale - self.scale, 0);
+        int.limbs.extend_from_slice(&self.int.limbs);
+      }
+    } else if self.int.limbs.len() > self.scale - scale {
+      int.limbs.extend_from_slice(&self.int.limbs[self.scale - scale..]);
+    }
+    int.trim();
+    int
+  }
+
+  /// Copies the value into another radix, truncated toward zero to the
+  /// given scale in the new radix
+  pub fn to_radix(&self, from: i32, to: i32, scale: usize, pool: &mut Pool) -> Fixed {
+    let int = self.int.to_radix(from, to, pool);
+    let mut fixed = Fixed{ int, scale: 0 };
+    if self.scale != 0 {
+      let den = Fixed{ int: Int::from_isize(from as isize, to, pool).power(self.scale, to, pool), scale: 0 };
+      let q = fixed.quotient(&den, scale, to, pool).unwrap();
+      den.clean(pool);
+      std::mem::replace(&mut fixed, q).clean(pool);
+    }
+    fixed.trim();
+    fixed
+  }
+
+  pub fn compare_mag(&self, other: &Fixed, pool: &mut Pool) -> Ordering {
+    if self.scale == other.scale { return self.int.compare_mag(&other.int) }
+    let scale = self.scale.max(other.scale);
+    let (a, b) = (self.scaled(scale, pool), other.scaled(scale, pool));
+    let ordering = a.compare_mag(&b);
+    a.clean(pool);
+    b.clean(pool);
+    ordering
+  }
+
+  pub fn compare(&self, other: &Fixed, pool: &mut Pool) -> Ordering {
+    if self.scale == other.scale { return self.int.compare(&other.int) }
+    let scale = self.scale.max(other.scale);
+    let (a, b) = (self.scaled(scale, pool), other.scaled(scale, pool));
+    let ordering = a.compare(&b);
+    a.clean(pool);
+    b.clean(pool);
+    ordering
+  }
+
+  pub fn sum(&self, other: &Fixed, radix: i32, pool: &mut Pool) -> Fixed {
+    let scale = self.scale.max(other.scale);
+    let (a, b) = (self.scaled(scale, pool), other.scaled(scale, pool));
+    let mut fixed = Fixed{ int: a.sum(&b, radix, pool), scale };
+    a.clean(pool);
+    b.clean(pool);
+    fixed.trim();
+    fixed
+  }
+
+  pub fn difference(&self, other: &Fixed, radix: i32, pool: &mut Pool) -> Fixed {
+    let scale = self.scale.max(other.scale);
+    let (a, b) = (self.scaled(scale, pool), other.scaled(scale, pool));
+    let mut fixed = Fixed{ int: a.difference(&b, radix, pool), scale };
+    a.clean(pool);
+    b.clean(pool);
+    fixed.trim();
+    fixed
+  }
+
+  pub fn product(&self, other: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> Fixed {
+    let mut fixed = Fixed{ int: self.int.product(&other.int, radix, pool), scale: self.scale + other.scale };
+    fixed.truncate(scale);
+    fixed.trim();
+    fixed
+  }
+
+  /// Quotient truncated to the given scale. Returns None if other is zero.
+  pub fn quotient(&self, other: &Fixed, scale: usize, radix: i32, pool: &mut Pool) -> Option<Fixed> {
+    if other.is_zero() { return None }
+    let num = self.scaled(scale + other.scale, pool);
+    let (q, r) = num.divmod(&other.int, radix, pool)?;
+    num.clean(pool);
+    r.clean(pool);
+    let mut fixed = Fixed{ int: q, scale };
+    fixed.trim();
+    Some(fixed)
+  }
+
+  /// Integer power truncated to the given scale. Returns None if self is
+  /// zero and exp is negative.
+  pub fn power(&self, exp: isize, scale: usize, radix: i32, pool: &mut Pool) -> Option<Fixed> {
+    let mut fixed = Fixed{
+      int: self.int.power(exp.unsigned_abs(), radix, pool),
+      scale: self.scale * exp.unsigned_abs()
+    };
+    if exp >= 0 {
+      fixed.truncate(scale);
+      fixed.trim();
+      return Some(fixed)
+    }
+    let one = Fixed::from_int(Int::from_isize(1, radix, pool));
+    let result = one.quotient(&fixed, scale, radix, pool);
+    one.clean(pool);
+    fixed.clean(pool);
+    result
+  }
+
+  /// Square root truncated to the given scale. Assumes self is nonnegative.
+  pub fn sqrt(&self, scale: usize, radix: i32, pool: &mut Pool) -> Fixed {
+    let int = self.scaled(scale * 2, pool);
+    let mut fixed = Fixed{ int: int.sqrt(radix, pool), scale };
+    int.clean(pool);
+    fixed.trim();
+    fixed
+  }
+}
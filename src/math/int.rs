@@ -0,0 +1,478 @@
+use crate::*;
+
+use std::cmp::Ordering;
+
+/// Arbitrary precision signed integer. The magnitude is stored as little-endian
+/// limbs in [0, radix), where the radix is supplied by the caller on every
+/// operation (see Math::limb_base). Zero is represented by an empty limb vector.
+pub struct Int {
+  pub neg: bool,
+  pub limbs: Vec<i32>,
+}
+
+impl Int {
+  pub fn new(capacity: usize, pool: &mut Pool) -> Int {
+    Int{ neg: false, limbs: pool.get_ints(capacity) }
+  }
+
+  pub fn from_isize(i: isize, radix: i32, pool: &mut Pool) -> Int {
+    let mut int = Int::new(DEFAULT_INT_LIMBS, pool);
+    int.neg = i < 0;
+    let mut u = i.unsigned_abs();
+    while u != 0 {
+      int.limbs.push((u % radix as usize) as i32);
+      u /= radix as usize;
+    }
+    int
+  }
+
+  pub fn to_isize(&self, radix: i32) -> Option<isize> {
+    let mut agg: isize = 0;
+    for l in self.limbs.iter().rev() {
+      agg = agg.checked_mul(radix as isize)?;
+      agg = if self.neg { agg.checked_sub(*l as isize)? } else { agg.checked_add(*l as isize)? };
+    }
+    Some(agg)
+  }
+
+  pub fn copy(&self, pool: &mut Pool) -> Int {
+    let mut int = Int::new(self.limbs.len(), pool);
+    int.neg = self.neg;
+    int.limbs.extend_from_slice(&self.limbs);
+    int
+  }
+
+  pub fn clean(self, pool: &mut Pool) {
+    pool.add_ints(self.limbs)
+  }
+
+  pub fn is_zero(&self) -> bool { self.limbs.is_empty() }
+
+  pub fn negate(&mut self) {
+    if !self.is_zero() { self.neg = !self.neg }
+  }
+
+  /// Removes leading zero limbs and normalizes the sign of zero
+  pub fn trim(&mut self) {
+    while self.limbs.last() == Some(&0) { self.limbs.pop(); }
+    if self.is_zero() { self.neg = false }
+  }
+
+  pub fn compare_mag(&self, other: &Int) -> Ordering {
+    cmp_mag(&self.limbs, &other.limbs)
+  }
+
+  pub fn compare(&self, other: &Int) -> Ordering {
+    match (self.neg, other.neg) {
+      (false, true) => Ordering::Greater,
+      (true, false) => Ordering::Less,
+      (false, false) => self.compare_mag(other),
+      (true, true) => other.compare_mag(self),
+    }
+  }
+
+  pub fn sum(&self, other: &Int, radix: i32, pool: &mut Pool) -> Int {
+    let mut int = Int::new(self.limbs.len().max(other.limbs.len()) + 1, pool);
+    if self.neg == other.neg {
+      add_mag(&self.limbs, &other.limbs, radix, &mut int.limbs);
+      int.neg = self.neg;
+    } else if self.compare_mag(other) != Ordering::Less {
+      sub_mag(&self.limbs, &other.limbs, radix, &mut int.limbs);
+      int.neg = self.neg;
+    } else {
+      sub_mag(&other.limbs, &self.limbs, radix, &mut int.limbs);
+      int.neg = other.neg;
+    }
+    int.trim();
+    int
+  }
+
+  pub fn difference(&self, other: &Int, radix: i32, pool: &mut Pool) -> Int {
+    let mut int = Int::new(self.limbs.len().max(other.limbs.len()) + 1, pool);
+    if self.neg != other.neg {
+      add_mag(&self.limbs, &other.limbs, radix, &mut int.limbs);
+      int.neg = self.neg;
+    } else if self.compare_mag(other) != Ordering::Less {
+      sub_mag(&self.limbs, &other.limbs, radix, &mut int.limbs);
+      int.neg = self.neg;
+    } else {
+      sub_mag(&other.limbs, &self.limbs, radix, &mut int.limbs);
+      int.neg = !self.neg;
+    }
+    int.trim();
+    int
+  }
+
+  pub fn product(&self, other: &Int, radix: i32, pool: &mut Pool) -> Int {
+    let mut int = Int::new(self.limbs.len() + other.limbs.len(), pool);
+    mul_mag(&self.limbs, &other.limbs, radix, &mut int.limbs);
+    int.neg = self.neg != other.neg;
+    int.trim();
+    int
+  }
+
+  /// Truncated division: the quotient rounds toward zero and the remainder
+  /// takes the sign of the dividend. Returns None if other is zero.
+  pub fn divmod(&self, other: &Int, radix: i32, pool: &mut Pool) -> Option<(Int, Int)> {
+    if other.is_zero() { return None }
+    let mut q = Int::new(self.limbs.len(), pool);
+    let mut r = Int::new(other.limbs.len(), pool);
+    divmod_mag(&self.limbs, &other.limbs, radix, &mut q.limbs, &mut r.limbs, pool);
+    q.neg = self.neg != other.neg;
+    r.neg = self.neg;
+    q.trim();
+    r.trim();
+    Some((q, r))
+  }
+
+  /// Copies the value into limbs of another radix
+  pub fn to_radix(&self, from: i32, to: i32, pool: &mut Pool) -> Int {
+    let mut int = Int::new(self.limbs.len(), pool);
+    for l in self.limbs.iter().rev() {
+      let mut carry = *l as i64;
+      for c in int.limbs.iter_mut() {
+        let t = *c as i64 * from as i64 + carry;
+        *c = (t % to as i64) as i32;
+        carry = t / to as i64;
+      }
+      while carry != 0 {
+        int.limbs.push((carry % to as i64) as i32);
+        carry /= to as i64;
+      }
+    }
+    int.neg = self.neg;
+    int.trim();
+    int
+  }
+
+  /// Floored division: the quotient rounds toward negative infinity and the
+  /// remainder takes the sign of the divisor. Returns None if other is zero.
+  pub fn floor_divmod(&self, other: &Int, radix: i32, pool: &mut Pool) -> Option<(Int, Int)> {
+    let (q, r) = self.divmod(other, radix, pool)?;
+    if r.is_zero() || r.neg == other.neg { return Some((q, r)) }
+    let one = Int::from_isize(1, radix, pool);
+    let q1 = q.difference(&one, radix, pool);
+    let r1 = r.sum(other, radix, pool);
+    one.clean(pool);
+    q.clean(pool);
+    r.clean(pool);
+    Some((q1, r1))
+  }
+
+  /// Nonnegative greatest common divisor, by Euclid's algorithm
+  pub fn gcd(&self, other: &Int, radix: i32, pool: &mut Pool) -> Int {
+    let mut a = self.copy(pool);
+    let mut b = other.copy(pool);
+    while !b.is_zero() {
+      let (q, r) = a.divmod(&b, radix, pool).unwrap();
+      q.clean(pool);
+      std::mem::replace(&mut a, std::mem::replace(&mut b, r)).clean(pool);
+    }
+    b.clean(pool);
+    a.neg = false;
+    a
+  }
+
+  pub fn power(&self, mut exp: usize, radix: i32, pool: &mut Pool) -> Int {
+    let mut result = Int::from_isize(1, radix, pool);
+    let mut base = self.copy(pool);
+    while exp != 0 {
+      if exp & 1 == 1 {
+        let tmp = result.product(&base, radix, pool);
+        std::mem::replace(&mut result, tmp).clean(pool);
+      }
+      exp >>= 1;
+      if exp != 0 {
+        let tmp = base.product(&base, radix, pool);
+        std::mem::replace(&mut base, tmp).clean(pool);
+      }
+    }
+    base.clean(pool);
+    result
+  }
+
+  /// Applies f to each pair of aligned bits of the two's complement
+  /// representations, which are sign-extended indefinitely
+  pub fn bitwise<F>(&self, other: &Int, radix: i32, pool: &mut Pool, f: F) -> Int
+  where F: Fn(i32, i32) -> i32
+  {
+    let (mut a, fill_a) = self.to_twos(radix, pool);
+    let (b, fill_b) = other.to_twos(radix, pool);
+    for i in 0..a.len().max(b.len()) {
+      let x = f(*a.get(i).unwrap_or(&fill_a), *b.get(i).unwrap_or(&fill_b)) & BIT_MASK;
+      if i < a.len() { a[i] = x } else { a.push(x) }
+    }
+    let fill = f(fill_a, fill_b) & BIT_MASK;
+    pool.add_ints(b);
+    Int::from_twos(a, fill, radix, pool)
+  }
+
+  /// Bitwise complement, which is -self - 1
+  pub fn bitnot(&self, radix: i32, pool: &mut Pool) -> Int {
+    let one = Int::from_isize(1, radix, pool);
+    let mut int = self.sum(&one, radix, pool);
+    one.clean(pool);
+    int.negate();
+    int
+  }
+
+  /// Multiplies by 2^n, shifting the two's complement chunks up
+  pub fn shl(&self, n: usize, radix: i32, pool: &mut Pool) -> Int {
+    let (b, fill) = self.to_twos(radix, pool);
+    let (chunks, bits) = (n / BIT_CHUNK as usize, (n % BIT_CHUNK as usize) as u32);
+    let mut s = pool.get_ints(chunks + b.len() + 1);
+    s.resize(chunks, 0);
+    let mut carry = 0;
+    for c in b.iter().chain(std::iter::once(&fill)) {
+      let t = (*c as i64) << bits;
+      s.push((t as i32 & BIT_MASK) | carry);
+      carry = (t >> BIT_CHUNK) as i32;
+    }
+    pool.add_ints(b);
+    Int::from_twos(s, fill, radix, pool)
+  }
+
+  /// Divides by 2^n, rounding toward negative infinity, by shifting the two's
+  /// complement chunks down
+  pub fn shr(&self, n: usize, radix: i32, pool: &mut Pool) -> Int {
+    let (b, fill) = self.to_twos(radix, pool);
+    let (chunks, bits) = (n / BIT_CHUNK as usize, (n % BIT_CHUNK as usize) as u32);
+    let mut s = pool.get_ints(b.len().saturating_sub(chunks));
+    for i in chunks..b.len() {
+      let t = (b[i] as i64 | (*b.get(i + 1).unwrap_or(&fill) as i64) << BIT_CHUNK) >> bits;
+      s.push(t as i32 & BIT_MASK);
+    }
+    pool.add_ints(b);
+    Int::from_twos(s, fill, radix, pool)
+  }
+
+  /// Number of one bits in the magnitude
+  pub fn popcount(&self, radix: i32, pool: &mut Pool) -> usize {
+    let mut b = pool.get_ints(self.limbs.len());
+    to_binary(&self.limbs, radix, &mut b);
+    let count = b.iter().map(|c| c.count_ones() as usize).sum();
+    pool.add_ints(b);
+    count
+  }
+
+  /// Two's complement binary chunks, with the chunk that repeats above them
+  fn to_twos(&self, radix: i32, pool: &mut Pool) -> (Vec<i32>, i32) {
+    let mut b = pool.get_ints(self.limbs.len());
+    to_binary(&self.limbs, radix, &mut b);
+    if !self.neg { return (b, 0) }
+    // -x == !(x - 1)
+    for c in b.iter_mut() {
+      if *c != 0 { *c -= 1; break }
+      *c = BIT_MASK;
+    }
+    for c in b.iter_mut() { *c ^= BIT_MASK }
+    (b, BIT_MASK)
+  }
+
+  fn from_twos(mut b: Vec<i32>, fill: i32, radix: i32, pool: &mut Pool) -> Int {
+    let neg = fill != 0;
+    if neg {
+      for c in b.iter_mut() { *c ^= BIT_MASK }
+      let mut carry = true;
+      for c in b.iter_mut() {
+        if *c != BIT_MASK { *c += 1; carry = false; break }
+        *c = 0;
+      }
+      if carry { b.push(1) }
+    }
+    let mut int = Int::new(b.len(), pool);
+    from_binary(&mut b, radix, &mut int.limbs);
+    pool.add_ints(b);
+    int.neg = neg;
+    int.trim();
+    int
+  }
+
+  /// Floor of the square root, by Newton's method. Assumes self is nonnegative.
+  pub fn sqrt(&self, radix: i32, pool: &mut Pool) -> Int {
+    let mut x = Int::new(self.limbs.len() / 2 + 2, pool);
+    if self.is_zero() { return x }
+    // radix^ceil(len/2) is above the root
+    x.limbs.resize(self.limbs.len().div_ceil(2), 0);
+    x.limbs.push(1);
+    let two = Int::from_isize(2, radix, pool);
+    loop {
+      let (q, r) = self.divmod(&x, radix, pool).unwrap();
+      r.clean(pool);
+      let s = x.sum(&q, radix, pool);
+      q.clean(pool);
+      let (y, r) = s.divmod(&two, radix, pool).unwrap();
+      s.clean(pool);
+      r.clean(pool);
+      if y.compare(&x) != Ordering::Less {
+        y.clean(pool);
+        break
+      }
+      std::mem::replace(&mut x, y).clean(pool);
+    }
+    two.clean(pool);
+    x
+  }
+}
+
+const BIT_CHUNK: u32 = 30;
+const BIT_MASK: i32 = (1 << BIT_CHUNK) - 1;
+
+/// Converts a magnitude from limbs in the given radix to chunks of BIT_CHUNK bits
+fn to_binary(a: &[i32], radix: i32, r: &mut Vec<i32>) {
+  for l in a.iter().rev() {
+    let mut carry = *l as i64;
+    for c in r.iter_mut() {
+      let t = *c as i64 * radix as i64 + carry;
+      *c = (t & BIT_MASK as i64) as i32;
+      carry = t >> BIT_CHUNK;
+    }
+    while carry != 0 {
+      r.push((carry & BIT_MASK as i64) as i32);
+      carry >>= BIT_CHUNK;
+    }
+  }
+}
+
+/// Converts chunks of BIT_CHUNK bits to limbs in the given radix, consuming b
+fn from_binary(b: &mut [i32], radix: i32, r: &mut Vec<i32>) {
+  let mut len = b.len();
+  while len != 0 && b[len - 1] == 0 { len -= 1 }
+  while len != 0 {
+    let mut rem: i64 = 0;
+    for c in b[..len].iter_mut().rev() {
+      let t = (rem << BIT_CHUNK) + *c as i64;
+      *c = (t / radix as i64) as i32;
+      rem = t % radix as i64;
+    }
+    r.push(rem as i32);
+    while len != 0 && b[len - 1] == 0 { len -= 1 }
+  }
+}
+
+fn cmp_mag(a: &[i32], b: &[i32]) -> Ordering {
+  if a.len() != b.len() { return a.len().cmp(&b.len()) }
+  for (x, y) in a.iter().rev().zip(b.iter().rev()) {
+    if x != y { return x.cmp(y) }
+  }
+  Ordering::Equal
+}
+
+fn add_mag(a: &[i32], b: &[i32], radix: i32, r: &mut Vec<i32>) {
+  let mut carry = 0;
+  for i in 0..a.len().max(b.len()) {
+    let t = a.get(i).unwrap_or(&0) + b.get(i).unwrap_or(&0) + carry;
+    if t >= radix { r.push(t - radix); carry = 1 }
+    else { r.push(t); carry = 0 }
+  }
+  if carry != 0 { r.push(carry) }
+}
+
+/// Assumes |a| >= |b|
+fn sub_mag(a: &[i32], b: &[i32], radix: i32, r: &mut Vec<i32>) {
+  let mut borrow = 0;
+  for (i, x) in a.iter().enumerate() {
+    let t = x - b.get(i).unwrap_or(&0) - borrow;
+    if t < 0 { r.push(t + radix); borrow = 1 }
+    else { r.push(t); borrow = 0 }
+  }
+}
+
+fn mul_mag(a: &[i32], b: &[i32], radix: i32, r: &mut Vec<i32>) {
+  if a.is_empty() || b.is_empty() { return }
+  let radix = radix as i64;
+  r.resize(a.len() + b.len(), 0);
+  for (i, x) in a.iter().enumerate() {
+    let mut carry: i64 = 0;
+    for (j, y) in b.iter().enumerate() {
+      let t = r[i + j] as i64 + *x as i64 * *y as i64 + carry;
+      r[i + j] = (t % radix) as i32;
+      carry = t / radix;
+    }
+    r[i + b.len()] = carry as i32;
+  }
+}
+
+/// Divides a in place by a single limb, returning the remainder
+fn divmod_small(a: &mut [i32], d: i32, radix: i32) -> i32 {
+  let mut rem: i64 = 0;
+  for l in a.iter_mut().rev() {
+    let t = rem * radix as i64 + *l as i64;
+    *l = (t / d as i64) as i32;
+    rem = t % d as i64;
+  }
+  rem as i32
+}
+
+fn mul_small(a: &[i32], m: i32, radix: i32, r: &mut Vec<i32>) {
+  let mut carry: i64 = 0;
+  for l in a.iter() {
+    let t = *l as i64 * m as i64 + carry;
+    r.push((t % radix as i64) as i32);
+    carry = t / radix as i64;
+  }
+  r.push(carry as i32);
+}
+
+/// Schoolbook long division (Knuth, TAOCP vol. 2, algorithm D) in an arbitrary radix
+fn divmod_mag(u: &[i32], v: &[i32], radix: i32, q: &mut Vec<i32>, r: &mut Vec<i32>, pool: &mut Pool) {
+  if cmp_mag(u, v) == Ordering::Less {
+    r.extend_from_slice(u);
+    return
+  }
+  if v.len() == 1 {
+    q.extend_from_slice(u);
+    let rem = divmod_small(q, v[0], radix);
+    r.push(rem);
+    return
+  }
+  let n = v.len();
+  let m = u.len() - n;
+  let b = radix as i64;
+  // normalize so that the leading limb of the divisor is at least radix / 2
+  let d = radix / (v[n - 1] + 1);
+  let mut un = pool.get_ints(u.len() + 1);
+  let mut vn = pool.get_ints(n + 1);
+  mul_small(u, d, radix, &mut un);
+  mul_small(v, d, radix, &mut vn);
+  vn.pop();
+  q.resize(m + 1, 0);
+  for j in (0..=m).rev() {
+    let num = un[j + n] as i64 * b + un[j + n - 1] as i64;
+    let mut qhat = num / vn[n - 1] as i64;
+    let mut rhat = num % vn[n - 1] as i64;
+    while qhat >= b || qhat * vn[n - 2] as i64 > b * rhat + un[j + n - 2] as i64 {
+      qhat -= 1;
+      rhat += vn[n - 1] as i64;
+      if rhat >= b { break }
+    }
+    // multiply and subtract
+    let mut borrow: i64 = 0;
+    for i in 0..n {
+      let p = qhat * vn[i] as i64;
+      let t = un[i + j] as i64 - borrow - p % b;
+      un[i + j] = t.rem_euclid(b) as i32;
+      borrow = p / b - t.div_euclid(b);
+    }
+    let t = un[j + n] as i64 - borrow;
+    if t < 0 {
+      // qhat was one too large; add the divisor back
+      qhat -= 1;
+      let mut carry: i64 = 0;
+      for i in 0..n {
+        let s = un[i + j] as i64 + vn[i] as i64 + carry;
+        un[i + j] = (s % b) as i32;
+        carry = s / b;
+      }
+      un[j + n] = (t + carry) as i32;
+    } else {
+      un[j + n] = t as i32;
+    }
+    q[j] = qhat as i32;
+  }
+  un.truncate(n);
+  divmod_small(&mut un, d, radix);
+  r.extend_from_slice(&un);
+  pool.add_ints(un);
+  pool.add_ints(vn);
+}
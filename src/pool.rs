@@ -120,6 +120,9 @@ pub struct Pool {
   nodes: Nodes,
 
   pub custom_pools: HashMap<String, CustomPool>,
+
+  pub hits: usize, // requests served from the pool
+  pub misses: usize, // requests that had to allocate
 }
 
 trait DisregardPool {
@@ -168,10 +171,12 @@ macro_rules! pool_remove {
       if let Some($var) = tree.remove_at_least($capacity, $drop_node, $destruct, $self) {
         $mod;
         $tree = Some(tree);
+        $self.hits += 1;
         return $retval;
       }
       $tree = Some(tree);
     }
+    $self.misses += 1;
   };
 }
 macro_rules! pool_remove_val {
@@ -181,31 +186,37 @@ macro_rules! pool_remove_val {
         let $letpattern = retval else { panic!("Bad value type in pool tree") };
         $mod;
         $tree = Some(tree);
+        $self.hits += 1;
         return $retval;
       }
       $tree = Some(tree);
     }
+    $self.misses += 1;
   }
 }
 macro_rules! pool_pop {
-  ($stack:expr,$var:pat,$retval:tt,$mod:block) => {
+  ($self:ident,$stack:expr,$var:pat,$retval:tt,$mod:block) => {
     if let Some(stack) = &mut $stack {
       if let Some($var) = stack.pop() {
         $mod;
+        $self.hits += 1;
         return $retval;
       }
     }
+    $self.misses += 1;
   };
 }
 macro_rules! pool_pop_val {
-  ($stack:expr,$letpattern:pat,$retval:tt,$mod:block) => {
+  ($self:ident,$stack:expr,$letpattern:pat,$retval:tt,$mod:block) => {
     if let Some(stack) = &mut $stack {
       if let Some(v) = stack.pop() {
         let $letpattern = v else { panic!("Bad value type in pool vec") };
         $mod;
+        $self.hits += 1;
         return $retval;
       }
     }
+    $self.misses += 1;
   };
 }
 macro_rules! pool_pop_node {
@@ -267,6 +278,9 @@ impl Pool {
       nodes: Nodes::new(),
 
       custom_pools: HashMap::new(),
+
+      hits: 0,
+      misses: 0,
     }
   }
 
@@ -723,6 +737,7 @@ impl Pool {
   pub fn get_vword(&mut self, capacity: usize) -> Box<VWord> {
     pool_remove_val!(self, self.vwords, capacity, Value::Word(mut vword), vword, {
       vword.str_word.clear();
+      vword.loc = None;
     });
     Box::new(VWord::with_capacity(capacity))
   }
@@ -759,6 +774,11 @@ impl Pool {
       if container.word_table.is_some() {
         self.add_word_table(container.word_table.take().unwrap());
       }
+      if let Some(on_error) = container.on_error.take() {
+        self.add_word_def(on_error);
+      }
+      container.errors_pending = 0;
+      container.handling_errors = false;
     });
     Box::new(VStack::with_container(Container::with_stack(self.get_stack(capacity))))
   }
@@ -771,12 +791,16 @@ impl Pool {
   pub fn get_verror(&mut self, capacity: usize) -> Box<VError> {
     pool_remove_val!(self, self.verrors, capacity, Value::Error(mut verror), verror, {
       verror.error.clear();
+      verror.kind = ErrorKind::Other;
       if let Some(word) = verror.str_word.take() {
         self.add_string(word);
       }
       if let Some(loc) = verror.loc.take() {
         self.add_verror_loc(loc);
       }
+      while let Some(word) = verror.backtrace.pop() {
+        self.add_string(word);
+      }
     });
     Box::new(VError::with_capacity(capacity))
   }
@@ -791,7 +815,7 @@ impl Pool {
                column: self.get_string(4) }
   }
   pub fn get_vfllib(&mut self, f: CognitionFunction) -> Box<VFLLib> {
-    pool_pop_val!(self.vfllibs, Value::FLLib(mut vfllib), vfllib, {
+    pool_pop_val!(self, self.vfllibs, Value::FLLib(mut vfllib), vfllib, {
       if let Some(s) = vfllib.str_word.take() {
         self.add_string(s)
       }
@@ -868,30 +892,30 @@ impl Pool {
     WordTable::with_capacity(capacity)
   }
   pub fn get_word_def(&mut self, v: Value) -> WordDef {
-    pool_pop!(self.word_defs, mut wd, wd, {
+    pool_pop!(self, self.word_defs, mut wd, wd, {
       *(Arc::<Value>::get_mut(&mut wd).expect("unauthorized references to word def in pool")) = v;
     });
     WordDef::from(v)
   }
   pub fn get_family(&mut self) -> Family {
-    pool_pop!(self.families, mut family, family, { family.clear(); });
+    pool_pop!(self, self.families, mut family, family, { family.clear(); });
     Family::with_capacity(DEFAULT_STACK_SIZE)
   }
 
   pub fn get_un_op(&mut self) -> UnaryOp {
-    pool_pop!(self.un_ops, mut op, op, { op.drain() });
+    pool_pop!(self, self.un_ops, mut op, op, { op.drain() });
     UnaryOp::new()
   }
   pub fn get_bin_op(&mut self) -> BinaryOp {
-    pool_pop!(self.bin_ops, mut op, op, { op.drain() });
+    pool_pop!(self, self.bin_ops, mut op, op, { op.drain() });
     BinaryOp::new()
   }
   pub fn get_str_op(&mut self) -> StrOp {
-    pool_pop!(self.str_ops, mut op, op, { op.drain() });
+    pool_pop!(self, self.str_ops, mut op, op, { op.drain() });
     StrOp::new()
   }
   pub fn get_custom_op(&mut self) -> CustomOp {
-    pool_pop!(self.custom_ops, mut op, op, { op.drain() });
+    pool_pop!(self, self.custom_ops, mut op, op, { op.drain() });
     CustomOp::new()
   }
 
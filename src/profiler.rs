@@ -0,0 +1,127 @@
+use crate::*;
+
+use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// Totals for one word or foreign library function
+#[derive(Clone, Copy, Default)]
+pub struct ProfileEntry {
+  pub calls: usize,
+  pub inclusive: Duration, // with everything it called; recursive calls are only counted once
+  pub exclusive: Duration, // spent in its own values
+}
+
+struct ProfileFrame {
+  chain: usize,
+  start: Instant,
+  children: Duration,
+}
+
+// a call chain, interned the first time it is entered
+struct ProfileChain {
+  name: String, // of its innermost frame
+  path: String, // the names of its frames joined with ';'
+  recursive: bool, // whether its innermost name is also further out
+  parent: usize,
+  time: Duration, // exclusive time spent in it
+  children: HashMap<String, usize>,
+}
+
+/// Per-definition call counts and timings, collected while installed as
+/// CognitionState::profiler. Words are keyed by name and foreign library
+/// functions by their str_word (or "fllib" if unnamed) followed by "()".
+pub struct Profiler {
+  pub entries: HashMap<String, ProfileEntry>,
+  chains: Vec<ProfileChain>, // the first is the empty chain everything starts from
+  frames: Vec<ProfileFrame>,
+}
+
+impl Default for Profiler {
+  fn default() -> Self {
+    let root = ProfileChain{ name: String::new(), path: String::new(), recursive: false, parent: 0,
+                             time: Duration::ZERO, children: HashMap::new() };
+    Self{ entries: HashMap::new(), chains: vec![root], frames: Vec::new() }
+  }
+}
+
+impl Profiler {
+  pub fn new() -> Self { Self::default() }
+
+  pub fn enter(&mut self, name: &str) {
+    let parent = self.frames.last().map_or(0, |f| f.chain);
+    let chain = match self.chains[parent].children.get(name) {
+      Some(&chain) => chain,
+      None => self.add_chain(parent, name)
+    };
+    self.frames.push(ProfileFrame{ chain, start: Instant::now(), children: Duration::ZERO });
+  }
+
+  fn add_chain(&mut self, parent: usize, name: &str) -> usize {
+    let mut path = self.chains[parent].path.clone();
+    if parent != 0 { path.push(';') }
+    // ';' separates the frames, so it cannot appear in a name
+    path.extend(name.chars().map(|c| if c == ';' { ':' } else { c }));
+    let mut recursive = false;
+    let mut outer = parent;
+    while outer != 0 && !recursive {
+      recursive = self.chains[outer].name == name;
+      outer = self.chains[outer].parent;
+    }
+    let chain = self.chains.len();
+    self.chains.push(ProfileChain{ name: name.to_string(), path, recursive, parent, time: Duration::ZERO,
+                                   children: HashMap::new() });
+    self.chains[parent].children.insert(name.to_string(), chain);
+    chain
+  }
+
+  pub fn exit(&mut self) {
+    let Some(frame) = self.frames.pop() else { return };
+    let elapsed = frame.start.elapsed();
+    let exclusive = elapsed.saturating_sub(frame.children);
+    if let Some(parent) = self.frames.last_mut() { parent.children += elapsed }
+
+    let chain = &mut self.chains[frame.chain];
+    chain.time += exclusive;
+    let entry = match self.entries.get_mut(&chain.name) {
+      Some(entry) => entry,
+      None => self.entries.entry(chain.name.clone()).or_default()
+    };
+    entry.calls += 1;
+    entry.exclusive += exclusive;
+    if !chain.recursive { entry.inclusive += elapsed }
+  }
+
+  /// Closes anything still open, as when evaluation was cut short
+  pub fn finish(&mut self) {
+    while !self.frames.is_empty() { self.exit() }
+  }
+
+  /// Entries sorted by inclusive time, longest first
+  pub fn sorted(&self) -> Vec<(&String, &ProfileEntry)> {
+    let mut entries: Vec<(&String, &ProfileEntry)> = self.entries.iter().collect();
+    entries.sort_by(|a, b| b.1.inclusive.cmp(&a.1.inclusive).then(a.0.cmp(b.0)));
+    entries
+  }
+
+  /// Writes a table of every entry, times in microseconds, followed by the
+  /// pool's hit and miss counts
+  pub fn report(&self, f: &mut dyn Write, pool: &Pool) -> std::io::Result<()> {
+    writeln!(f, "{:>10} {:>14} {:>14}  word", "calls", "inclusive(us)", "exclusive(us)")?;
+    for (name, entry) in self.sorted() {
+      writeln!(f, "{:>10} {:>14} {:>14}  '{name}'", entry.calls, entry.inclusive.as_micros(), entry.exclusive.as_micros())?;
+    }
+    writeln!(f, "\npool hits: {}, pool misses: {}", pool.hits, pool.misses)
+  }
+
+  /// Writes the call chains in the collapsed stack format read by flamegraph
+  /// tools, weighted in microseconds
+  pub fn collapsed(&self, f: &mut dyn Write) -> std::io::Result<()> {
+    let mut stacks: Vec<(&String, &Duration)> = self.chains[1..].iter().map(|c| (&c.path, &c.time)).collect();
+    stacks.sort();
+    for (path, time) in stacks {
+      let path: String = path.chars().map(|c| if c.is_whitespace() { '_' } else { c }).collect();
+      writeln!(f, "{path} {}", time.as_micros())?;
+    }
+    Ok(())
+  }
+}
@@ -374,7 +374,7 @@ impl Serialize for Container {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where S: Serializer
   {
-    let mut ser = serializer.serialize_struct("Container", 12)?;
+    let mut ser = serializer.serialize_struct("Container", 13)?;
     ser.serialize_field("stack", &self.stack)?;
     ser.serialize_field("err_stack", &self.err_stack)?;
     ser.serialize_field("cranks", &self.cranks)?;
@@ -391,6 +391,7 @@ impl Serialize for Container {
       None => None
     };
     ser.serialize_field("word_table", &word_table)?;
+    ser.serialize_field("on_error", &self.on_error.as_ref().map(ArcWrap))?;
     ser.end()
   }
 }
@@ -422,7 +423,7 @@ impl Serialize for Math {
   fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
   where S: Serializer
   {
-    let mut ser = serializer.serialize_struct("Math", 10)?;
+    let mut ser = serializer.serialize_struct("Math", 11)?;
     ser.serialize_field("base", &self.base)?;
     ser.serialize_field("digits", &self.digits)?;
     ser.serialize_field("d_idx", &MapWrap(&self.d_idx))?;
@@ -433,6 +434,7 @@ impl Serialize for Math {
     ser.serialize_field("delim", &self.delim)?;
     ser.serialize_field("meta_radix", &self.meta_radix)?;
     ser.serialize_field("meta_delim", &self.meta_delim)?;
+    ser.serialize_field("precision", &self.precision)?;
     ser.end()
   }
 }
@@ -610,8 +612,14 @@ macro_rules! impl_cognition_deserialize_for_deserialize {
   }
 }
 
+// a field given a default can be left out, as by states saved before it was added
+macro_rules! field_or_default {
+  ($field:expr,$missing:expr) => { $field.ok_or_else(|| $missing)? };
+  ($field:expr,$missing:expr,$default:expr) => { $field.unwrap_or($default) };
+}
+
 macro_rules! impl_cognition_deserialize_struct {
-  ($type:ty,$typename:literal,$state:tt,$from:block $([$capital:tt,$lower:tt,$name:literal,$subtype:ty]),*) => {
+  ($type:ty,$typename:literal,$state:tt,$from:block $([$capital:tt,$lower:tt,$name:literal,$subtype:ty $(= $default:expr)?]),*) => {
     impl <'de> CognitionDeserialize<'de> for $type {
       fn cognition_deserialize<D>(deserializer: D, state: &mut CognitionState) -> Result<Self, D::Error>
       where
@@ -639,8 +647,8 @@ macro_rules! impl_cognition_deserialize_struct {
           {
             $(
               let cogseed = CognitionDeserializeSeed::<$subtype>::new(self.state);
-              let $lower = seq.next_element_seed(cogseed)?
-                .ok_or_else(|| de::Error::invalid_length(0, &self))?; // ${index()} is still unstable
+              let $lower = field_or_default!(seq.next_element_seed(cogseed)?,
+                de::Error::invalid_length(0, &self) $(, $default)?); // ${index()} is still unstable
             )*
             let $state = self.state;
             $from
@@ -666,7 +674,7 @@ macro_rules! impl_cognition_deserialize_struct {
               }
             }
             $(
-              let $lower = $lower.ok_or_else(|| de::Error::missing_field($name))?;
+              let $lower = field_or_default!($lower, de::Error::missing_field($name) $(, $default)?);
             )*
             let $state = self.state;
             $from
@@ -679,8 +687,8 @@ macro_rules! impl_cognition_deserialize_struct {
       }
     }
   };
-  ($type:ty,$typename:literal,$from:block $([$capital:tt,$lower:tt,$name:literal,$subtype:ty])*) => {
-    impl_cognition_deserialize_struct!{ $type, $typename, _state, $from $([$capital,$lower,$name,$subtype])* }
+  ($type:ty,$typename:literal,$from:block $([$capital:tt,$lower:tt,$name:literal,$subtype:ty $(= $default:expr)?])*) => {
+    impl_cognition_deserialize_struct!{ $type, $typename, _state, $from $([$capital,$lower,$name,$subtype $(= $default)?])* }
   }
 }
 
@@ -858,7 +866,7 @@ macro_rules! impl_cognition_deserialize_map_as_vec {
 }
 
 impl_cognition_deserialize_for_deserialize! {
-  bool, u32, i32, char, String,
+  bool, u32, i32, usize, char, String,
   (i32, i32), (char, i32), (char, char),
   ((i32, i32), (i32, i32)),
   ((char, char), (i32, i32)),
@@ -880,6 +888,7 @@ impl_cognition_deserialize_option!{ Library, "Option<Library>" }
 impl_cognition_deserialize_option!{ Stack, "Option<Stack>" }
 impl_cognition_deserialize_option!{ WordTable, "Option<WordTable>" }
 impl_cognition_deserialize_option!{ Math, "Option<Math>" }
+impl_cognition_deserialize_option!{ WordDef, "Option<WordDef>" }
 
 impl_cognition_deserialize_table! {
   WordDef, state, state, map, len,
@@ -982,6 +991,7 @@ impl_cognition_deserialize_struct! {
     container.iflag = iflag;
     container.sflag = sflag;
     container.word_table = word_table;
+    container.on_error = on_error;
     Ok(container)
   }
   [Stack, stack, "stack", Stack],
@@ -995,7 +1005,8 @@ impl_cognition_deserialize_struct! {
   [Dflag, dflag, "dflag", bool],
   [Iflag, iflag, "iflag", bool],
   [Sflag, sflag, "sflag", bool],
-  [WordTable, word_table, "word_table", Option<WordTable>]
+  [WordTable, word_table, "word_table", Option<WordTable>],
+  [OnError, on_error, "on_error", Option<WordDef> = None]
 }
 
 impl_cognition_deserialize_struct! {
@@ -1011,6 +1022,8 @@ impl_cognition_deserialize_struct! {
     math.delim = delim;
     math.meta_radix = meta_radix;
     math.meta_delim = meta_delim;
+    math.precision = precision;
+    math.init_chebyshev();
     Ok(math)
   }
   [Base, base, "base", i32],
@@ -1022,7 +1035,8 @@ impl_cognition_deserialize_struct! {
   [Radix, radix, "radix", Option<char>],
   [Delim, delim, "delim", Option<char>],
   [MetaRadix, meta_radix, "meta_radix", Option<char>],
-  [MetaDelim, meta_delim, "meta_delim", Option<char>]
+  [MetaDelim, meta_delim, "meta_delim", Option<char>],
+  [Precision, precision, "precision", usize = 0]
 }
 
 impl_cognition_deserialize_struct! {
@@ -1124,14 +1138,14 @@ where S: Serializer
   map.end()
 }
 
-pub fn serde_load_fllibs<'de, D>(deserializer: D, state: &mut CognitionState) -> Result<Option<&'static str>, D::Error>
+pub fn serde_load_fllibs<'de, D>(deserializer: D, state: &mut CognitionState) -> Result<Option<ErrorKind>, D::Error>
 where D: Deserializer<'de>
 {
   struct CognitionVisitor<'s> {
     state: &'s mut CognitionState
   }
   impl<'s, 'de: 's> Visitor<'de> for CognitionVisitor<'s> {
-    type Value = Option<&'static str>;
+    type Value = Option<ErrorKind>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
       formatter.write_str("Foreign Library Descriptor Map")
@@ -1154,11 +1168,13 @@ where D: Deserializer<'de>
             return Err(e)
           }
         };
+        // checked before opening, since opening a library already runs its initializers
+        if !self.state.capabilities.has(Capability::Fllib) { return Ok(Some(ErrorKind::PermissionDenied)) }
         let Ok(lib) = (unsafe { libloading::Library::new(&value) }) else {
-          return Ok(Some("INVALID FILENAME"))
+          return Ok(Some(ErrorKind::InvalidFilename))
         };
         if (unsafe { lib.get::<libloading::Symbol<AddWordsFn>>(b"add_words\0") }).is_err() {
-          return Ok(Some("INVALID FLLIB"))
+          return Ok(Some(ErrorKind::InvalidFllib))
         };
         names.push(key);
         vals.push(value);
@@ -1233,7 +1249,7 @@ where
 {
   D(D::Error),
   F(F::Error),
-  S(&'static str)
+  S(ErrorKind)
 }
 
 impl<'de, D, F> fmt::Display for TripleErr<'de, D, F>
@@ -0,0 +1,94 @@
+use crate::*;
+
+/// An in-memory copy of a CognitionState's metastack, chroots, family and
+/// parser, taken by CognitionState::snapshot. Containers are copied along
+/// with their cranks, faliases and maths, and word tables share their word
+/// definitions with the state the snapshot was taken from. Unlike state and
+/// restate, nothing is serialized, so customs are copied with their copyfunc.
+pub struct Snapshot {
+  chroots: Vec<Stack>,
+  stack: Stack,
+  family: Family,
+  parser: Option<Parser>,
+}
+
+impl CognitionState {
+  fn stack_copy(&mut self, stack: &Stack) -> Stack {
+    let mut new_stack = self.pool.get_stack(stack.len());
+    for v in stack.iter() {
+      let v = self.value_copy(v);
+      new_stack.push(v);
+    }
+    new_stack
+  }
+
+  fn parser_copy(&mut self, parser: &Parser) -> Parser {
+    Parser{
+      source: parser.source.as_ref().map(|s| self.string_copy(s)),
+      filename: parser.filename.as_ref().map(|s| self.string_copy(s)),
+      shared_filename: parser.shared_filename.clone(),
+      i: parser.i,
+      c: parser.c,
+      line: parser.line,
+      column: parser.column,
+      parse_delim: parser.parse_delim
+    }
+  }
+
+  fn chroots_copy(&mut self, chroots: &[Stack]) -> Vec<Stack> {
+    let mut new_chroots = Vec::with_capacity(chroots.len());
+    for chroot in chroots.iter() {
+      let chroot = self.stack_copy(chroot);
+      new_chroots.push(chroot);
+    }
+    new_chroots
+  }
+
+  fn free_stack(&mut self, mut stack: Stack) {
+    while let Some(v) = stack.pop() { self.pool.add_val(v) }
+    self.pool.add_stack(stack);
+  }
+
+  /// Copies everything a later restore puts back
+  pub fn snapshot(&mut self) -> Snapshot {
+    let chroots = std::mem::take(&mut self.chroots);
+    let chroots_copy = self.chroots_copy(&chroots);
+    self.chroots = chroots;
+    let stack = std::mem::take(&mut self.stack);
+    let stack_copy = self.stack_copy(&stack);
+    self.stack = stack;
+    let mut family = self.pool.get_family();
+    family.extend(self.family.iter().cloned());
+    let parser = self.parser.take();
+    let parser_copy = parser.as_ref().map(|p| self.parser_copy(p));
+    self.parser = parser;
+    Snapshot{ chroots: chroots_copy, stack: stack_copy, family, parser: parser_copy }
+  }
+
+  /// For restoring the same snapshot more than once
+  pub fn snapshot_copy(&mut self, snapshot: &Snapshot) -> Snapshot {
+    let chroots = self.chroots_copy(&snapshot.chroots);
+    let stack = self.stack_copy(&snapshot.stack);
+    let mut family = self.pool.get_family();
+    family.extend(snapshot.family.iter().cloned());
+    let parser = snapshot.parser.as_ref().map(|p| self.parser_copy(p));
+    Snapshot{ chroots, stack, family, parser }
+  }
+
+  /// Rolls the state back to a snapshot. The family and parser are only
+  /// restored between evaluations: one in progress is still reading from
+  /// the parser and expects to find the family as it left it.
+  pub fn restore(&mut self, snapshot: Snapshot) {
+    let Snapshot{ chroots, stack, mut family, mut parser } = snapshot;
+    for chroot in std::mem::replace(&mut self.chroots, chroots) { self.free_stack(chroot) }
+    let stack = std::mem::replace(&mut self.stack, stack);
+    self.free_stack(stack);
+    if self.depth == 0 {
+      std::mem::swap(&mut self.family, &mut family);
+      std::mem::swap(&mut self.parser, &mut parser);
+    }
+    while let Some(wd) = family.pop() { self.pool.add_word_def(wd) }
+    self.pool.add_family(family);
+    if let Some(parser) = parser { self.pool.add_parser(parser) }
+  }
+}
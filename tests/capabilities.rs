@@ -0,0 +1,52 @@
+mod common;
+use common::*;
+use cognition::*;
+
+const MANIFEST: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/Cargo.toml");
+
+/// Evaluates source after the standard library with the capabilities crank
+/// --sandbox gives a script
+fn sandboxed(caps: Capabilities, source: &str) -> CognitionState {
+  let mut state = std_state();
+  state.capabilities = caps;
+  eval_source(state, source)
+}
+
+#[test]
+fn sandbox_denies_files_fllibs_and_load() {
+  for (source, args) in [
+    (format!("[ {MANIFEST} ] fopen"), vec![format!("[ '{MANIFEST}' ]")]),
+    ("[ x ] file-new".to_string(), vec!["[ 'x' ]".to_string()]),
+    ("[ lib ] [ x ] fllib".to_string(), vec!["[ 'lib' ]".to_string(), "[ 'x' ]".to_string()]),
+    ("[ x ] [ JSON ] load".to_string(), vec!["[ 'x' ]".to_string(), "[ 'JSON' ]".to_string()]),
+    ("[ x ] [ JSON ] fstate".to_string(), vec!["[ 'x' ]".to_string(), "[ 'JSON' ]".to_string()]),
+  ] {
+    let state = sandboxed(Capabilities::none(), &source);
+    assert_eq!(stack(&state), args, "{source}");
+    assert_eq!(errors(&state), ["PERMISSION DENIED"], "{source}");
+  }
+}
+
+#[test]
+fn allowing_fs_read_still_denies_writes() {
+  let caps = Capabilities::none().allow(Capability::FsRead);
+  let state = sandboxed(caps, &format!("[ {MANIFEST} ] fread"));
+  assert!(stack(&state)[0].contains("[package]"));
+  assert!(errors(&state).is_empty());
+  let state = sandboxed(caps, "[ data ] [ x ] fwrite [ x ] file-new");
+  assert_eq!(stack(&state), ["[ 'data' ]", "[ 'x' ]", "[ 'x' ]"]);
+  assert_eq!(errors(&state), ["PERMISSION DENIED", "PERMISSION DENIED"]);
+}
+
+#[test]
+fn streams_opened_before_the_sandbox_stay_usable() {
+  let mut state = run(&format!("[ {MANIFEST} ] fopen"));
+  state.capabilities = Capabilities::none();
+  let state = eval_source(state, &format!("fread [ {MANIFEST} ] fread"));
+  let stack = stack(&state);
+  assert_eq!(stack.len(), 3);
+  assert!(stack[0].contains("[package]"));
+  assert_eq!(stack[1], "[ (reader) ]");
+  assert_eq!(stack[2], format!("[ '{MANIFEST}' ]"));
+  assert_eq!(errors(&state), ["PERMISSION DENIED"]);
+}
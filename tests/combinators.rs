@@ -0,0 +1,62 @@
+mod common;
+use common::*;
+
+#[test]
+fn map_composes_what_the_quote_leaves() {
+  let state = run("[ 1 2 3 ] ( 1 + ) map [ 1 2 ] ( dup ) map");
+  assert_eq!(stack(&state), ["[ '2' '3' '4' ]", "[ '1' '1' '2' '2' ]"]);
+}
+
+#[test]
+fn filter_keeps_elements_leaving_a_nonempty_word() {
+  let state = run("[ 1 5 2 6 ] ( 3 < ) filter");
+  assert_eq!(stack(&state), ["[ '1' '2' ]"]);
+}
+
+#[test]
+fn fold_threads_the_accumulator() {
+  let state = run("[ 1 2 3 ] 0 ( + ) fold");
+  assert_eq!(stack(&state), ["[ '6' ]"]);
+}
+
+#[test]
+fn quotes_run_without_the_stack_below() {
+  let state = run("outer [ 1 ] ( drop drop ) map");
+  assert_eq!(stack(&state), ["[ 'outer' ]", "[ ]"]);
+  assert_eq!(errors(&state), ["TOO FEW ARGUMENTS"]);
+}
+
+#[test]
+fn each_times_and_while_run_on_the_stack() {
+  let state = run("[ 1 2 ] ( 10 * ) each 0 ( 1 + ) 3 times ( dup 5 < ) ( 1 + ) while");
+  assert_eq!(stack(&state), ["[ '10' ]", "[ '20' ]", "[ '5' ]"]);
+}
+
+#[test]
+fn map_leaving_by_cd_restores_the_original_container() {
+  let state = run("outer1 outer2 [ 1 2 3 ] ( drop child cd ) map");
+  assert_eq!(state.stack.len(), 2);
+  assert_eq!(printed(&state.stack[0]), "[ [ 'outer1' ] [ 'outer2' ] ]");
+  assert!(stack(&state).is_empty());
+}
+
+#[test]
+fn filter_and_fold_leaving_by_cd_restore_the_original_container() {
+  let state = run("outer1 [ 1 2 ] ( drop child cd ) filter uncd outer2 [ 1 2 ] 0 ( drop drop child cd ) fold uncd");
+  assert_eq!(state.stack.len(), 1);
+  assert_eq!(stack(&state), ["[ 'outer1' ]", "[ ]", "[ 'outer2' ]", "[ ]"]);
+}
+
+#[test]
+fn map_leaving_by_uncd_restores_the_original_container() {
+  let state = run("child cd outer [ 1 2 ] ( drop uncd ) map");
+  assert_eq!(state.stack.len(), 1);
+  assert_eq!(stack(&state), ["[ [ 'outer' ] ]"]);
+}
+
+#[test]
+fn map_stops_on_return() {
+  let state = run("[ f ] ( outer [ 1 2 ] ( return ) map after ) def f");
+  assert_eq!(state.stack.len(), 1);
+  assert_eq!(stack(&state), ["[ 'outer' ]"]);
+}
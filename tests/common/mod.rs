@@ -0,0 +1,65 @@
+#![allow(dead_code)]
+use cognition::*;
+
+const BOOTSTRAP: &str = include_str!("../../coglib/std/bootstrap.cog");
+const STD: &str = include_str!("../../coglib/std.cog");
+
+/// A state set up the way crank sets one up, before any source is read
+pub fn new_state() -> CognitionState {
+  let mut state = CognitionState::new(Stack::with_capacity(DEFAULT_STACK_SIZE));
+  let mut vstack = Box::new(VStack::with_capacity(DEFAULT_STACK_SIZE));
+  vstack.container.faliases = Container::default_faliases();
+  state.stack.push(Value::Stack(vstack));
+  builtins::add_builtins(&mut state);
+  state.parser = Some(Parser::new(None, None));
+  state
+}
+
+/// Parses and evaluates source as crank evaluates a file
+pub fn eval_source(state: CognitionState, source: &str) -> CognitionState {
+  eval_file(state, source, None)
+}
+
+/// Parses and evaluates source as crank evaluates the named file
+pub fn eval_file(mut state: CognitionState, source: &str, filename: Option<&str>) -> CognitionState {
+  let mut parser = state.parser.take().unwrap();
+  parser.reset(source.to_string(), filename.map(String::from));
+  state.parser = Some(parser);
+  while let Some(v) = state.parser_get_next() {
+    state = state.eval(v, None);
+    if state.control.is_return() || state.exited { break }
+  }
+  state
+}
+
+/// A state with the standard library loaded, which the coglib files it
+/// reads are found for through COGLIB_DIR
+pub fn std_state() -> CognitionState {
+  std::env::set_var("COGLIB_DIR", concat!(env!("CARGO_MANIFEST_DIR"), "/coglib"));
+  let state = eval_source(new_state(), BOOTSTRAP);
+  eval_source(state, STD)
+}
+
+/// Evaluates source after the standard library
+pub fn run(source: &str) -> CognitionState {
+  eval_source(std_state(), source)
+}
+
+pub fn printed(v: &Value) -> String {
+  let mut out = Vec::new();
+  v.fprint(&mut out, "", false);
+  String::from_utf8(out).unwrap()
+}
+
+/// The current container's stack as crank prints it at the end, one value per entry
+pub fn stack(state: &CognitionState) -> Vec<String> {
+  state.current_ref().stack.iter().map(printed).collect()
+}
+
+/// The messages on the current container's error stack
+pub fn errors(state: &CognitionState) -> Vec<String> {
+  match state.current_ref().err_stack {
+    Some(ref errors) => errors.iter().map(|v| v.verror_ref().error.clone()).collect(),
+    None => Vec::new()
+  }
+}
@@ -0,0 +1,33 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+// runs crank --debug on source after the standard library, feeding it the
+// debugger commands, and returns what it printed
+fn debug(source: &str, commands: &str) -> String {
+  let path = std::env::temp_dir().join(format!("cognition-debugger-{}.cog", std::process::id()));
+  std::fs::write(&path, source).unwrap();
+  let mut child = Command::new(env!("CARGO_BIN_EXE_crank"))
+    .current_dir(concat!(env!("CARGO_MANIFEST_DIR"), "/coglib"))
+    .args(["-e", "s", "-s", "3", "--debug", "std/bootstrap.cog", "std.cog"])
+    .arg(&path)
+    .stdin(Stdio::piped())
+    .stdout(Stdio::piped())
+    .spawn()
+    .unwrap();
+  child.stdin.take().unwrap().write_all(commands.as_bytes()).unwrap();
+  let output = child.wait_with_output().unwrap();
+  std::fs::remove_file(&path).unwrap();
+  String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn debugger_stops_at_a_word_breakpoint() {
+  let out = debug("[ f ] ( 0 brk 3 ) def [ brk ] ( 1 2 ) def\nf\n", "b brk\nc\np\nc\n");
+  // the first stop is before the first value of the bootstrap
+  let (_, after) = out.split_once("breakpoint 'brk'\n").expect("stopped at brk");
+  let (stop, end) = after.split_once("Stack at end:").unwrap();
+  assert!(stop.starts_with("value '1'\n"));
+  assert!(stop.contains("evaluation depth 2"));
+  assert!(stop.contains("(debug) [ '0' ]\n"));
+  assert_eq!(end.trim(), "[ '0' ]\n[ '1' ]\n[ '2' ]\n[ '3' ]");
+}
@@ -0,0 +1,55 @@
+mod common;
+use common::*;
+use cognition::*;
+
+fn root_errors(state: &CognitionState) -> usize {
+  state.stack[0].vstack_ref().container.err_stack.as_ref().map_or(0, |e| e.len())
+}
+
+#[test]
+fn try_catches_errors_raised_in_its_container() {
+  let state = run("( [ a ] ethrow ) ( [ caught ] ) try");
+  assert_eq!(stack(&state).last().unwrap(), "[ 'caught' ]");
+  assert!(errors(&state).is_empty());
+}
+
+#[test]
+fn try_leaves_errors_raised_after_cd() {
+  let state = run("[ e1 ] ethrow [ e2 ] ethrow ( [ x ] cd drop drop drop drop ) ( [ caught ] ) try");
+  // still in x, which dropped its word and kept all three of its errors
+  assert_eq!(stack(&state), Vec::<String>::new());
+  assert_eq!(errors(&state), ["TOO FEW ARGUMENTS"; 3]);
+  assert_eq!(root_errors(&state), 2);
+}
+
+#[test]
+fn eraise_reaches_the_parent_handler() {
+  let state = run("( [ got ] ) ehandler child cd ( eraise ) ehandler drop");
+  // the child handler passed the error up, so neither container kept it
+  assert!(errors(&state).is_empty());
+  assert_eq!(root_errors(&state), 0);
+  let root = &state.stack[0].vstack_ref().container.stack;
+  assert_eq!(printed(root.last().unwrap()), "[ 'got' ]");
+}
+
+#[test]
+fn ethrow_runs_the_handler() {
+  let state = run("( [ got ] ) ehandler [ boom ] ethrow");
+  assert_eq!(stack(&state), ["[ [ 'boom' ] [ 'ethrow' ] [ ] ]", "[ 'got' ]"]);
+  assert!(errors(&state).is_empty());
+}
+
+#[test]
+fn errors_a_handler_raises_in_its_container_are_left() {
+  let state = run("( drop [ again ] ethrow ) ehandler [ boom ] ethrow 1");
+  assert_eq!(stack(&state), ["[ '1' ]"]);
+  assert_eq!(errors(&state), ["again"]);
+}
+
+#[test]
+fn errors_carry_the_chain_of_definitions_they_were_raised_in() {
+  let state = run("[ f ] ( g 0 ) def [ g ] ( h 1 ) def [ h ] ( drop 2 ) def f");
+  let errors = state.current_ref().err_stack.as_ref().unwrap();
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0].verror_ref().backtrace, ["f", "g", "h"]);
+}
@@ -0,0 +1,110 @@
+mod common;
+use common::*;
+use cognition::*;
+
+use std::time::{Duration, Instant};
+
+const NESTED: &str = "[ sq ] [ dup * ] def [ quad ] [ sq sq ] def";
+const BODY: &str = "( 2 quad 3 ( sq ) eval a b swap )";
+const CRANKED: &str = "( 2 crank 3 sq 2 quad 0 crank x )";
+
+// a state with NESTED defined, and the quote the source leaves as a definition
+fn setup(source: &str) -> (CognitionState, WordDef) {
+  let mut state = eval_source(std_state(), NESTED);
+  state = eval_source(state, source);
+  let v = state.current().stack.pop().expect("source leaves a quote");
+  let wd = state.pool.get_word_def(v);
+  (state, wd)
+}
+
+fn evaluated(source: &str) -> CognitionState {
+  let (state, wd) = setup(source);
+  state.evalstack(wd, None, false)
+}
+
+fn stepped(source: &str, run: impl FnOnce(Evaluator, CognitionState) -> CognitionState) -> CognitionState {
+  let (mut state, wd) = setup(source);
+  let evaluator = Evaluator::new(&mut state, wd, None, false);
+  run(evaluator, state)
+}
+
+fn assert_same(a: &CognitionState, b: &CognitionState) {
+  assert_eq!(stack(a), stack(b));
+  assert_eq!(errors(a), errors(b));
+  assert_eq!((a.depth, a.family.len(), a.calls.len()), (b.depth, b.family.len(), b.calls.len()));
+}
+
+#[test]
+fn stepping_nested_definitions_matches_evalstack() {
+  let expected = evaluated(BODY);
+  let state = stepped(BODY, |mut ev, mut state| {
+    while !ev.is_done() { state = ev.step(state) }
+    state
+  });
+  assert_same(&state, &expected);
+  assert_eq!(stack(&expected)[1..], ["[ '9' ]", "[ 'b' ]", "[ 'a' ]"]);
+  assert_eq!(expected.depth, 0);
+}
+
+#[test]
+fn stepping_cranks_matches_evalstack() {
+  let expected = evaluated(CRANKED);
+  let state = stepped(CRANKED, |ev, state| ev.finish(state));
+  assert_same(&state, &expected);
+}
+
+#[test]
+fn steps_and_until_resume_where_they_stopped() {
+  let expected = evaluated(BODY);
+  let state = stepped(BODY, |mut ev, mut state| {
+    state = ev.steps(state, 3);
+    assert!(!ev.is_done());
+    state = ev.until(state, Instant::now() - Duration::from_secs(1));
+    assert!(!ev.is_done());
+    state = ev.steps(state, 1);
+    ev.until(state, Instant::now() + Duration::from_secs(60))
+  });
+  assert_same(&state, &expected);
+}
+
+#[test]
+fn errors_match_evalstack() {
+  let source = "( 1 sq drop drop 2 quad )";
+  let expected = evaluated(source);
+  assert_eq!(errors(&expected), ["TOO FEW ARGUMENTS"]);
+  let state = stepped(source, |ev, state| ev.finish(state));
+  assert_same(&state, &expected);
+}
+
+#[test]
+fn cancel_inside_a_definition_unwinds() {
+  let (mut state, wd) = setup(BODY);
+  let (depth, family) = (state.depth, state.family.len());
+  let mut ev = Evaluator::new(&mut state, wd, None, false);
+  // into the sq quad calls, then into the dup sq calls
+  state = ev.steps(state, 3);
+  assert!(ev.depth() > 1);
+  assert!(!state.calls.is_empty());
+  ev.cancel(&mut state);
+  assert_eq!((state.depth, state.family.len()), (depth, family));
+  assert!(state.calls.is_empty());
+  assert_eq!(stack(&state), ["[ '2' ]"]);
+  // the state is left usable
+  let state = eval_source(state, "sq");
+  assert_eq!(stack(&state), ["[ '4' ]"]);
+}
+
+#[test]
+fn top_level_values_match_eval() {
+  let source = "2 quad 3 ( sq ) eval x";
+  let expected = eval_source(eval_source(std_state(), NESTED), source);
+  let mut state = eval_source(std_state(), NESTED);
+  let mut parser = state.parser.take().unwrap();
+  parser.reset(source.to_string(), None);
+  state.parser = Some(parser);
+  while let Some(v) = state.parser_get_next() {
+    if let Some(ev) = Evaluator::from_value(&mut state, v) { state = ev.finish(state) }
+  }
+  assert_same(&state, &expected);
+  assert_eq!(stack(&state)[1..], ["[ '9' ]", "[ 'x' ]"]);
+}
@@ -0,0 +1,62 @@
+mod common;
+use common::*;
+
+fn run_with_fuel(source: &str, fuel: usize) -> cognition::CognitionState {
+  let mut state = std_state();
+  state.limits.fuel = Some(fuel);
+  eval_source(state, source)
+}
+
+#[test]
+fn empty_times_loop_spends_fuel() {
+  let state = run_with_fuel("( ) 1000000000 times", 10);
+  assert!(state.limit_hit);
+  assert_eq!(errors(&state), ["FUEL EXHAUSTED"]);
+}
+
+#[test]
+fn empty_while_loop_spends_fuel() {
+  let state = run_with_fuel("( t ) ( ) while", 10);
+  assert!(state.limit_hit);
+  assert_eq!(errors(&state), ["FUEL EXHAUSTED"]);
+}
+
+#[test]
+fn eval_limited_stops_an_empty_loop() {
+  let state = run("[ ( ) 1000000000 times ] 10 [ ] [ ] eval-limited after");
+  assert!(!state.limit_hit);
+  assert_eq!(stack(&state), ["[ 'after' ]"]);
+  assert_eq!(errors(&state), ["FUEL EXHAUSTED"]);
+}
+
+#[test]
+fn max_stack_counts_values_on_the_metastack() {
+  let mut state = std_state();
+  state.limits.stack = Some(state.stack.iter().map(|v| v.value_stack_ref().len()).sum::<usize>() + 2);
+  let state = eval_source(state, "[ 1 2 3 4 ] a b c");
+  assert!(state.limit_hit);
+  assert_eq!(errors(&state), ["STACK TOO LARGE"]);
+}
+
+#[test]
+fn interrupt_from_another_thread_ends_a_long_loop() {
+  let state = std_state();
+  let interrupt = state.interrupt.clone();
+  let handle = std::thread::spawn(move || {
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    interrupt.store(true, std::sync::atomic::Ordering::Relaxed);
+  });
+  let state = eval_source(state, "( ) 1000000000000 times after");
+  handle.join().unwrap();
+  assert!(state.interrupted);
+  assert!(stack(&state).is_empty());
+  assert_eq!(errors(&state), ["INTERRUPTED"]);
+}
+
+#[test]
+fn self_recursive_word_in_tail_position_runs_without_nesting() {
+  let state = run_with_fuel("[ loop ] ( x \\ loop ) def loop", 2_000_200);
+  assert!(state.limit_hit);
+  assert_eq!(errors(&state), ["FUEL EXHAUSTED"]);
+  assert!(state.current_ref().stack.len() >= 1_000_000);
+}
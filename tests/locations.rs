@@ -0,0 +1,42 @@
+mod common;
+use common::*;
+use cognition::*;
+
+// the filename, line and column of each error on the current error stack
+fn locations(state: &CognitionState) -> Vec<(String, String, String)> {
+  let Some(ref errors) = state.current_ref().err_stack else { return Vec::new() };
+  errors.iter().map(|v| {
+    let loc = v.verror_ref().loc.as_ref().unwrap();
+    (loc.filename.clone(), loc.line.clone(), loc.column.clone())
+  }).collect()
+}
+
+// f and g each raise an error from a drop written on the line they are defined on
+const DEFS: &str = "[ f ] [ drop ] def\n[ g ] [ x ] [ drop drop ] compose def\n\nf g";
+
+fn located(locate_words: bool, source: &str) -> CognitionState {
+  let mut state = std_state();
+  state.locate_words = locate_words;
+  eval_file(state, source, Some("test.cog"))
+}
+
+#[test]
+fn words_keep_their_location_through_def_and_compose() {
+  let state = located(true, DEFS);
+  let lines: Vec<String> = locations(&state).into_iter().map(|(f, l, _)| format!("{f}:{l}")).collect();
+  assert_eq!(lines, ["test.cog:1", "test.cog:2"]);
+}
+
+#[test]
+fn without_locations_errors_point_at_the_parser() {
+  let state = located(false, DEFS);
+  let lines: Vec<String> = locations(&state).into_iter().map(|(f, l, _)| format!("{f}:{l}")).collect();
+  assert_eq!(lines, ["test.cog:4", "test.cog:4"]);
+}
+
+#[test]
+fn columns_count_from_one_on_every_line() {
+  let state = located(true, "  drop\n  drop\n\ndrop");
+  let columns: Vec<String> = locations(&state).into_iter().map(|(_, l, c)| format!("{l}:{c}")).collect();
+  assert_eq!(columns, ["1:3", "2:3", "4:1"]);
+}
@@ -0,0 +1,99 @@
+mod common;
+use common::*;
+
+#[test]
+fn binary_op_without_base_restores_arguments() {
+  let state = run("1 2 0 base +");
+  assert_eq!(stack(&state), ["[ '1' ]", "[ '2' ]"]);
+  assert_eq!(errors(&state), ["MATH BASE ZERO"]);
+}
+
+#[test]
+fn unary_op_without_base_restores_argument() {
+  let state = run("1 0 base abs");
+  assert_eq!(stack(&state), ["[ '1' ]"]);
+  assert_eq!(errors(&state), ["MATH BASE ZERO"]);
+}
+
+#[test]
+fn shl_past_the_digit_limit_restores_arguments() {
+  let state = run("1 ↊↊↊↊↊↊ shl 3 ↊̅↊̅↊̅↊̅↊̅↊̅ shr");
+  assert_eq!(stack(&state), ["[ '1' ]", "[ '↊↊↊↊↊↊' ]", "[ '3' ]", "[ '↊̅↊̅↊̅↊̅↊̅↊̅' ]"]);
+  assert_eq!(errors(&state), ["OUT OF BOUNDS", "OUT OF BOUNDS"]);
+}
+
+#[test]
+fn pow_past_the_digit_limit_restores_arguments() {
+  let state = run("2 ↊↊↊↊↊↊ pow .1 ↊̅↊̅↊̅↊̅↊̅↊̅ pow 1̅ ↊↊↊↊↊↊ pow");
+  assert_eq!(stack(&state), ["[ '2' ]", "[ '↊↊↊↊↊↊' ]", "[ '.1' ]", "[ '↊̅↊̅↊̅↊̅↊̅↊̅' ]", "[ '1' ]"]);
+  assert_eq!(errors(&state), ["OUT OF BOUNDS", "OUT OF BOUNDS"]);
+}
+
+#[test]
+fn sums_and_products_carry_and_borrow_across_limbs() {
+  // 1000000000001̅ is 24^12 - 1, whose limbs are all 23
+  let state = run("1000000000001̅ 1 + 1000000000000 1 - 1000000000001̅ 1000000000001̅ *");
+  assert_eq!(stack(&state), ["[ '1000000000000' ]", "[ '1000000000001̅' ]", "[ '1000000000002̅000000000001' ]"]);
+}
+
+#[test]
+fn quotient_of_many_limbs_truncates_toward_zero() {
+  let state = run("123456789↊↋🜘1̅2̅3̅ ↋̅54321 /");
+  assert_eq!(stack(&state), ["[ '2̅↊̅4̅7↋̅2↊9̅9' ]"]);
+}
+
+#[test]
+fn fractions_keep_their_digits_and_products_truncate_to_precision() {
+  let state = run(".5 .5 * .5̅ .5 * 1.2345 .1 + 2 precision .5 .5 *");
+  assert_eq!(stack(&state), ["[ '.1' ]", "[ '.1̅' ]", "[ '1.3345' ]", "[ '.11' ]"]);
+}
+
+#[test]
+fn roots_and_transcendentals_match_known_digits() {
+  // sqrt 2 = 1.4142135..., e = 2.7182818... and ln 2 = 0.6931471... to six
+  // places in base twenty-four
+  let state = run("6 precision 2 sqrt 1 exp 2 ln");
+  assert_eq!(stack(&state), ["[ '1.↊1̅↊̅234̅' ]", "[ '3.7̅66̅↋̅8̅3' ]", "[ '1.7̅9̅62↊̅9' ]"]);
+}
+
+#[test]
+fn defined_ops_apply_until_undefined() {
+  let state = run("[ [ 1 2 ] [ 2 1 ] ] [ swap ] def-unary-op 1221 [ swap ] op
+    [ [ 0 0 0 0 ] [ 0 1 1 0 ] [ 0 2 2 0 ] [ 1 0 1 0 ] [ 1 1 2 0 ] [ 1 2 0 1 ] [ 2 0 2 0 ] [ 2 1 0 1 ] [ 2 2 1 1 ] ]
+    [ add3 ] def-binary-op 12 21 [ add3 ] op
+    [ swap ] undef-op 12 [ swap ] op");
+  assert_eq!(stack(&state), ["[ '2112' ]", "[ '110' ]", "[ '12' ]", "[ 'swap' ]"]);
+  assert_eq!(errors(&state), ["UNDEFINED MATH OP"]);
+}
+
+#[test]
+fn divmod_of_many_limbs_floors() {
+  let state = run("123456789↊↋🜘1̅2̅3̅ ↋̅54321 divmod");
+  assert_eq!(stack(&state), ["[ '2̅↊̅4̅7↋̅2↊9̅8' ]", "[ '9̅7879̅↋̅' ]"]);
+}
+
+#[test]
+fn bitwise_ops_on_negatives_act_on_twos_complement() {
+  // 1̅000005 is -24^6 + 5
+  let state = run("1̅000005 1000001̅ band 1̅000005 3 bor 1̅000005 1000001̅ bxor 1̅000005 bnot
+    1̅000005 7 shl 1̅000005 7 shr 7̅ 1 shr");
+  assert_eq!(stack(&state), [
+    "[ '5' ]", "[ '1̅000007' ]", "[ '6̅' ]", "[ '1000006̅' ]", "[ '5̅8̅000138̅' ]", "[ '5̅🜘000' ]", "[ '4̅' ]"
+  ]);
+}
+
+#[test]
+fn convert_between_bases() {
+  // an empty stack stands for the nearest math, base twenty-four here
+  let state = run("11 [ ] child cd 2 base uncd convert 1.1 [ ] child cd 🜘 base uncd convert
+    101̅011̅ child cd 2 base uncd [ ] convert");
+  assert_eq!(stack(&state), ["[ '101̅011̅' ]", "[ '1.16̅' ]", "[ '11' ]"]);
+  assert!(errors(&state).is_empty());
+}
+
+#[test]
+fn compound_numbers_combine_by_part_and_component() {
+  // (1+2i)(3+4i) = -5+10i
+  let state = run("1,2 3,4 * 1,2 3,4 + 3,4 2 * 1;2,3 1;1,1 +");
+  assert_eq!(stack(&state), ["[ '5̅,↊' ]", "[ '4,6' ]", "[ '6,8' ]", "[ '2;3,4' ]"]);
+}
@@ -0,0 +1,26 @@
+mod common;
+use common::*;
+use cognition::profiler::Profiler;
+
+#[test]
+fn profile_without_base_keeps_profiling() {
+  let state = run("profile-start 0 base profile");
+  assert!(state.profiler.is_some());
+  assert_eq!(errors(&state), ["MATH BASE ZERO"]);
+}
+
+#[test]
+fn profiler_counts_calls_by_chain() {
+  // nothing is in tail position, so each call nests in its caller
+  let mut state = run("[ f ] ( g g 0 ) def [ g ] ( h 1 ) def [ h ] ( 2 ) def");
+  state.profiler = Some(Profiler::new());
+  let mut state = eval_source(state, "f g");
+  let profiler = state.profiler.as_mut().unwrap();
+  profiler.finish();
+  let calls = |name: &str| profiler.entries.get(name).map_or(0, |e| e.calls);
+  assert_eq!((calls("f"), calls("g"), calls("h")), (1, 3, 3));
+  let mut out = Vec::new();
+  profiler.collapsed(&mut out).unwrap();
+  let chains: Vec<&str> = std::str::from_utf8(&out).unwrap().lines().map(|l| l.rsplit_once(' ').unwrap().0).collect();
+  assert_eq!(chains, ["f", "f;g", "f;g;h", "g", "g;h"]);
+}
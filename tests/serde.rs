@@ -0,0 +1,63 @@
+mod common;
+use common::*;
+use cognition::*;
+
+// serializes with serde_json and drops a field, as in a state saved before
+// the field existed
+fn json_without<T: Serialize>(value: &T, field: &str) -> String {
+  let mut json: serde_json::Value = serde_json::from_str(&serde_json::to_string(value).unwrap()).unwrap();
+  assert!(json.as_object_mut().unwrap().remove(field).is_some());
+  json.to_string()
+}
+
+#[test]
+fn math_saved_without_precision_loads() {
+  let mut state = run("");
+  let math = state.current_ref().math.as_ref().expect("std sets a math");
+  let (json, base) = (json_without(math, "precision"), math.base());
+  let mut deserializer = serde_json::Deserializer::from_str(&json);
+  let loaded = Math::cognition_deserialize(&mut deserializer, &mut state).unwrap();
+  assert_eq!(loaded.precision(), 0);
+  assert_eq!(loaded.base(), base);
+}
+
+fn push_word(state: &mut CognitionState, s: &str) {
+  let mut vword = state.pool.get_vword(s.len());
+  vword.str_word.push_str(s);
+  state.push_quoted(Value::Word(vword));
+}
+
+fn load(fllibs: &str) -> CognitionState {
+  let mut state = run("");
+  push_word(&mut state, fllibs);
+  push_word(&mut state, "JSON");
+  eval_source(state, "load")
+}
+
+fn error_kinds(state: &CognitionState) -> Vec<ErrorKind> {
+  state.current_ref().err_stack.iter().flatten().map(|v| v.verror_ref().kind).collect()
+}
+
+#[test]
+fn load_raises_the_kind_fllib_loading_failed_with() {
+  let state = load(r#"{"missing":"/nonexistent/libmissing.so"}"#);
+  assert_eq!(errors(&state), ["INVALID FILENAME"]);
+  assert_eq!(error_kinds(&state), [ErrorKind::InvalidFilename]);
+  assert_eq!(state.current_ref().stack.len(), 2);
+}
+
+#[test]
+fn load_of_malformed_data_fails_to_deserialize() {
+  let state = load("{");
+  assert_eq!(errors(&state), ["DESERIALIZATION FAILED"]);
+  assert_eq!(error_kinds(&state), [ErrorKind::DeserializationFailed]);
+}
+
+#[test]
+fn container_saved_without_on_error_loads_without_a_handler() {
+  let mut state = run("");
+  let json = json_without(&Container::with_capacity(0), "on_error");
+  let mut deserializer = serde_json::Deserializer::from_str(&json);
+  let loaded = Container::cognition_deserialize(&mut deserializer, &mut state).unwrap();
+  assert!(loaded.on_error.is_none());
+}
@@ -0,0 +1,35 @@
+mod common;
+use common::*;
+use cognition::*;
+
+fn cranks(state: &CognitionState) -> Vec<(i32, i32)> {
+  state.current_ref().cranks.iter().flatten().map(|c| (c.modulo, c.base)).collect()
+}
+
+#[test]
+fn restore_rolls_back_the_stack_chroots_and_cranks() {
+  let mut state = run("a b snapshot");
+  let snapshot = state.current().stack.pop().unwrap();
+  let before = cranks(&state);
+  state = eval_source(state, "c 3 0 metacrank");
+  let root = state.pool.get_stack(0);
+  state.chroots.push(root);
+  assert_ne!(cranks(&state), before);
+  state.current().stack.push(snapshot);
+  let state = eval_source(state, "restore");
+  assert_eq!(stack(&state), ["[ 'a' ]", "[ 'b' ]"]);
+  assert!(state.chroots.is_empty());
+  assert_eq!(cranks(&state), before);
+}
+
+#[test]
+fn restore_of_a_duped_snapshot_works_twice() {
+  let mut state = run("a snapshot dup");
+  let copy = state.current().stack.pop().unwrap();
+  state = eval_source(state, "b swap restore");
+  assert_eq!(stack(&state), ["[ 'a' ]"]);
+  state.current().stack.push(copy);
+  state = eval_source(state, "c swap restore");
+  assert_eq!(stack(&state), ["[ 'a' ]"]);
+  assert!(errors(&state).is_empty());
+}
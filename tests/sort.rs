@@ -0,0 +1,38 @@
+mod common;
+use common::*;
+
+#[test]
+fn nsort_without_base_raises_even_with_nothing_to_compare() {
+  let state = run("[ 5 ] 0 base nsort");
+  assert_eq!(stack(&state), ["[ '5' ]"]);
+  assert_eq!(errors(&state), ["MATH BASE ZERO"]);
+}
+
+#[test]
+fn sort_by_orders_with_the_comparator() {
+  let state = run("[ 3 1 2 ] ( > ) sort-by");
+  assert_eq!(stack(&state), ["[ '3' '2' '1' ]"]);
+}
+
+#[test]
+fn sort_by_failing_comparator_restores_arguments() {
+  let state = run("outer [ 3 1 2 ] ( drop drop ) sort-by");
+  assert_eq!(stack(&state), ["[ 'outer' ]", "[ '3' '1' '2' ]", "( drop drop )"]);
+  assert_eq!(errors(&state), ["BAD COMPARATOR"]);
+}
+
+#[test]
+fn sort_by_leaving_by_cd_restores_the_original_container() {
+  let state = run("outer [ 3 1 2 ] ( drop drop child cd ) sort-by");
+  assert_eq!(state.stack.len(), 2);
+  assert_eq!(printed(&state.stack[0]), "[ [ 'outer' ] ]");
+  assert!(stack(&state).is_empty());
+  assert!(errors(&state).is_empty());
+}
+
+#[test]
+fn sort_by_stops_on_return() {
+  let state = run("[ f ] ( outer [ 3 1 2 ] ( return ) sort-by after ) def f");
+  assert_eq!(stack(&state), ["[ 'outer' ]"]);
+  assert!(errors(&state).is_empty());
+}
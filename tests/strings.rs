@@ -0,0 +1,21 @@
+mod common;
+use common::*;
+
+#[test]
+fn compare_without_base_restores_arguments() {
+  let state = run("[ a ] [ b ] 0 base compare");
+  assert_eq!(stack(&state), ["[ 'a' ]", "[ 'b' ]"]);
+  assert_eq!(errors(&state), ["MATH BASE ZERO"]);
+}
+
+#[test]
+fn compare_orders_by_code_point() {
+  let state = run("[ b ] [ a ] compare [ a ] [ a ] compare [ 10 ] [ 9 ] compare");
+  assert_eq!(stack(&state), ["[ '1' ]", "[ '' ]", "[ '1̅' ]"]);
+}
+
+#[test]
+fn str_lt_orders_by_code_point_and_ustr_lt_ignores_case_first() {
+  let state = run("[ apple ] [ Banana ] str< [ Apple ] [ apple ] str< [ apple ] [ Banana ] ustr< [ apple ] [ Apple ] ustr<");
+  assert_eq!(stack(&state), ["[ '' ]", "[ 't' ]", "[ 't' ]", "[ '' ]"]);
+}
@@ -0,0 +1,72 @@
+mod common;
+use common::*;
+use cognition::*;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+// records every event but the values evaluated, with the evaluation depth
+struct Recorder(Rc<RefCell<Vec<String>>>);
+
+fn name(v: Option<&Value>) -> &str {
+  match v {
+    Some(Value::Word(vword)) => &vword.str_word,
+    _ => "",
+  }
+}
+
+impl Tracer for Recorder {
+  fn lookup(&mut self, state: &CognitionState, word: &Value, _family: Option<usize>) {
+    self.0.borrow_mut().push(format!("lookup {} {}", name(Some(word)), state.depth))
+  }
+  fn enter(&mut self, state: &CognitionState, _wd: &Value, callword: Option<&Value>, tail: bool) {
+    let tail = if tail { " tail" } else { "" };
+    self.0.borrow_mut().push(format!("enter {} {}{tail}", name(callword), state.depth))
+  }
+  fn exit(&mut self, state: &CognitionState, callword: Option<&Value>) {
+    self.0.borrow_mut().push(format!("exit {} {}", name(callword), state.depth))
+  }
+  fn crank(&mut self, state: &CognitionState, _wd: &Value) {
+    self.0.borrow_mut().push(format!("crank {}", state.depth))
+  }
+}
+
+fn traced(mut state: CognitionState, run: impl FnOnce(CognitionState) -> CognitionState) -> (CognitionState, Vec<String>) {
+  let events = Rc::new(RefCell::new(Vec::new()));
+  state.tracer = Some(Box::new(Recorder(events.clone())));
+  let state = run(state);
+  let events = events.borrow().clone();
+  (state, events)
+}
+
+#[test]
+fn tracer_sees_lookups_and_definitions_at_their_depths() {
+  // f is defined before g, so g is looked up when f runs
+  let state = run("[ f ] ( g 3 ) def [ g ] ( 1 2 ) def");
+  let (state, events) = traced(state, |state| eval_source(state, "f"));
+  assert_eq!(stack(&state), ["[ '1' ]", "[ '2' ]", "[ '3' ]"]);
+  assert_eq!(events, [
+    "enter  1", "lookup f 1", "enter f 1 tail",
+    "lookup g 1", "enter g 2", "exit g 2",
+    "exit  1",
+  ]);
+}
+
+#[test]
+fn tracer_sees_cranks() {
+  // with the crank off and a metacrank of one, each value pushed cranks the
+  // one below it
+  let mut state = run("[ 1 1 metacrank 0 crank 4 5 ]");
+  let v = state.current().stack.pop().unwrap();
+  let wd = state.pool.get_word_def(v);
+  let state = eval_source(state, "pre");
+  let (state, events) = traced(state, |state| state.evalstack(wd, None, false));
+  assert_eq!(stack(&state), ["[ '4' ]", "[ '5' ]", "[ 'pre' ]"]);
+  assert_eq!(events, [
+    "enter  1",
+    "lookup metacrank 1", "enter metacrank 2", "exit metacrank 2",
+    "lookup crank 1", "enter crank 2", "exit crank 2",
+    "crank 1", "enter  2", "exit  2",
+    "crank 1", "enter  1 tail", "exit  1",
+  ]);
+}